@@ -0,0 +1,31 @@
+use criterion::{black_box, criterion_group, criterion_main, Criterion};
+use iter_num_tools::{fast_math, lin_space, log_space};
+
+fn bench(i: impl Iterator<Item = f64>) -> f64 {
+    black_box(black_box(i).sum())
+}
+
+pub fn bench_fast_math(c: &mut Criterion) {
+    let mut group = c.benchmark_group("FastMath");
+
+    group.bench_function("linspace [1.0, 100.0] x200", |b| {
+        b.iter(|| bench(lin_space(1.0..=100.0, 200)))
+    });
+
+    group.bench_function("linspace [1.0, 100.0] x200 (fast_math)", |b| {
+        b.iter(|| bench(fast_math::lin_space(1.0..=100.0, 200)))
+    });
+
+    group.bench_function("logspace [1, 1000) x200", |b| {
+        b.iter(|| bench(log_space(1.0..1000.0, 200)))
+    });
+
+    group.bench_function("logspace [1, 1000) x200 (fast_math)", |b| {
+        b.iter(|| bench(fast_math::log_space(1.0..1000.0, 200)))
+    });
+
+    group.finish();
+}
+
+criterion_group!(benches, bench_fast_math);
+criterion_main!(benches);
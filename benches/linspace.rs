@@ -29,5 +29,35 @@ pub fn bench_lin_space(c: &mut Criterion) {
     group.finish();
 }
 
-criterion_group!(benches, bench_lin_space);
+pub fn bench_lin_space_chunks(c: &mut Criterion) {
+    let mut group = c.benchmark_group("LinSpace chunks");
+
+    group.bench_function(
+        "linspace [1.0, 100.0] x4096 in 256s (for_each_chunk)",
+        |b| {
+            b.iter(|| {
+                let mut total = 0.0;
+                let mut scratch = [0.0; 256];
+                black_box(lin_space(1.0..=100.0, 4096))
+                    .for_each_chunk(&mut scratch, |chunk| total += chunk.iter().sum::<f64>());
+                black_box(total)
+            })
+        },
+    );
+
+    group.bench_function("linspace [1.0, 100.0] x4096 in 256s (collect)", |b| {
+        b.iter(|| {
+            let values: Vec<f64> = black_box(lin_space(1.0..=100.0, 4096)).collect();
+            let total: f64 = values
+                .chunks(256)
+                .map(|chunk| chunk.iter().sum::<f64>())
+                .sum();
+            black_box(total)
+        })
+    });
+
+    group.finish();
+}
+
+criterion_group!(benches, bench_lin_space, bench_lin_space_chunks);
 criterion_main!(benches);
@@ -0,0 +1,34 @@
+use criterion::{black_box, criterion_group, criterion_main, Criterion};
+use iter_num_tools::{lin_space, poly_space};
+
+fn bench(i: impl Iterator<Item = f64>) -> f64 {
+    black_box(black_box(i).sum())
+}
+
+fn horner(coeffs: [f64; 4], x: f64) -> f64 {
+    coeffs.iter().rev().fold(0.0, |acc, c| acc * x + c)
+}
+
+pub fn bench_poly_space(c: &mut Criterion) {
+    let mut group = c.benchmark_group("PolySpace");
+    let coeffs = [1.0, -2.0, 0.5, 3.0];
+
+    group.bench_function(
+        "polyspace deg 3 [1.0, 100.0] x200 (forward differences)",
+        |b| b.iter(|| bench(poly_space(coeffs, 1.0..=100.0, 200))),
+    );
+
+    group.bench_function(
+        "polyspace deg 3 [1.0, 100.0] x200 (compensated forward differences)",
+        |b| b.iter(|| bench(poly_space(coeffs, 1.0..=100.0, 200).compensated())),
+    );
+
+    group.bench_function("polyspace deg 3 [1.0, 100.0] x200 (horner)", |b| {
+        b.iter(|| bench(lin_space(1.0..=100.0, 200).map(|x| horner(coeffs, x))))
+    });
+
+    group.finish();
+}
+
+criterion_group!(benches, bench_poly_space);
+criterion_main!(benches);
@@ -0,0 +1,735 @@
+use core::fmt;
+use core::ops::Range;
+
+use num_traits::{FromPrimitive, Num};
+
+use crate::{
+    linspace::LinearInterpolation,
+    map::MapInterpolation,
+    space::{Direction, Interpolate, Monotonic, Space, SpaceFmt, SpaceType},
+};
+
+#[derive(Clone, Copy, Debug)]
+pub struct WithIndexInterpolation<I>(pub I);
+
+impl<I: Interpolate> Interpolate for WithIndexInterpolation<I> {
+    type Item = (usize, I::Item);
+    fn interpolate(&self, x: usize) -> Self::Item {
+        (x, self.0.interpolate(x))
+    }
+}
+
+impl<I: SpaceFmt> SpaceFmt for WithIndexInterpolation<I> {
+    const NAME: &'static str = "WithIndex";
+    fn fmt_params(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, ", inner: {}", I::NAME)?;
+        self.0.fmt_params(f)
+    }
+}
+
+/// [`Iterator`] returned by [`Space::with_index`], pairing the values of the space `S` with their index
+pub type WithIndex<S> = Space<WithIndexInterpolation<<S as SpaceType>::Interpolation>>;
+
+impl<I> Space<I> {
+    /// Pairs each value with its index in the space.
+    ///
+    /// Unlike [`Iterator::enumerate`], the index is the position of the value in the whole space,
+    /// so it doesn't restart from 0 if the space has already been partially consumed.
+    /// The result is still a [space](Space#adapters).
+    ///
+    /// ```
+    /// use iter_num_tools::lin_space;
+    ///
+    /// let it = lin_space(0.0..=1.0, 5).with_index();
+    /// assert!(it.rev().eq([(4, 1.0), (3, 0.75), (2, 0.5), (1, 0.25), (0, 0.0)]));
+    /// ```
+    pub fn with_index(self) -> WithIndex<Self> {
+        self.map_interpolate(WithIndexInterpolation)
+    }
+}
+
+#[derive(Clone, Copy, Debug)]
+pub struct WithTInterpolation<I> {
+    inner: (I, usize),
+    denom: usize,
+}
+
+impl<I: Interpolate> Interpolate for WithTInterpolation<I> {
+    type Item = (f64, I::Item);
+    fn interpolate(&self, x: usize) -> Self::Item {
+        let (inner, offset) = &self.inner;
+        let t = match self.denom {
+            0 => 0.0,
+            denom => x as f64 / denom as f64,
+        };
+        (t, inner.interpolate(offset + x))
+    }
+}
+
+impl<I: SpaceFmt> SpaceFmt for WithTInterpolation<I> {
+    const NAME: &'static str = "WithT";
+    fn fmt_params(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, ", inner: {}", I::NAME)?;
+        self.inner.0.fmt_params(f)
+    }
+}
+
+/// [`Iterator`] returned by [`Space::with_t`] and [`Space::with_t_exclusive`]
+pub type WithT<S> = Space<WithTInterpolation<<S as SpaceType>::Interpolation>>;
+
+impl<I> Space<I> {
+    /// Pairs each remaining value with how far through them it is, as `t` from 0 to 1.
+    ///
+    /// The first value has `t = 0` and the last has `t = 1`, so `t = i / (n - 1)`,
+    /// which matches the position in an inclusive range such as `0.0..=1.0`.
+    /// A single value has `t = 0`.
+    /// For an exclusive range, use [`with_t_exclusive`](Self::with_t_exclusive) instead.
+    ///
+    /// ```
+    /// use iter_num_tools::lin_space;
+    ///
+    /// let it = lin_space(10.0..=20.0, 5).with_t();
+    /// assert!(it.eq([(0.0, 10.0), (0.25, 12.5), (0.5, 15.0), (0.75, 17.5), (1.0, 20.0)]));
+    /// ```
+    pub fn with_t(self) -> WithT<Self> {
+        let denom = self.range.len().saturating_sub(1);
+        self.with_t_over(denom)
+    }
+
+    /// Pairs each remaining value with how far through them it is, as `t` from 0 up to but not including 1.
+    ///
+    /// The `i`th of `n` values has `t = i / n`,
+    /// which matches the position in an exclusive range such as `0.0..1.0`.
+    ///
+    /// ```
+    /// use iter_num_tools::lin_space;
+    ///
+    /// let it = lin_space(10.0..20.0, 4).with_t_exclusive();
+    /// assert!(it.eq([(0.0, 10.0), (0.25, 12.5), (0.5, 15.0), (0.75, 17.5)]));
+    /// ```
+    pub fn with_t_exclusive(self) -> WithT<Self> {
+        let denom = self.range.len();
+        self.with_t_over(denom)
+    }
+
+    fn with_t_over(self, denom: usize) -> WithT<Self> {
+        let len = self.range.len();
+        let inner = (self.interpolate, self.range.start);
+        Space::new(len, WithTInterpolation { inner, denom })
+    }
+}
+
+#[derive(Clone, Copy, Debug)]
+pub struct ZipInterpolation<A, B> {
+    a: (A, usize),
+    b: (B, usize),
+}
+
+impl<A: Interpolate, B: Interpolate> Interpolate for ZipInterpolation<A, B> {
+    type Item = (A::Item, B::Item);
+    fn interpolate(&self, x: usize) -> Self::Item {
+        let ZipInterpolation { a, b } = self;
+        (a.0.interpolate(a.1 + x), b.0.interpolate(b.1 + x))
+    }
+}
+
+impl<A: SpaceFmt, B: SpaceFmt> SpaceFmt for ZipInterpolation<A, B> {
+    const NAME: &'static str = "Zip";
+    fn fmt_params(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, ", inner: ({}, {})", A::NAME, B::NAME)
+    }
+}
+
+/// [`Iterator`] returned by [`zip_spaces`], pairing up the values of the spaces `A` and `B`
+pub type Zip<A, B> =
+    Space<ZipInterpolation<<A as SpaceType>::Interpolation, <B as SpaceType>::Interpolation>>;
+
+/// Combines two spaces of equal length into a single space of pairs
+///
+/// Like [`Iterator::zip`], but the result is still a [space](Space#adapters).
+///
+/// ```
+/// use iter_num_tools::{arange, lin_space, zip_spaces};
+///
+/// let it = zip_spaces(lin_space(0.0..=1.0, 3), arange(0.0..3.0, 1.0));
+/// assert_eq!(it.bounds(), Some((0.0, 0.0)..=(1.0, 2.0)));
+/// assert!(it.eq([(0.0, 0.0), (0.5, 1.0), (1.0, 2.0)]));
+/// ```
+///
+/// # Panics
+///
+/// Panics if the spaces have a different number of values remaining
+pub fn zip_spaces<A, B>(a: Space<A>, b: Space<B>) -> Zip<Space<A>, Space<B>> {
+    assert_eq!(
+        a.range.len(),
+        b.range.len(),
+        "zipped spaces must be the same length"
+    );
+    let len = a.range.len();
+    let a = (a.interpolate, a.range.start);
+    let b = (b.interpolate, b.range.start);
+    Space::new(len, ZipInterpolation { a, b })
+}
+
+#[derive(Clone, Copy, Debug)]
+pub struct ChainInterpolation<A, B> {
+    a: (A, usize),
+    b: (B, usize),
+    a_len: usize,
+}
+
+impl<A: Interpolate, B: Interpolate<Item = A::Item>> Interpolate for ChainInterpolation<A, B> {
+    type Item = A::Item;
+    fn interpolate(&self, x: usize) -> Self::Item {
+        let ChainInterpolation { a, b, a_len } = self;
+        match x.checked_sub(*a_len) {
+            None => a.0.interpolate(a.1 + x),
+            Some(x) => b.0.interpolate(b.1 + x),
+        }
+    }
+}
+
+impl<A: SpaceFmt, B: SpaceFmt<Item = A::Item>> SpaceFmt for ChainInterpolation<A, B> {
+    const NAME: &'static str = "Chain";
+    fn fmt_params(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, ", inner: ({}, {})", A::NAME, B::NAME)
+    }
+}
+
+/// [`Iterator`] returned by [`chain_spaces`], yielding the values of the space `A` then `B`
+pub type Chain<A, B> =
+    Space<ChainInterpolation<<A as SpaceType>::Interpolation, <B as SpaceType>::Interpolation>>;
+
+/// Joins two spaces over the same type into a single space
+///
+/// Like [`Iterator::chain`], but the result is still a [space](Space#adapters),
+/// with [`bounds`](Space::bounds) spanning both spaces.
+///
+/// ```
+/// use iter_num_tools::{chain_spaces, lin_space};
+///
+/// // dense near zero, sparse further out
+/// let it = chain_spaces(lin_space(0.0..1.0, 4), lin_space(1.0..=4.0, 4));
+/// assert_eq!(it.bounds(), Some(0.0..=4.0));
+/// assert!(it.eq([0.0, 0.25, 0.5, 0.75, 1.0, 2.0, 3.0, 4.0]));
+/// ```
+///
+/// # Panics
+///
+/// Panics if the combined length overflows a `usize`
+pub fn chain_spaces<A, B>(a: Space<A>, b: Space<B>) -> Chain<Space<A>, Space<B>> {
+    let a_len = a.range.len();
+    let len = a_len
+        .checked_add(b.range.len())
+        .expect("chained space is too large");
+    let a = (a.interpolate, a.range.start);
+    let b = (b.interpolate, b.range.start);
+    Space::new(len, ChainInterpolation { a, b, a_len })
+}
+
+#[derive(Clone, Copy, Debug)]
+pub struct StrideInterpolation<I> {
+    inner: (I, usize),
+    stride: usize,
+}
+
+impl<I: Interpolate> Interpolate for StrideInterpolation<I> {
+    type Item = I::Item;
+    fn interpolate(&self, x: usize) -> Self::Item {
+        let StrideInterpolation { inner, stride } = self;
+        inner.0.interpolate(inner.1 + x * stride)
+    }
+}
+
+impl<I: Monotonic> Monotonic for StrideInterpolation<I> {
+    fn direction(&self) -> Direction {
+        self.inner.0.direction()
+    }
+}
+
+impl<I: SpaceFmt> SpaceFmt for StrideInterpolation<I> {
+    const NAME: &'static str = "Stride";
+    fn fmt_params(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, ", stride: {}, inner: {}", self.stride, I::NAME)?;
+        self.inner.0.fmt_params(f)
+    }
+}
+
+/// [`Iterator`] returned by [`Space::stride`]
+pub type Stride<S> = Space<StrideInterpolation<<S as SpaceType>::Interpolation>>;
+
+impl<I> Space<I> {
+    /// Keeps every `step`th remaining value, starting with the first.
+    ///
+    /// Like [`Iterator::step_by`], but the result is still a [space](Space#adapters).
+    ///
+    /// ```
+    /// use iter_num_tools::lin_space;
+    ///
+    /// let it = lin_space(0.0..=1.0, 5).stride(2);
+    /// assert_eq!(it.bounds(), Some(0.0..=1.0));
+    /// assert!(it.rev().eq([1.0, 0.5, 0.0]));
+    /// ```
+    ///
+    /// # Panics
+    ///
+    /// Panics if `step` is zero
+    pub fn stride(self, step: usize) -> Stride<Self> {
+        assert!(step > 0, "stride must be non-zero");
+        let len = self.range.len().div_ceil(step);
+        let inner = (self.interpolate, self.range.start);
+        Space::new(
+            len,
+            StrideInterpolation {
+                inner,
+                stride: step,
+            },
+        )
+    }
+}
+
+#[derive(Clone, Copy, Debug)]
+pub struct ReversedInterpolation<I> {
+    inner: I,
+    end: usize,
+}
+
+impl<I: Interpolate> Interpolate for ReversedInterpolation<I> {
+    type Item = I::Item;
+    fn interpolate(&self, x: usize) -> Self::Item {
+        self.inner.interpolate(self.end - 1 - x)
+    }
+}
+
+impl<I: Monotonic> Monotonic for ReversedInterpolation<I> {
+    fn direction(&self) -> Direction {
+        match self.inner.direction() {
+            Direction::Increasing => Direction::Decreasing,
+            Direction::Decreasing => Direction::Increasing,
+            direction => direction,
+        }
+    }
+}
+
+impl<I: SpaceFmt> SpaceFmt for ReversedInterpolation<I> {
+    const NAME: &'static str = "Reversed";
+    fn fmt_params(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, ", inner: {}", I::NAME)?;
+        self.inner.fmt_params(f)
+    }
+}
+
+/// [`Iterator`] returned by [`Space::reversed`]
+pub type Reversed<S> = Space<ReversedInterpolation<<S as SpaceType>::Interpolation>>;
+
+impl<I> Space<I> {
+    /// Reverses the order of the remaining values.
+    ///
+    /// Like [`Iterator::rev`], but the result is still a [space](Space#adapters),
+    /// with its [`bounds`](Space::bounds) swapped around.
+    ///
+    /// ```
+    /// use iter_num_tools::lin_space;
+    ///
+    /// let it = lin_space(0.0..=1.0, 5).reversed();
+    /// assert_eq!(it.bounds(), Some(1.0..=0.0));
+    /// assert!(it.eq([1.0, 0.75, 0.5, 0.25, 0.0]));
+    /// ```
+    pub fn reversed(self) -> Reversed<Self> {
+        let len = self.range.len();
+        let end = self.range.end;
+        Space::new(
+            len,
+            ReversedInterpolation {
+                inner: self.interpolate,
+                end,
+            },
+        )
+    }
+}
+
+#[derive(Clone, Copy, Debug)]
+pub struct WindowsInterpolation<I, const W: usize> {
+    inner: (I, usize),
+}
+
+impl<I: Interpolate, const W: usize> Interpolate for WindowsInterpolation<I, W> {
+    type Item = [I::Item; W];
+    fn interpolate(&self, x: usize) -> Self::Item {
+        let (inner, offset) = &self.inner;
+        core::array::from_fn(|j| inner.interpolate(offset + x + j))
+    }
+}
+
+impl<I: SpaceFmt, const W: usize> SpaceFmt for WindowsInterpolation<I, W> {
+    const NAME: &'static str = "Windows";
+    fn fmt_params(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, ", inner: {}", I::NAME)?;
+        self.inner.0.fmt_params(f)
+    }
+}
+
+/// [`Iterator`] returned by [`Space::windows`], yielding windows of `W` values of the space `S`
+pub type Windows<S, const W: usize> =
+    Space<WindowsInterpolation<<S as SpaceType>::Interpolation, W>>;
+
+impl<I> Space<I> {
+    /// Yields every overlapping window of `W` consecutive remaining values.
+    ///
+    /// Each window is interpolated directly from its indices, so nothing is buffered,
+    /// and the result is still a [space](Space#adapters) with `W - 1` fewer values.
+    ///
+    /// ```
+    /// use iter_num_tools::lin_space;
+    ///
+    /// let it = lin_space(0.0..=2.0, 5).windows::<3>();
+    /// assert!(it.eq([[0.0, 0.5, 1.0], [0.5, 1.0, 1.5], [1.0, 1.5, 2.0]]));
+    /// ```
+    ///
+    /// # Panics
+    ///
+    /// Panics if `W` is zero
+    pub fn windows<const W: usize>(self) -> Windows<Self, W> {
+        assert!(W > 0, "window size must be non-zero");
+        let len = self.range.len().saturating_sub(W - 1);
+        let inner = (self.interpolate, self.range.start);
+        Space::new(len, WindowsInterpolation { inner })
+    }
+}
+
+#[derive(Clone, Copy, Debug)]
+pub struct PairwiseInterpolation<I> {
+    inner: (I, usize),
+}
+
+impl<I: Interpolate> Interpolate for PairwiseInterpolation<I> {
+    type Item = (I::Item, I::Item);
+    fn interpolate(&self, x: usize) -> Self::Item {
+        let (inner, offset) = &self.inner;
+        (
+            inner.interpolate(offset + x),
+            inner.interpolate(offset + x + 1),
+        )
+    }
+}
+
+impl<I: SpaceFmt> SpaceFmt for PairwiseInterpolation<I> {
+    const NAME: &'static str = "Pairwise";
+    fn fmt_params(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, ", inner: {}", I::NAME)?;
+        self.inner.0.fmt_params(f)
+    }
+}
+
+/// [`Iterator`] returned by [`Space::pairwise`]
+pub type Pairwise<S> = Space<PairwiseInterpolation<<S as SpaceType>::Interpolation>>;
+
+impl<I> Space<I> {
+    /// Yields each pair of consecutive remaining values,
+    /// which are the intervals between the points of the space.
+    ///
+    /// There is one fewer interval than there are points.
+    ///
+    /// ```
+    /// use iter_num_tools::lin_space;
+    ///
+    /// let it = lin_space(0.0..=1.0, 3).pairwise();
+    /// assert!(it.eq([(0.0, 0.5), (0.5, 1.0)]));
+    /// ```
+    pub fn pairwise(self) -> Pairwise<Self> {
+        let len = self.range.len().saturating_sub(1);
+        let inner = (self.interpolate, self.range.start);
+        Space::new(len, PairwiseInterpolation { inner })
+    }
+}
+
+#[derive(Clone, Copy, Debug)]
+pub struct MapWithBoundsInterpolation<I, F, G> {
+    map: MapInterpolation<I, F>,
+    f_inv: G,
+}
+
+impl<I: Interpolate, F: Fn(I::Item) -> U, G, U> Interpolate
+    for MapWithBoundsInterpolation<I, F, G>
+{
+    type Item = U;
+    fn interpolate(&self, x: usize) -> U {
+        self.map.interpolate(x)
+    }
+
+    const EXACT_FOLD: bool = I::EXACT_FOLD;
+
+    fn fold_range<B, H>(&self, range: Range<usize>, init: B, h: H) -> B
+    where
+        H: FnMut(B, U) -> B,
+    {
+        self.map.fold_range(range, init, h)
+    }
+}
+
+impl<I: Interpolate, F: Fn(I::Item) -> U, G, U> Monotonic for MapWithBoundsInterpolation<I, F, G> {
+    fn direction(&self) -> Direction {
+        Direction::NonMonotonic
+    }
+}
+
+impl<I: SpaceFmt, F: Fn(I::Item) -> U, G, U> SpaceFmt for MapWithBoundsInterpolation<I, F, G> {
+    const NAME: &'static str = "MapWithBounds";
+    fn fmt_params(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.map.fmt_params(f)
+    }
+}
+
+/// [`Iterator`] returned by [`Space::map_with_bounds`], mapping the values of the space `S` with `F`, undone by `G`
+pub type MapWithBounds<S, F, G> =
+    Space<MapWithBoundsInterpolation<<S as SpaceType>::Interpolation, F, G>>;
+
+impl<I: Interpolate> Space<I> {
+    /// Transforms every value with `f`, where `f_inv` undoes it.
+    ///
+    /// Like [`map_fn`](Space::map_fn), the result is still a [space](Space#adapters),
+    /// so [`bounds`](Space::bounds) reports the transformed first and last values.
+    /// Values can also be located in the space by undoing the transform,
+    /// such as with [`position_of`](Space::position_of).
+    /// This can turn a linear space into one over another scale or unit.
+    ///
+    /// ```
+    /// use iter_num_tools::lin_space;
+    ///
+    /// // evenly spaced in celsius, shown in fahrenheit
+    /// let it = lin_space(0.0..=100.0, 5).map_with_bounds(|c| c * 1.8 + 32.0, |f| (f - 32.0) / 1.8);
+    /// assert_eq!(it.bounds(), Some(32.0..=212.0));
+    /// assert_eq!(it.position_of(122.0), 2.0);
+    /// assert!(it.eq([32.0, 77.0, 122.0, 167.0, 212.0]));
+    /// ```
+    pub fn map_with_bounds<U, F, G>(self, f: F, f_inv: G) -> MapWithBounds<Self, F, G>
+    where
+        F: Fn(I::Item) -> U,
+        G: Fn(U) -> I::Item,
+    {
+        self.map_interpolate(|inner| MapWithBoundsInterpolation {
+            map: MapInterpolation { inner, f },
+            f_inv,
+        })
+    }
+}
+
+impl<I: Interpolate, F: Fn(I::Item) -> U, G: Fn(U) -> I::Item, U>
+    Space<MapWithBoundsInterpolation<I, F, G>>
+{
+    /// Undoes the transform, giving the value of the original space
+    ///
+    /// ```
+    /// use iter_num_tools::lin_space;
+    ///
+    /// let it = lin_space(0.0..=3.0, 4).map_with_bounds(|x| x * 2.0, |y| y / 2.0);
+    /// assert_eq!(it.invert(3.0), 1.5);
+    /// ```
+    pub fn invert(&self, value: U) -> I::Item {
+        (self.interpolate.f_inv)(value)
+    }
+}
+
+impl<T, F, G, U> Space<MapWithBoundsInterpolation<LinearInterpolation<T>, F, G>>
+where
+    T: Num + FromPrimitive + Clone,
+    F: Fn(T) -> U,
+    G: Fn(U) -> T,
+{
+    /// The index in the space where `value` would be, found by undoing the transform.
+    /// See [`LinSpace::position_of`](crate::LinSpace::position_of)
+    pub fn position_of(&self, value: U) -> T {
+        self.interpolate.map.inner.position_of(self.invert(value))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{check_double_ended_iter, lin_space};
+
+    #[test]
+    fn test_with_index() {
+        check_double_ended_iter(
+            lin_space(0.0..2.0, 4).with_index(),
+            [(0, 0.0), (1, 0.5), (2, 1.0), (3, 1.5)],
+        );
+
+        let mut it = lin_space(0.0..2.0, 4);
+        it.next();
+        let mut it = it.with_index();
+        assert_eq!(it.len(), 3);
+        assert_eq!(it.nth_back(1), Some((2, 1.0)));
+        assert_eq!(it.next(), Some((1, 0.5)));
+        assert_eq!(it.next(), None);
+    }
+
+    #[test]
+    fn test_with_t() {
+        check_double_ended_iter(
+            lin_space(0..=8, 5).with_t(),
+            [(0.0, 0), (0.25, 2), (0.5, 4), (0.75, 6), (1.0, 8)],
+        );
+        check_double_ended_iter(
+            lin_space(0..8, 4).with_t_exclusive(),
+            [(0.0, 0), (0.25, 2), (0.5, 4), (0.75, 6)],
+        );
+        check_double_ended_iter(lin_space(3..=3, 1).with_t(), [(0.0, 3)]);
+        assert_eq!(lin_space(0..8, 0).with_t().next(), None);
+
+        // t runs over the values that are left
+        let mut it = lin_space(0..=8, 5);
+        it.next();
+        it.next_back();
+        check_double_ended_iter(it.with_t(), [(0.0, 2), (0.5, 4), (1.0, 6)]);
+    }
+
+    #[test]
+    fn test_reversed() {
+        let mut it = lin_space(0..10, 5);
+        it.next();
+        let it = it.reversed();
+        let bounds = it.bounds().unwrap();
+        assert_eq!((*bounds.start(), *bounds.end()), (8, 2));
+        assert_eq!(it.direction(), Direction::Decreasing);
+        check_double_ended_iter(it.clone(), [8, 6, 4, 2]);
+        check_double_ended_iter(it.reversed(), [2, 4, 6, 8]);
+        assert_eq!(lin_space(0..10, 0).reversed().bounds(), None);
+    }
+
+    #[test]
+    fn test_zip_spaces() {
+        let mut a = lin_space(0.0..4.0, 4);
+        a.next();
+        let mut b = lin_space(0..8, 4);
+        b.next_back();
+        let it = zip_spaces(a, b);
+        assert_eq!(it.bounds(), Some((1.0, 0)..=(3.0, 4)));
+        check_double_ended_iter(it, [(1.0, 0), (2.0, 2), (3.0, 4)]);
+    }
+
+    #[test]
+    #[should_panic(expected = "zipped spaces must be the same length")]
+    fn test_zip_spaces_mismatched() {
+        zip_spaces(lin_space(0.0..4.0, 4), lin_space(0.0..4.0, 3));
+    }
+
+    #[test]
+    fn test_chain_spaces() {
+        let mut a = lin_space(0.0..4.0, 4);
+        a.next();
+        let mut b = lin_space(10.0..=20.0, 3);
+        b.next_back();
+        check_double_ended_iter(chain_spaces(a, b), [1.0, 2.0, 3.0, 10.0, 15.0]);
+
+        let it = chain_spaces(lin_space(0.0..4.0, 0), lin_space(1.0..=2.0, 2));
+        assert_eq!(it.bounds(), Some(1.0..=2.0));
+        let it = chain_spaces(lin_space(0.0..4.0, 0), lin_space(1.0..=2.0, 0));
+        assert_eq!(it.bounds(), None);
+
+        let mut it = chain_spaces(lin_space(0.0..4.0, 4), lin_space(4.0..=6.0, 3));
+        assert_eq!(it.nth(5), Some(5.0));
+        assert_eq!(it.nth_back(0), Some(6.0));
+        assert_eq!(it.len(), 0);
+    }
+
+    #[test]
+    fn test_stride() {
+        let mut it = lin_space(0..10, 10);
+        it.next();
+        check_double_ended_iter(it.clone().stride(3), [1, 4, 7]);
+        check_double_ended_iter(it.clone().stride(4), [1, 5, 9]);
+        check_double_ended_iter(it.stride(1).stride(20), [1]);
+        assert_eq!(lin_space(0..10, 0).stride(2).len(), 0);
+        assert_eq!(
+            format!("{:?}", lin_space(0.0..=1.0, 3).stride(2)),
+            "Stride { 0.0..=1.0, len: 2, stride: 2, inner: LinSpace, step: 0.5 }"
+        );
+    }
+
+    #[test]
+    #[should_panic(expected = "stride must be non-zero")]
+    fn test_stride_zero() {
+        lin_space(0..10, 10).stride(0);
+    }
+
+    #[test]
+    fn test_windows() {
+        let mut it = lin_space(0..5, 5);
+        it.next_back();
+        check_double_ended_iter(it.clone().windows::<2>(), [[0, 1], [1, 2], [2, 3]]);
+        check_double_ended_iter(it.clone().windows::<4>(), [[0, 1, 2, 3]]);
+        assert_eq!(it.clone().windows::<5>().len(), 0);
+        check_double_ended_iter(it.windows::<1>(), [[0], [1], [2], [3]]);
+    }
+
+    #[test]
+    fn test_pairwise() {
+        let mut it = lin_space(0..5, 5);
+        it.next();
+        let it = it.pairwise();
+        assert_eq!(it.bounds(), Some((1, 2)..=(3, 4)));
+        check_double_ended_iter(it, [(1, 2), (2, 3), (3, 4)]);
+        assert_eq!(lin_space(0..5, 1).pairwise().len(), 0);
+        assert_eq!(lin_space(0..5, 0).pairwise().len(), 0);
+    }
+
+    #[test]
+    fn test_map_with_bounds() {
+        let it = lin_space(0.0..=2.0, 3).map_with_bounds(|x: f64| x.exp2(), f64::log2);
+        check_double_ended_iter(it.clone(), [1.0, 2.0, 4.0]);
+        assert!((it.position_of(2.0_f64.sqrt()) - 0.5).abs() < 1e-12);
+        assert_eq!(it.invert(8.0), 3.0);
+
+        let mut it = it;
+        it.next();
+        assert_eq!(it.bounds(), Some(2.0..=4.0));
+        assert_eq!(it.position_of(4.0), 2.0);
+        assert_eq!(
+            format!("{it:?}"),
+            "MapWithBounds { 2.0..=4.0, len: 2, inner: LinSpace, step: 1.0 }"
+        );
+    }
+
+    #[test]
+    fn test_map_with_bounds_fold() {
+        // folds through the inner space the same way as map_fn
+        let it = crate::poly_space([0.1, 0.2, 0.3], 0.0..=10.0, 1000);
+        let bounded = it.clone().map_with_bounds(|x: f64| x * 3.0, |y| y / 3.0);
+        let mapped = it.map_fn(|x: f64| x * 3.0);
+        assert_eq!(bounded.clone().sum::<f64>(), mapped.clone().sum::<f64>());
+        assert!(bounded.eq(mapped));
+    }
+
+    #[test]
+    fn test_nameable_types() {
+        use crate::{arange, Arange, LinSpace};
+
+        struct Adapted {
+            indexed: WithIndex<Stride<LinSpace<u32>>>,
+            pairs: Pairwise<Reversed<LinSpace<u32>>>,
+            windows: Windows<LinSpace<u32>, 2>,
+            zipped: Zip<LinSpace<f64>, Arange<f64>>,
+            chained: Chain<LinSpace<u32>, LinSpace<u32>>,
+            with_t: WithT<LinSpace<u32>>,
+        }
+
+        let it = lin_space(0..4, 4);
+        let adapted = Adapted {
+            indexed: it.clone().stride(2).with_index(),
+            pairs: it.clone().reversed().pairwise(),
+            windows: it.clone().windows(),
+            zipped: zip_spaces(lin_space(0.0..4.0, 4), arange(4.0..8.0, 1.0)),
+            chained: chain_spaces(it.clone(), it.clone()),
+            with_t: it.with_t(),
+        };
+        assert!(adapted.indexed.eq([(0, 0), (1, 2)]));
+        assert!(adapted.pairs.eq([(3, 2), (2, 1), (1, 0)]));
+        assert!(adapted.windows.eq([[0, 1], [1, 2], [2, 3]]));
+        assert!(adapted
+            .zipped
+            .eq([(0.0, 4.0), (1.0, 5.0), (2.0, 6.0), (3.0, 7.0)]));
+        assert_eq!(adapted.chained.len(), 8);
+        assert_eq!(adapted.with_t.last(), Some((1.0, 3)));
+    }
+}
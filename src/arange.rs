@@ -1,12 +1,20 @@
-use crate::linspace::{IntoLinSpace, LinSpace, LinearInterpolation};
+use crate::{
+    error::Error,
+    linspace::{is_finite, LinearInterpolation},
+    space::{Interpolate, IntoSpace, Space},
+    unbounded::Unbounded,
+};
 use core::ops::Range;
 use num_traits::real::Real;
 
 /// [`Iterator`] returned by [`arange`]
-pub type Arange<T> = LinSpace<T>;
+///
+/// `D` is the type of the step, which is the same as the values for numbers,
+/// but may differ for types like timestamps
+pub type Arange<T, D = T> = Space<LinearInterpolation<T, D>>;
 
 /// [`IntoIterator`] returned by [`ToArange::into_arange`]
-pub type IntoArange<T> = IntoLinSpace<T>;
+pub type IntoArange<T, D = T> = IntoSpace<LinearInterpolation<T, D>>;
 
 /// Create a new iterator over the range, stepping by `step` each time
 /// This allows you to create simple float iterators
@@ -17,19 +25,73 @@ pub type IntoArange<T> = IntoLinSpace<T>;
 /// let it = arange(0.0..2.0, 0.5);
 /// assert!(it.eq(vec![0.0, 0.5, 1.0, 1.5]));
 /// ```
-pub fn arange<R, F>(range: R, step: F) -> Arange<R::Item>
+///
+/// # Panics
+///
+/// Panics if the step is zero or points away from the end, or if the number of values overflows a `usize`.
+/// Use [`try_arange`] to check this instead.
+///
+/// With the `chrono` or `time` features enabled, ranges of timestamps can be stepped
+/// through by a duration, such as `arange(start..end, TimeDelta::minutes(5))`.
+pub fn arange<R, F>(range: R, step: F) -> Arange<R::Item, F>
 where
     R: ToArange<F>,
 {
     range.into_arange(step).into_space()
 }
 
+/// Create a new iterator over the range, stepping by `step` each time,
+/// returning an error instead of panicking if the step is invalid
+///
+/// ```
+/// use iter_num_tools::{try_arange, Error};
+///
+/// let it = try_arange(0.0..2.0, 0.5).unwrap();
+/// assert!(it.eq(vec![0.0, 0.5, 1.0, 1.5]));
+///
+/// assert_eq!(try_arange(0.0..2.0, -0.5).unwrap_err(), Error::SignMismatch);
+/// assert_eq!(try_arange(0.0..2.0, 0.0).unwrap_err(), Error::ZeroSteps);
+/// ```
+pub fn try_arange<R, F>(range: R, step: F) -> Result<Arange<R::Item, F>, Error>
+where
+    R: ToArange<F>,
+{
+    range.try_into_arange(step).map(IntoSpace::into_space)
+}
+
+/// [`Iterator`] returned by [`arange_from`]
+pub type ArangeFrom<T, D = T> = Unbounded<LinearInterpolation<T, D>>;
+
+/// Create an iterator that starts at `start` and steps by `step` forever
+///
+/// Each value is computed directly from its index, so errors don't build up from repeatedly
+/// adding the step, and [`nth`](Iterator::nth) skips ahead in constant time.
+///
+/// ```
+/// use iter_num_tools::arange_from;
+///
+/// let mut it = arange_from(0.0, 0.25);
+/// assert!(it.by_ref().take(3).eq([0.0, 0.25, 0.5]));
+/// assert_eq!(it.nth(1_000_000), Some(250_000.75));
+/// assert_eq!(it.size_hint(), (usize::MAX - 1_000_004, None));
+/// ```
+///
+/// With the `chrono` or `time` features enabled, this also steps timestamps by a duration.
+pub fn arange_from<T, D>(start: T, step: D) -> ArangeFrom<T, D>
+where
+    LinearInterpolation<T, D>: Interpolate,
+{
+    Unbounded::new(LinearInterpolation { start, step })
+}
+
 /// Helper trait for [`arange`]
 pub trait ToArange<S> {
     /// The item that this is a arange space over
     type Item;
     /// Create the arange space
-    fn into_arange(self, step: S) -> IntoArange<Self::Item>;
+    fn into_arange(self, step: S) -> IntoArange<Self::Item, S>;
+    /// Create the arange space, validating the range and step
+    fn try_into_arange(self, step: S) -> Result<IntoArange<Self::Item, S>, Error>;
 }
 
 impl<F: Real> ToArange<F> for Range<F> {
@@ -39,10 +101,91 @@ impl<F: Real> ToArange<F> for Range<F> {
         let Range { start, end } = self;
 
         IntoArange::new(
-            ((end - start) / step).ceil().to_usize().unwrap(),
+            ((end - start) / step)
+                .ceil()
+                .to_usize()
+                .expect("arange step must be finite, non-zero and towards the end"),
             LinearInterpolation { start, step },
         )
     }
+
+    fn try_into_arange(self, step: F) -> Result<IntoArange<Self::Item>, Error> {
+        ArangeConfig { epsilon: F::zero() }.try_into_arange(self, step)
+    }
+}
+
+/// Controls how [`arange`] decides whether the last step is still before the end of the range
+///
+/// The number of values is `ceil((end - start) / step - epsilon)`, where `epsilon` is measured in steps.
+/// When the range is meant to divide evenly, rounding can leave the quotient just above
+/// a whole number, adding a value that lands on the end of the range.
+/// A small positive epsilon leaves that value out, while a small negative epsilon
+/// keeps the end of the range as a value, like an inclusive range.
+///
+/// ```
+/// use iter_num_tools::{arange, ArangeConfig};
+///
+/// // (1.3 - 1.0) / 0.1 rounds up to 3.0000000000000004
+/// assert_eq!(arange(1.0..1.3, 0.1).len(), 4);
+///
+/// let config = ArangeConfig { epsilon: 1e-9 };
+/// assert_eq!(config.arange(1.0..1.3, 0.1).len(), 3);
+///
+/// let inclusive = ArangeConfig { epsilon: -1e-9 };
+/// assert!(inclusive.arange(0.0..1.0, 0.25).eq([0.0, 0.25, 0.5, 0.75, 1.0]));
+/// ```
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+pub struct ArangeConfig<F> {
+    /// How many steps short of the end the last value must be, or past it if negative
+    pub epsilon: F,
+}
+
+impl<F: Real> ArangeConfig<F> {
+    /// Create a new iterator over the range, stepping by `step` each time
+    ///
+    /// # Panics
+    ///
+    /// Panics if [`try_arange`](Self::try_arange) would return an error
+    pub fn arange(&self, range: Range<F>, step: F) -> Arange<F> {
+        match self.try_arange(range, step) {
+            Ok(space) => space,
+            Err(err) => panic!("invalid arange: {err}"),
+        }
+    }
+
+    /// Create a new iterator over the range, stepping by `step` each time,
+    /// returning an error instead of panicking if the step is invalid
+    ///
+    /// ```
+    /// use iter_num_tools::{ArangeConfig, Error};
+    ///
+    /// let config = ArangeConfig { epsilon: f64::NAN };
+    /// assert_eq!(config.try_arange(0.0..1.0, 0.5).unwrap_err(), Error::NonFinite);
+    /// ```
+    pub fn try_arange(&self, range: Range<F>, step: F) -> Result<Arange<F>, Error> {
+        self.try_into_arange(range, step).map(IntoSpace::into_space)
+    }
+
+    pub(crate) fn try_into_arange(&self, range: Range<F>, step: F) -> Result<IntoArange<F>, Error> {
+        let Range { start, end } = range;
+        if !is_finite(&start) || !is_finite(&end) || !is_finite(&step) || !is_finite(&self.epsilon)
+        {
+            return Err(Error::NonFinite);
+        }
+        if step.is_zero() {
+            return Err(Error::ZeroSteps);
+        }
+        if (end - start) * step < F::zero() {
+            return Err(Error::SignMismatch);
+        }
+        let len = ((end - start) / step - self.epsilon)
+            .ceil()
+            .max(F::zero())
+            .to_usize()
+            .ok_or(Error::Overflow)?;
+
+        Ok(IntoArange::new(len, LinearInterpolation { start, step }))
+    }
 }
 
 #[cfg(test)]
@@ -54,4 +197,64 @@ mod tests {
         let it = arange(0.0..2.0, 0.5);
         assert!(it.eq(vec![0.0, 0.5, 1.0, 1.5]));
     }
+
+    #[test]
+    fn test_try_arange() {
+        assert!(try_arange(2.0..0.0, -0.5)
+            .unwrap()
+            .eq(vec![2.0, 1.5, 1.0, 0.5]));
+        assert!(try_arange(1.0..1.0, 0.5).unwrap().eq(Vec::<f64>::new()));
+        assert_eq!(try_arange(2.0..0.0, 0.5).unwrap_err(), Error::SignMismatch);
+        assert_eq!(try_arange(0.0..2.0, 0.0).unwrap_err(), Error::ZeroSteps);
+        assert_eq!(
+            try_arange(0.0..2.0, f64::NAN).unwrap_err(),
+            Error::NonFinite
+        );
+        assert_eq!(
+            try_arange(0.0..f64::INFINITY, 1.0).unwrap_err(),
+            Error::NonFinite
+        );
+        assert_eq!(try_arange(0.0..1e300, 1e-300).unwrap_err(), Error::Overflow);
+    }
+
+    #[test]
+    fn test_arange_config() {
+        let exclude = ArangeConfig { epsilon: 1e-9 };
+        let include = ArangeConfig { epsilon: -1e-9 };
+        for (start, end, step, len) in [
+            (1.0, 1.3, 0.1, 3),
+            (0.0, 0.3, 0.1, 3),
+            (0.0, 0.7, 0.1, 7),
+            (2.0, 1.4, -0.2, 3),
+            (0.0, 1.0, 0.3, 4),
+            (0.0, 0.0, 1.0, 0),
+        ] {
+            assert_eq!(
+                exclude.arange(start..end, step).len(),
+                len,
+                "{start}..{end}"
+            );
+            let inclusive = include.arange(start..end, step);
+            let reaches_end = (inclusive.clone().last().unwrap_or(start) - end).abs() < 1e-9;
+            let expected = if reaches_end { len + 1 } else { len };
+            assert_eq!(inclusive.len(), expected, "{start}..={end}");
+        }
+        assert_eq!(
+            ArangeConfig { epsilon: 10.0 }.arange(0.0..1.0, 0.5).len(),
+            0
+        );
+        assert_eq!(
+            ArangeConfig::default().arange(1.0..1.3, 0.1).len(),
+            arange(1.0..1.3, 0.1).len()
+        );
+    }
+
+    #[test]
+    fn test_arange_from() {
+        let mut it = arange_from(1.0, -0.5);
+        assert_eq!(it.size_hint(), (usize::MAX, None));
+        assert!(it.by_ref().take(4).eq([1.0, 0.5, 0.0, -0.5]));
+        assert_eq!(it.nth(5), Some(-3.5));
+        assert_eq!(format!("{it:?}"), "LinSpace { -4.0.., step: -0.5 }");
+    }
 }
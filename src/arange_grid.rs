@@ -1,15 +1,18 @@
 use crate::{
     arange::ToArange,
-    gridspace::{GridSpace, GridSpaceInterpolation},
+    gridspace::{grid_len, GridSpace, GridSpaceInterpolation},
+    transpose::Transpose,
     IntoGridSpace,
 };
 use array_bin_ops::Array;
 use core::ops::Range;
 
 /// [`Iterator`] returned by [`arange_grid`]
+///
+/// The same type as [`GridSpace`], with `N` axes of `T` coordinates.
 pub type ArangeGrid<T, const N: usize> = GridSpace<T, N>;
 
-/// [`IntoIterator`] returned by [`ToGridSpace::into_grid_space`]
+/// [`IntoIterator`] returned by [`ToArangeGrid::into_arange_grid`]
 pub type IntoArangeGrid<T, const N: usize> = IntoGridSpace<T, N>;
 
 /// Creates a grid space over the range made up of fixed step intervals
@@ -42,6 +45,10 @@ pub type IntoArangeGrid<T, const N: usize> = IntoGridSpace<T, N>;
 ///     [0.0, 1.0, 1.0], [1.0, 1.0, 1.0],
 /// ]));
 /// ```
+///
+/// # Panics
+///
+/// Panics if the total number of points in the grid overflows a `usize`
 pub fn arange_grid<R, S, const N: usize>(range: R, step: S) -> ArangeGrid<R::Item, N>
 where
     R: ToArangeGrid<S, N>,
@@ -57,43 +64,32 @@ pub trait ToArangeGrid<S, const N: usize> {
     fn into_arange_grid(self, step: S) -> IntoArangeGrid<Self::Item, N>;
 }
 
-impl<F: Copy, const N: usize> ToArangeGrid<[F; N], N> for Range<[F; N]>
+impl<F: Clone, const N: usize> ToArangeGrid<[F; N], N> for Range<[F; N]>
 where
-    Range<F>: ToArange<F>,
+    Range<F>: ToArange<F, Item = F>,
 {
-    type Item = <Range<F> as ToArange<F>>::Item;
+    type Item = F;
 
     fn into_arange_grid(self, step: [F; N]) -> IntoArangeGrid<Self::Item, N> {
-        let Range { start, end } = self;
-
-        let mut len = 1;
-        let ranges = Array(start).zip_map(end, |start, end| start..end);
-        let lerps = Array(ranges).zip_map(step, |range, step| {
-            let space = range.into_arange(step);
-            len *= space.len;
-            space
-        });
+        let lerps = Array(self.transpose()).zip_map(step, ToArange::into_arange);
+        let len = grid_len(lerps.iter().map(|space| space.len));
 
-        IntoArangeGrid::new(len, GridSpaceInterpolation(lerps))
+        IntoArangeGrid::new(len, GridSpaceInterpolation::new(lerps))
     }
 }
-impl<F: Copy, const N: usize> ToArangeGrid<F, N> for Range<[F; N]>
+impl<F: Clone, const N: usize> ToArangeGrid<F, N> for Range<[F; N]>
 where
-    Range<F>: ToArange<F>,
+    Range<F>: ToArange<F, Item = F>,
 {
-    type Item = <Range<F> as ToArange<F>>::Item;
+    type Item = F;
 
     fn into_arange_grid(self, step: F) -> IntoArangeGrid<Self::Item, N> {
         let Range { start, end } = self;
 
-        let mut len = 1;
-        let lerps = Array(start).zip_map(end, |start, end| {
-            let space = (start..end).into_arange(step);
-            len *= space.len;
-            space
-        });
+        let lerps = Array(start).zip_map(end, |start, end| (start..end).into_arange(step.clone()));
+        let len = grid_len(lerps.iter().map(|space| space.len));
 
-        IntoArangeGrid::new(len, GridSpaceInterpolation(lerps))
+        IntoArangeGrid::new(len, GridSpaceInterpolation::new(lerps))
     }
 }
 
@@ -0,0 +1,77 @@
+//! Random spaces for property tests with [`proptest`], such as `any::<LinSpace<f64>>()`.
+//!
+//! The bounds are always finite and the spaces have at most [`MAX_LEN`] values,
+//! so properties can iterate over every value without slowing the test down.
+
+use proptest::prelude::*;
+
+use crate::{lin_space, log_space, LinSpace, LogSpace};
+
+/// The most values an arbitrary space can have
+const MAX_LEN: usize = 1000;
+
+macro_rules! arbitrary_spaces {
+    ($($t:ty),*) => {$(
+        /// Spaces between any two values from -10<sup>6</sup> to 10<sup>6</sup>,
+        /// which may or may not include the end
+        impl Arbitrary for LinSpace<$t> {
+            type Parameters = ();
+            type Strategy = BoxedStrategy<Self>;
+
+            fn arbitrary_with((): ()) -> Self::Strategy {
+                let bound = -1e6 as $t..1e6;
+                (bound.clone(), bound, 0..=MAX_LEN, any::<bool>())
+                    .prop_map(|(start, end, steps, inclusive)| match inclusive {
+                        true => lin_space(start..=end, steps),
+                        false => lin_space(start..end, steps),
+                    })
+                    .boxed()
+            }
+        }
+
+        /// Spaces between any two values from 10<sup>-6</sup> to 10<sup>6</sup>, or the same negated,
+        /// which may or may not include the end
+        impl Arbitrary for LogSpace<$t> {
+            type Parameters = ();
+            type Strategy = BoxedStrategy<Self>;
+
+            fn arbitrary_with((): ()) -> Self::Strategy {
+                let exponent = -6.0 as $t..6.0;
+                let sign = prop_oneof![Just(1.0 as $t), Just(-1.0)];
+                (exponent.clone(), exponent, sign, 0..=MAX_LEN, any::<bool>())
+                    .prop_map(|(start, end, sign, steps, inclusive)| {
+                        let start = sign * <$t>::powf(10.0, start);
+                        let end = sign * <$t>::powf(10.0, end);
+                        match inclusive {
+                            true => log_space(start..=end, steps),
+                            false => log_space(start..end, steps),
+                        }
+                    })
+                    .boxed()
+            }
+        }
+    )*};
+}
+
+arbitrary_spaces!(f32, f64);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    proptest! {
+        #[test]
+        fn test_arbitrary_lin_space(space in any::<LinSpace<f64>>()) {
+            prop_assert!(space.len() <= MAX_LEN);
+            prop_assert!(space.clone().all(f64::is_finite));
+            space.verify_invariants();
+        }
+
+        #[test]
+        fn test_arbitrary_log_space(space in any::<LogSpace<f32>>()) {
+            prop_assert!(space.len() <= MAX_LEN);
+            prop_assert!(space.clone().all(|x| x.is_finite() && x != 0.0));
+            space.verify_invariants();
+        }
+    }
+}
@@ -0,0 +1,89 @@
+use core::ops::{Range, RangeInclusive};
+
+use crate::{
+    error::Error,
+    linspace::{IntoLinSpace, ToLinSpace},
+};
+
+/// A range along a single axis of a grid, which can include its end point, exclude it,
+/// or exclude it as periodic, where the end wraps back around to the start.
+///
+/// This allows mixing inclusive and exclusive axes in [`grid_space`](crate::grid_space)
+/// and [`grid_step`](crate::grid_step)
+///
+/// ```
+/// use iter_num_tools::{grid_step, AxisRange};
+///
+/// // closed in the x direction, open in the y direction
+/// let it = grid_step([AxisRange::from(0..=1), AxisRange::from(0..2)]);
+/// assert!(it.eq([
+///     [0, 0], [1, 0],
+///     [0, 1], [1, 1],
+/// ]));
+/// ```
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum AxisRange<T> {
+    /// Excludes the end point, like [`Range`]
+    Exclusive(Range<T>),
+    /// Includes the end point, like [`RangeInclusive`]
+    Inclusive(RangeInclusive<T>),
+    /// Excludes the end point, which is treated as wrapping back around to the start.
+    ///
+    /// Useful for angular coordinates or simulations on a torus. [`GridSpace::wrap_index`](crate::GridSpace::wrap_index)
+    /// maps out of range indices along periodic axes back into the grid.
+    Periodic(Range<T>),
+}
+
+impl<T> From<Range<T>> for AxisRange<T> {
+    fn from(range: Range<T>) -> Self {
+        AxisRange::Exclusive(range)
+    }
+}
+
+impl<T> From<RangeInclusive<T>> for AxisRange<T> {
+    fn from(range: RangeInclusive<T>) -> Self {
+        AxisRange::Inclusive(range)
+    }
+}
+
+impl<T> ToLinSpace for AxisRange<T>
+where
+    Range<T>: ToLinSpace<Item = T>,
+    RangeInclusive<T>: ToLinSpace<Item = T>,
+{
+    type Item = T;
+
+    fn into_lin_space(self, steps: usize) -> IntoLinSpace<Self::Item> {
+        match self {
+            AxisRange::Exclusive(range) | AxisRange::Periodic(range) => range.into_lin_space(steps),
+            AxisRange::Inclusive(range) => range.into_lin_space(steps),
+        }
+    }
+
+    fn try_into_lin_space(self, steps: usize) -> Result<IntoLinSpace<Self::Item>, Error> {
+        match self {
+            AxisRange::Exclusive(range) | AxisRange::Periodic(range) => {
+                range.try_into_lin_space(steps)
+            }
+            AxisRange::Inclusive(range) => range.try_into_lin_space(steps),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::lin_space;
+
+    #[test]
+    fn test_axis_range_lin_space() {
+        let it = lin_space(AxisRange::from(0.0..2.0), 4);
+        assert!(it.eq(vec![0.0, 0.5, 1.0, 1.5]));
+
+        let it = lin_space(AxisRange::from(0.0..=2.0), 5);
+        assert!(it.eq(vec![0.0, 0.5, 1.0, 1.5, 2.0]));
+
+        let it = lin_space(AxisRange::Periodic(0.0..2.0), 4);
+        assert!(it.eq(vec![0.0, 0.5, 1.0, 1.5]));
+    }
+}
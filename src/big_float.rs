@@ -0,0 +1,203 @@
+//! Spaces over arbitrary precision floats from the [`dashu_float`] crate.
+//!
+//! Spaces only need their values to be [`Clone`], so [`lin_space`](crate::lin_space) works on [`FBig`] as it is.
+//! Each value is computed at the precision of the range, so give its ends as many digits as the values need.
+//!
+//! [`log_space`](crate::log_space) and [`arange`](crate::arange) are built on the `Copy` floats of [`Real`](num_traits::real::Real),
+//! so this has its own [`log_space`], using the `ln` and `exp` of [`FBig`].
+//!
+//! `dashu-float` is pure Rust and works without `std`, unlike `rug`, which needs a system GMP.
+//!
+//! ```
+//! use dashu_float::DBig;
+//! use iter_num_tools::lin_space;
+//!
+//! let one = DBig::ONE.with_precision(30).value();
+//! let third = lin_space(DBig::ZERO..=one, 4).nth(1).unwrap();
+//! assert_eq!(third.to_string(), "0.333333333333333333333333333333");
+//! ```
+
+use core::fmt;
+use core::ops::Range;
+
+use dashu_float::{round::Round, FBig, Word};
+
+use crate::{
+    axis::AxisRange,
+    error::Error,
+    space::{Interpolate, IntoSpace, Space, SpaceFmt},
+};
+
+/// Creates a logarithmic space over a range of arbitrary precision floats with a fixed number of steps,
+/// as in [`log_space`](crate::log_space)
+///
+/// ```
+/// use dashu_float::DBig;
+/// use iter_num_tools::big_float::log_space;
+/// use num_traits::Signed;
+///
+/// let start = DBig::ONE.with_precision(40).value();
+/// let end = DBig::from(1000).with_precision(40).value();
+///
+/// let it = log_space(start..=end, 4);
+/// let tens = [1, 10, 100, 1000].map(DBig::from);
+/// assert!(it.zip(tens).all(|(x, y)| ((x - &y) / y).abs() < DBig::from_parts(1.into(), -35)));
+/// ```
+///
+/// # Panics
+///
+/// Panics if the range starts or ends at zero, or crosses zero.
+/// Use [`try_log_space`] to check this instead.
+pub fn log_space<R: Round, const B: Word>(
+    range: impl Into<AxisRange<FBig<R, B>>>,
+    steps: usize,
+) -> BigLogSpace<R, B> {
+    match try_log_space(range, steps) {
+        Ok(it) => it,
+        Err(err) => panic!("invalid log space: {err}"),
+    }
+}
+
+/// Creates a logarithmic space over a range of arbitrary precision floats with a fixed number of steps,
+/// returning an error instead of panicking if the range is invalid
+///
+/// ```
+/// use dashu_float::DBig;
+/// use iter_num_tools::{big_float::try_log_space, Error};
+///
+/// let err = try_log_space(DBig::ZERO..=DBig::ONE, 3).unwrap_err();
+/// assert_eq!(err, Error::ZeroStart);
+///
+/// let err = try_log_space(DBig::NEG_ONE..=DBig::ONE, 3).unwrap_err();
+/// assert_eq!(err, Error::SignMismatch);
+/// ```
+pub fn try_log_space<R: Round, const B: Word>(
+    range: impl Into<AxisRange<FBig<R, B>>>,
+    steps: usize,
+) -> Result<BigLogSpace<R, B>, Error> {
+    let (start, end, intervals) = match range.into() {
+        AxisRange::Exclusive(Range { start, end }) | AxisRange::Periodic(Range { start, end }) => {
+            (start, end, steps)
+        }
+        AxisRange::Inclusive(range) => {
+            let (start, end) = range.into_inner();
+            (start, end, steps.saturating_sub(1))
+        }
+    };
+    if steps == 0 {
+        return Err(Error::ZeroSteps);
+    }
+    if start.repr().is_zero() || end.repr().is_zero() {
+        return Err(Error::ZeroStart);
+    }
+    if !start.repr().is_finite() || !end.repr().is_finite() {
+        return Err(Error::NonFinite);
+    }
+    if start.sign() != end.sign() {
+        return Err(Error::SignMismatch);
+    }
+    let rate = (end / &start).ln() / FBig::from(intervals.max(1));
+    Ok(IntoSpace::new(steps, BigLogInterpolation { start, rate }).into_space())
+}
+
+/// Logarithmic interpolation for [`log_space`]
+///
+/// Each value is `start * e^(rate * x)`, computed at the precision of the range.
+#[derive(Clone, Debug)]
+pub struct BigLogInterpolation<R: Round, const B: Word> {
+    /// The first value
+    pub start: FBig<R, B>,
+    /// The natural logarithm of the ratio between each value and the one before
+    pub rate: FBig<R, B>,
+}
+
+impl<R: Round, const B: Word> Interpolate for BigLogInterpolation<R, B> {
+    type Item = FBig<R, B>;
+    fn interpolate(&self, x: usize) -> FBig<R, B> {
+        let exponent = &self.rate * FBig::from(x);
+        &self.start * exponent.exp()
+    }
+}
+
+impl<R: Round, const B: Word> SpaceFmt for BigLogInterpolation<R, B> {
+    const NAME: &'static str = "BigLogSpace";
+    fn fmt_params(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, ", ratio: {}", self.rate.exp())
+    }
+}
+
+/// [`Iterator`] returned by [`log_space`]
+pub type BigLogSpace<R, const B: Word> = Space<BigLogInterpolation<R, B>>;
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::lin_space;
+    use dashu_float::DBig;
+    use num_traits::Signed;
+
+    fn dbig(s: &str) -> DBig {
+        s.parse().unwrap()
+    }
+
+    #[test]
+    fn test_big_lin_space() {
+        let start = dbig("0.00000000000000000000");
+        let end = dbig("1.00000000000000000000");
+        let it = lin_space(start.clone()..end.clone(), 3);
+        let thirds = ["0", "0.333333333333333333333", "0.666666666666666666666"];
+        assert!(it.eq(thirds.map(dbig)));
+
+        // more precise than an f64 can tell apart
+        let it = lin_space(end.clone()..=dbig("1.00000000000000000002"), 3);
+        assert!(it.eq(["1", "1.00000000000000000001", "1.00000000000000000002"].map(dbig)));
+
+        let it = lin_space(start..=end, 5);
+        assert!(it.eq(["0", "0.25", "0.5", "0.75", "1"].map(dbig)));
+    }
+
+    #[test]
+    fn test_big_log_space() {
+        let it = log_space(dbig("1.0000000000000000000000000")..dbig("1000"), 3);
+        let expected = ["1", "10", "100"].map(dbig);
+        let close = |(x, y): (DBig, DBig)| ((x - &y) / y).abs() < dbig("1e-20");
+        assert!(it.clone().zip(expected.clone()).all(close));
+        assert!(it.rev().zip(expected.into_iter().rev()).all(close));
+
+        let it = log_space(dbig("-2.000000000000000000000")..=dbig("-0.5"), 3);
+        let expected = ["-2", "-1", "-0.5"].map(dbig);
+        assert!(it.zip(expected).all(close));
+
+        let it = log_space(dbig("5")..=dbig("20"), 1);
+        assert!(it.eq([dbig("5")]));
+    }
+
+    #[test]
+    fn test_try_big_log_space() {
+        assert_eq!(
+            try_log_space(dbig("1")..=dbig("10"), 0).unwrap_err(),
+            Error::ZeroSteps
+        );
+        assert_eq!(
+            try_log_space(dbig("1")..dbig("0"), 3).unwrap_err(),
+            Error::ZeroStart
+        );
+        assert_eq!(
+            try_log_space(dbig("-1")..dbig("10"), 3).unwrap_err(),
+            Error::SignMismatch
+        );
+        assert_eq!(
+            try_log_space(DBig::INFINITY..DBig::ONE, 3).unwrap_err(),
+            Error::NonFinite
+        );
+    }
+
+    #[test]
+    fn test_big_log_space_fmt() {
+        let it = log_space(dbig("1.000000")..=dbig("100"), 3);
+        assert_eq!(
+            format!("{it:?}"),
+            "BigLogSpace { 1 * 10 ^ 0 (prec: 7)..=9999998 * 10 ^ -5 (prec: 7), len: 3, ratio: 9.999999 }"
+        );
+    }
+}
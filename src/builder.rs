@@ -0,0 +1,94 @@
+use crate::{
+    arange::ToArange, arange_grid::ToArangeGrid, gridspace::ToGridSpace, linspace::ToLinSpace,
+    logspace::ToLogSpace, space::Space, Arange, ArangeGrid, GridSpace, LinSpace, LogSpace,
+};
+
+impl Space<()> {
+    /// Starts building a space over the range
+    ///
+    /// ```
+    /// use iter_num_tools::Space;
+    ///
+    /// let it = Space::linear(0.0..=1.0).steps(3);
+    /// assert!(it.eq([0.0, 0.5, 1.0]));
+    ///
+    /// let it = Space::linear(0.0..1.0).step_size(0.25);
+    /// assert!(it.eq([0.0, 0.25, 0.5, 0.75]));
+    ///
+    /// let it = Space::linear(1.0..=100.0).log().steps(3);
+    /// assert_eq!(it.len(), 3);
+    ///
+    /// let it = Space::linear([0.0, 0.0]..=[1.0, 2.0]).grid([2, 3]);
+    /// assert!(it.eq([
+    ///     [0.0, 0.0], [1.0, 0.0],
+    ///     [0.0, 1.0], [1.0, 1.0],
+    ///     [0.0, 2.0], [1.0, 2.0],
+    /// ]));
+    /// ```
+    pub fn linear<R>(range: R) -> SpaceBuilder<R> {
+        SpaceBuilder { range }
+    }
+}
+
+/// Builder returned by [`Space::linear`]
+#[derive(Clone, Debug)]
+pub struct SpaceBuilder<R> {
+    range: R,
+}
+
+impl<R> SpaceBuilder<R> {
+    /// Splits the range into a fixed number of steps, as in [`lin_space`](crate::lin_space)
+    pub fn steps(self, steps: usize) -> LinSpace<R::Item>
+    where
+        R: ToLinSpace,
+    {
+        crate::lin_space(self.range, steps)
+    }
+
+    /// Steps through the range by a fixed amount, as in [`arange`](crate::arange)
+    pub fn step_size<F>(self, step: F) -> Arange<R::Item, F>
+    where
+        R: ToArange<F>,
+    {
+        crate::arange(self.range, step)
+    }
+
+    /// Splits the range into a grid with a fixed number of steps along each axis,
+    /// as in [`grid_space`](crate::grid_space)
+    pub fn grid<S, const N: usize>(self, steps: S) -> GridSpace<<R as ToGridSpace<S, N>>::Item, N>
+    where
+        R: ToGridSpace<S, N>,
+    {
+        crate::grid_space(self.range, steps)
+    }
+
+    /// Steps through a grid over the range by a fixed amount along each axis,
+    /// as in [`arange_grid`](crate::arange_grid)
+    pub fn grid_step_size<S, const N: usize>(
+        self,
+        step: S,
+    ) -> ArangeGrid<<R as ToArangeGrid<S, N>>::Item, N>
+    where
+        R: ToArangeGrid<S, N>,
+    {
+        crate::arange_grid(self.range, step)
+    }
+
+    /// Switches to evenly spaced logarithmic steps
+    pub fn log(self) -> LogSpaceBuilder<R> {
+        LogSpaceBuilder { range: self.range }
+    }
+}
+
+/// Builder returned by [`SpaceBuilder::log`]
+#[derive(Clone, Debug)]
+pub struct LogSpaceBuilder<R> {
+    range: R,
+}
+
+impl<R: ToLogSpace> LogSpaceBuilder<R> {
+    /// Splits the range into a fixed number of logarithmic steps, as in [`log_space`](crate::log_space)
+    pub fn steps(self, steps: usize) -> LogSpace<R::Item> {
+        crate::log_space(self.range, steps)
+    }
+}
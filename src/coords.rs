@@ -0,0 +1,173 @@
+//! Grids over polar, cylindrical and spherical coordinates, yielding cartesian points
+
+use core::fmt;
+use num_traits::{real::Real, FromPrimitive};
+
+use crate::{
+    axis::AxisRange,
+    fp,
+    gridspace::{GridSpaceInterpolation, ToGridSpace},
+    space::{Interpolate, Space, SpaceFmt},
+};
+
+macro_rules! coordinate_grid {
+    (
+        $(#[$doc:meta])*
+        fn $name:ident($($axis:ident),+; $n:literal) -> $space:ident($interp:ident)
+        |[$($coord:ident),+]| $convert:expr
+    ) => {
+        $(#[$doc])*
+        pub fn $name<T: Real + FromPrimitive>(
+            $($axis: impl Into<AxisRange<T>>,)+
+            steps: [usize; $n],
+        ) -> $space<T> {
+            let grid = [$($axis.into()),+].into_grid_space(steps);
+            Space::new(grid.len, $interp(grid.interpolate))
+        }
+
+        #[derive(Clone, Copy, Debug)]
+        pub struct $interp<T>(pub GridSpaceInterpolation<T, $n>);
+
+        impl<T: Real + FromPrimitive> Interpolate for $interp<T> {
+            type Item = [T; $n];
+            fn interpolate(&self, x: usize) -> [T; $n] {
+                let [$($coord),+] = self.0.interpolate(x);
+                $convert
+            }
+        }
+
+        impl<T: Real + FromPrimitive> SpaceFmt for $interp<T> {
+            const NAME: &'static str = stringify!($space);
+            fn fmt_params(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+                self.0.fmt_params(f)
+            }
+        }
+
+        #[doc = concat!("[`Iterator`] returned by [`", stringify!($name), "`]")]
+        pub type $space<T> = Space<$interp<T>>;
+    };
+}
+
+coordinate_grid! {
+    /// Creates a grid over a range of radii and angles, yielding the cartesian `[x, y]` of each point
+    ///
+    /// Like [`grid_space`](crate::grid_space), the radius varies the fastest.
+    /// Use a periodic or exclusive range of angles to go all the way around without repeating a point.
+    ///
+    /// ```
+    /// use iter_num_tools::{polar_grid, AxisRange};
+    /// use core::f64::consts::TAU;
+    ///
+    /// let it = polar_grid(1.0..=2.0, AxisRange::Periodic(0.0..TAU), [2, 4]);
+    /// let expected = [
+    ///     [1.0, 0.0], [2.0, 0.0],
+    ///     [0.0, 1.0], [0.0, 2.0],
+    ///     [-1.0, 0.0], [-2.0, 0.0],
+    ///     [0.0, -1.0], [0.0, -2.0],
+    /// ];
+    /// assert!(it.zip(expected).all(|([x, y], [ex, ey])| (x - ex).abs() < 1e-12 && (y - ey).abs() < 1e-12));
+    /// ```
+    fn polar_grid(r, theta; 2) -> PolarGrid(PolarInterpolation)
+    |[r, theta]| {
+        let (sin, cos) = fp::sin_cos(theta);
+        [r * cos, r * sin]
+    }
+}
+
+coordinate_grid! {
+    /// Creates a grid over a range of radii, angles and heights,
+    /// yielding the cartesian `[x, y, z]` of each point
+    ///
+    /// Like [`grid_space`](crate::grid_space), the radius varies the fastest and the height the slowest.
+    ///
+    /// ```
+    /// use iter_num_tools::cylindrical_grid;
+    /// use core::f64::consts::PI;
+    ///
+    /// let it = cylindrical_grid(1.0..=1.0, 0.0..PI, 0.0..=1.0, [1, 2, 2]);
+    /// assert_eq!(it.len(), 4);
+    /// assert!(it.map(|[_, _, z]| z).eq([0.0, 0.0, 1.0, 1.0]));
+    /// ```
+    fn cylindrical_grid(r, theta, z; 3) -> CylindricalGrid(CylindricalInterpolation)
+    |[r, theta, z]| {
+        let (sin, cos) = fp::sin_cos(theta);
+        [r * cos, r * sin, z]
+    }
+}
+
+coordinate_grid! {
+    /// Creates a grid over a range of radii, polar angles and azimuths,
+    /// yielding the cartesian `[x, y, z]` of each point
+    ///
+    /// The polar angle is measured from the `z` axis, and the azimuth around it from the `x` axis,
+    /// so `[r, θ, φ]` is at `[r sin θ cos φ, r sin θ sin φ, r cos θ]`.
+    /// Like [`grid_space`](crate::grid_space), the radius varies the fastest and the azimuth the slowest.
+    ///
+    /// ```
+    /// use iter_num_tools::spherical_grid;
+    /// use core::f64::consts::PI;
+    ///
+    /// let it = spherical_grid(2.0..=2.0, 0.0..=PI, 0.0..=0.0, [1, 3, 1]);
+    /// let expected = [[0.0, 0.0, 2.0], [2.0, 0.0, 0.0], [0.0, 0.0, -2.0]];
+    /// assert!(it.zip(expected).all(|(p, e)| (0..3).all(|i| (p[i] - e[i]).abs() < 1e-12)));
+    /// ```
+    fn spherical_grid(r, theta, phi; 3) -> SphericalGrid(SphericalInterpolation)
+    |[r, theta, phi]| {
+        let (sin_theta, cos_theta) = fp::sin_cos(theta);
+        let (sin_phi, cos_phi) = fp::sin_cos(phi);
+        [r * sin_theta * cos_phi, r * sin_theta * sin_phi, r * cos_theta]
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use core::f64::consts::{FRAC_PI_2, TAU};
+
+    fn close<const N: usize>(a: [f64; N], b: [f64; N]) -> bool {
+        a.iter().zip(&b).all(|(a, b)| (a - b).abs() < 1e-12)
+    }
+
+    #[test]
+    fn test_polar_grid() {
+        let it = polar_grid(0.0..=1.0, AxisRange::Periodic(0.0..TAU), [3, 8]);
+        assert_eq!(it.len(), 24);
+        assert!(it.clone().all(|[x, y]| x.hypot(y) <= 1.0 + 1e-12));
+        assert!(close(it.clone().nth(8).unwrap(), [0.0, 1.0]));
+        assert!(close(
+            it.last().unwrap(),
+            [0.5_f64.sqrt(), -(0.5_f64.sqrt())]
+        ));
+    }
+
+    #[test]
+    fn test_cylindrical_grid() {
+        let it = cylindrical_grid(2.0..=2.0, 0.0..=FRAC_PI_2, 1.0..=3.0, [1, 2, 2]);
+        let expected = [
+            [2.0, 0.0, 1.0],
+            [0.0, 2.0, 1.0],
+            [2.0, 0.0, 3.0],
+            [0.0, 2.0, 3.0],
+        ];
+        assert_eq!(it.len(), 4);
+        assert!(it
+            .rev()
+            .zip(expected.into_iter().rev())
+            .all(|(a, b)| close(a, b)));
+    }
+
+    #[test]
+    fn test_spherical_grid() {
+        let it = spherical_grid(1.0..=1.0, 0.0..=FRAC_PI_2, 0.0..=FRAC_PI_2, [1, 2, 2]);
+        let expected = [
+            [0.0, 0.0, 1.0],
+            [1.0, 0.0, 0.0],
+            [0.0, 0.0, 1.0],
+            [0.0, 1.0, 0.0],
+        ];
+        assert!(it.clone().zip(expected).all(|(a, b)| close(a, b)));
+        assert_eq!(it.len(), 4);
+        assert!(format!("{it:?}").starts_with("SphericalGrid {"));
+        assert!(format!("{it:?}").ends_with("shape: [1, 2, 2] }"));
+    }
+}
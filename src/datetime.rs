@@ -0,0 +1,271 @@
+//! [`ToArange`] for timestamps, stepping by a duration
+
+use core::fmt;
+use core::ops::Range;
+
+use crate::{
+    arange::{IntoArange, ToArange},
+    error::Error,
+    linspace::LinearInterpolation,
+    space::{Interpolate, SpaceFmt},
+};
+
+const NANOS_PER_SEC: i128 = 1_000_000_000;
+
+/// The number of steps needed to cover the span, rounding up
+fn try_arange_len(span: i128, step: i128) -> Result<usize, Error> {
+    if step == 0 {
+        return Err(Error::ZeroSteps);
+    }
+    if span != 0 && span.signum() != step.signum() {
+        return Err(Error::SignMismatch);
+    }
+    let (span, step) = (span.unsigned_abs(), step.unsigned_abs());
+    usize::try_from(span.div_ceil(step)).map_err(|_| Error::Overflow)
+}
+
+fn arange_len(span: i128, step: i128) -> usize {
+    match try_arange_len(span, step) {
+        Ok(len) => len,
+        Err(err) => panic!("invalid arange: {err}"),
+    }
+}
+
+#[cfg(feature = "chrono")]
+mod chrono_impl {
+    use super::*;
+    use chrono::{DateTime, NaiveDateTime, TimeDelta, TimeZone};
+
+    fn nanos(delta: TimeDelta) -> i128 {
+        delta.num_seconds() as i128 * NANOS_PER_SEC + delta.subsec_nanos() as i128
+    }
+
+    fn delta(step: TimeDelta, x: usize) -> TimeDelta {
+        let nanos = nanos(step) * x as i128;
+        let secs = i64::try_from(nanos.div_euclid(NANOS_PER_SEC)).expect("arange out of range");
+        TimeDelta::new(secs, nanos.rem_euclid(NANOS_PER_SEC) as u32).expect("arange out of range")
+    }
+
+    impl<Tz: TimeZone> ToArange<TimeDelta> for Range<DateTime<Tz>> {
+        type Item = DateTime<Tz>;
+
+        fn into_arange(self, step: TimeDelta) -> IntoArange<Self::Item, TimeDelta> {
+            let len = arange_len(nanos(self.end.clone() - self.start.clone()), nanos(step));
+            IntoArange::new(
+                len,
+                LinearInterpolation {
+                    start: self.start,
+                    step,
+                },
+            )
+        }
+
+        fn try_into_arange(
+            self,
+            step: TimeDelta,
+        ) -> Result<IntoArange<Self::Item, TimeDelta>, Error> {
+            let len = try_arange_len(nanos(self.end.clone() - self.start.clone()), nanos(step))?;
+            Ok(IntoArange::new(
+                len,
+                LinearInterpolation {
+                    start: self.start,
+                    step,
+                },
+            ))
+        }
+    }
+
+    impl<Tz: TimeZone> Interpolate for LinearInterpolation<DateTime<Tz>, TimeDelta> {
+        type Item = DateTime<Tz>;
+        fn interpolate(&self, x: usize) -> Self::Item {
+            self.start.clone() + delta(self.step, x)
+        }
+    }
+
+    impl<Tz: TimeZone> SpaceFmt for LinearInterpolation<DateTime<Tz>, TimeDelta> {
+        const NAME: &'static str = "Arange";
+        fn fmt_params(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+            write!(f, ", step: {:?}", self.step)
+        }
+    }
+
+    impl ToArange<TimeDelta> for Range<NaiveDateTime> {
+        type Item = NaiveDateTime;
+
+        fn into_arange(self, step: TimeDelta) -> IntoArange<Self::Item, TimeDelta> {
+            let len = arange_len(nanos(self.end - self.start), nanos(step));
+            IntoArange::new(
+                len,
+                LinearInterpolation {
+                    start: self.start,
+                    step,
+                },
+            )
+        }
+
+        fn try_into_arange(
+            self,
+            step: TimeDelta,
+        ) -> Result<IntoArange<Self::Item, TimeDelta>, Error> {
+            let len = try_arange_len(nanos(self.end - self.start), nanos(step))?;
+            Ok(IntoArange::new(
+                len,
+                LinearInterpolation {
+                    start: self.start,
+                    step,
+                },
+            ))
+        }
+    }
+
+    impl Interpolate for LinearInterpolation<NaiveDateTime, TimeDelta> {
+        type Item = NaiveDateTime;
+        fn interpolate(&self, x: usize) -> Self::Item {
+            self.start + delta(self.step, x)
+        }
+    }
+
+    impl SpaceFmt for LinearInterpolation<NaiveDateTime, TimeDelta> {
+        const NAME: &'static str = "Arange";
+        fn fmt_params(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+            write!(f, ", step: {:?}", self.step)
+        }
+    }
+
+    #[cfg(test)]
+    mod tests {
+        use super::*;
+        use crate::{arange, arange_from, check_double_ended_iter, try_arange};
+        use chrono::NaiveDate;
+
+        #[test]
+        fn test_arange_chrono() {
+            let start = NaiveDate::from_ymd_opt(2024, 1, 1)
+                .unwrap()
+                .and_hms_opt(0, 0, 0)
+                .unwrap();
+            let end = start + TimeDelta::minutes(12);
+            let minutes = |m| start + TimeDelta::minutes(m);
+
+            check_double_ended_iter(
+                arange(start..end, TimeDelta::minutes(5)),
+                [minutes(0), minutes(5), minutes(10)],
+            );
+            check_double_ended_iter(
+                arange(end..start, TimeDelta::minutes(-5)),
+                [minutes(12), minutes(7), minutes(2)],
+            );
+
+            let start = start.and_utc();
+            let it = arange(start..start + TimeDelta::hours(1), TimeDelta::minutes(15));
+            assert_eq!(it.len(), 4);
+            assert_eq!(it.last(), Some(start + TimeDelta::minutes(45)));
+        }
+
+        #[test]
+        fn test_arange_from_chrono() {
+            let start = NaiveDate::from_ymd_opt(2024, 1, 1)
+                .unwrap()
+                .and_hms_opt(0, 0, 0)
+                .unwrap();
+            let mut it = arange_from(start, TimeDelta::hours(1));
+            assert_eq!(it.nth(24 * 366), Some(start + TimeDelta::days(366)));
+        }
+
+        #[test]
+        fn test_try_arange_chrono() {
+            let start = NaiveDate::from_ymd_opt(2024, 1, 1)
+                .unwrap()
+                .and_hms_opt(0, 0, 0)
+                .unwrap();
+            let end = start + TimeDelta::minutes(12);
+            assert_eq!(
+                try_arange(start..end, TimeDelta::zero()).unwrap_err(),
+                Error::ZeroSteps
+            );
+            assert_eq!(
+                try_arange(start..end, TimeDelta::minutes(-1)).unwrap_err(),
+                Error::SignMismatch
+            );
+            assert_eq!(
+                try_arange(start..end, TimeDelta::nanoseconds(1))
+                    .unwrap()
+                    .len(),
+                720_000_000_000
+            );
+        }
+    }
+}
+
+#[cfg(feature = "time")]
+mod time_impl {
+    use super::*;
+    use time::{Duration, OffsetDateTime, PrimitiveDateTime};
+
+    fn delta(step: Duration, x: usize) -> Duration {
+        let nanos = step.whole_nanoseconds() * x as i128;
+        let secs = i64::try_from(nanos.div_euclid(NANOS_PER_SEC)).expect("arange out of range");
+        Duration::new(secs, nanos.rem_euclid(NANOS_PER_SEC) as i32)
+    }
+
+    macro_rules! impl_time_arange {
+        ($($t:ty),*) => {$(
+            impl ToArange<Duration> for Range<$t> {
+                type Item = $t;
+
+                fn into_arange(self, step: Duration) -> IntoArange<Self::Item, Duration> {
+                    let span = (self.end - self.start).whole_nanoseconds();
+                    let len = arange_len(span, step.whole_nanoseconds());
+                    IntoArange::new(len, LinearInterpolation { start: self.start, step })
+                }
+
+                fn try_into_arange(self, step: Duration) -> Result<IntoArange<Self::Item, Duration>, Error> {
+                    let span = (self.end - self.start).whole_nanoseconds();
+                    let len = try_arange_len(span, step.whole_nanoseconds())?;
+                    Ok(IntoArange::new(len, LinearInterpolation { start: self.start, step }))
+                }
+            }
+
+            impl Interpolate for LinearInterpolation<$t, Duration> {
+                type Item = $t;
+                fn interpolate(&self, x: usize) -> Self::Item {
+                    self.start + delta(self.step, x)
+                }
+            }
+
+            impl SpaceFmt for LinearInterpolation<$t, Duration> {
+                const NAME: &'static str = "Arange";
+                fn fmt_params(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+                    write!(f, ", step: {:?}", self.step)
+                }
+            }
+        )*};
+    }
+
+    impl_time_arange!(OffsetDateTime, PrimitiveDateTime);
+
+    #[cfg(test)]
+    mod tests {
+        use super::*;
+        use crate::{arange, check_double_ended_iter};
+
+        #[test]
+        fn test_arange_time() {
+            let start = OffsetDateTime::UNIX_EPOCH;
+            let end = start + Duration::minutes(12);
+            let minutes = |m| start + Duration::minutes(m);
+
+            check_double_ended_iter(
+                arange(start..end, Duration::minutes(5)),
+                [minutes(0), minutes(5), minutes(10)],
+            );
+
+            let start = PrimitiveDateTime::new(start.date(), start.time());
+            let it = arange(
+                start..start + Duration::seconds(1),
+                Duration::milliseconds(300),
+            );
+            assert_eq!(it.len(), 4);
+        }
+    }
+}
@@ -0,0 +1,229 @@
+use core::fmt;
+use core::ops::Range;
+use num_traits::{real::Real, FromPrimitive};
+
+use crate::{
+    error::Error,
+    linspace::is_finite,
+    space::{Interpolate, Space, SpaceFmt},
+};
+
+/// Create an iterator over the range, stepping by an exact decimal `step` each time
+///
+/// [`arange`](crate::arange) repeatedly adds a step like `0.1`, which can't be represented exactly,
+/// so the values drift away from the decimals they are meant to be.
+/// This keeps the step as a fraction and computes `start + (i * numer) / denom`,
+/// so each offset from the start is rounded only once.
+///
+/// ```
+/// use iter_num_tools::{arange, arange_decimal};
+///
+/// assert_eq!(arange(0.0..1.0, 0.1).nth(3), Some(0.30000000000000004));
+///
+/// let it = arange_decimal(0.0..1.0, "0.1");
+/// assert!(it.eq([0.0, 0.1, 0.2, 0.3, 0.4, 0.5, 0.6, 0.7, 0.8, 0.9]));
+/// ```
+///
+/// # Panics
+///
+/// Panics if the step is not a decimal number, or if the range and step are not valid for
+/// [`try_arange_decimal`]
+pub fn arange_decimal<T: Real + FromPrimitive>(range: Range<T>, step: &str) -> ArangeDecimal<T> {
+    match try_arange_decimal(range, step) {
+        Ok(space) => space,
+        Err(err) => panic!("invalid decimal arange: {err}"),
+    }
+}
+
+/// Create an iterator over the range, stepping by an exact decimal `step` each time,
+/// returning an error instead of panicking if the range or step is invalid
+///
+/// ```
+/// use iter_num_tools::{try_arange_decimal, Error};
+///
+/// let it = try_arange_decimal(1.0..0.0, "-0.25").unwrap();
+/// assert!(it.eq([1.0, 0.75, 0.5, 0.25]));
+///
+/// assert_eq!(try_arange_decimal(0.0..1.0, "1e-3").unwrap_err(), Error::InvalidDecimal);
+/// assert_eq!(try_arange_decimal(0.0..1.0, "-0.1").unwrap_err(), Error::SignMismatch);
+/// ```
+pub fn try_arange_decimal<T: Real + FromPrimitive>(
+    range: Range<T>,
+    step: &str,
+) -> Result<ArangeDecimal<T>, Error> {
+    let Range { start, end } = range;
+    let (numer, denom) = parse_decimal(step).ok_or(Error::InvalidDecimal)?;
+    if !is_finite(&start) || !is_finite(&end) {
+        return Err(Error::NonFinite);
+    }
+    if numer == 0 {
+        return Err(Error::ZeroSteps);
+    }
+    if (end - start) * T::from_i128(numer).unwrap() < T::zero() {
+        return Err(Error::SignMismatch);
+    }
+
+    let interpolate = DecimalInterpolation {
+        start,
+        numer,
+        denom,
+    };
+    let guess = ((end - start) * T::from_i128(denom).unwrap() / T::from_i128(numer).unwrap())
+        .ceil()
+        .to_usize()
+        .ok_or(Error::Overflow)?;
+
+    // the guess can be off by one if the division rounded, so check the values around it
+    let before_end = |i: usize| match interpolate.try_interpolate(i) {
+        Some(x) if numer > 0 => x < end,
+        Some(x) => x > end,
+        None => false,
+    };
+    let mut len = guess;
+    while len > 0 && !before_end(len - 1) {
+        len -= 1;
+    }
+    while before_end(len) {
+        len = len.checked_add(1).ok_or(Error::Overflow)?;
+    }
+    Ok(Space::new(len, interpolate))
+}
+
+/// Parses an optionally signed decimal number without an exponent, like `-12.5`,
+/// into a reduced fraction
+fn parse_decimal(s: &str) -> Option<(i128, i128)> {
+    let (negative, s) = match s.as_bytes().first()? {
+        b'-' => (true, &s[1..]),
+        b'+' => (false, &s[1..]),
+        _ => (false, s),
+    };
+    let (int, frac) = s.split_once('.').unwrap_or((s, ""));
+    if int.is_empty() && frac.is_empty() {
+        return None;
+    }
+
+    let (mut numer, mut denom) = (0_i128, 1_i128);
+    for (digits, scale) in [(int, false), (frac, true)] {
+        for c in digits.bytes() {
+            let digit = match c {
+                b'0'..=b'9' => (c - b'0') as i128,
+                _ => return None,
+            };
+            numer = numer.checked_mul(10)?.checked_add(digit)?;
+            if scale {
+                denom = denom.checked_mul(10)?;
+            }
+        }
+    }
+
+    let gcd = gcd(numer, denom);
+    let numer = numer / gcd;
+    Some((if negative { -numer } else { numer }, denom / gcd))
+}
+
+fn gcd(mut a: i128, mut b: i128) -> i128 {
+    while b != 0 {
+        (a, b) = (b, a % b);
+    }
+    a
+}
+
+/// Interpolation for spaces with an exact fractional step, returned by [`arange_decimal`]
+#[derive(Clone, Copy, Debug)]
+pub struct DecimalInterpolation<T> {
+    pub start: T,
+    pub numer: i128,
+    pub denom: i128,
+}
+
+impl<T: Real + FromPrimitive> DecimalInterpolation<T> {
+    fn try_interpolate(&self, x: usize) -> Option<T> {
+        let offset = (x as i128).checked_mul(self.numer)?;
+        Some(self.start + T::from_i128(offset)? / T::from_i128(self.denom)?)
+    }
+}
+
+impl<T: Real + FromPrimitive> Interpolate for DecimalInterpolation<T> {
+    type Item = T;
+    fn interpolate(&self, x: usize) -> T {
+        self.try_interpolate(x).expect("decimal arange overflowed")
+    }
+}
+
+impl<T: Real + FromPrimitive + fmt::Debug> SpaceFmt for DecimalInterpolation<T> {
+    const NAME: &'static str = "ArangeDecimal";
+    fn fmt_params(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, ", step: {}/{}", self.numer, self.denom)
+    }
+}
+
+/// [`Iterator`] returned by [`arange_decimal`]
+pub type ArangeDecimal<T> = Space<DecimalInterpolation<T>>;
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::check_double_ended_iter;
+
+    #[test]
+    fn test_parse_decimal() {
+        assert_eq!(parse_decimal("0.1"), Some((1, 10)));
+        assert_eq!(parse_decimal("-2.50"), Some((-5, 2)));
+        assert_eq!(parse_decimal("+3"), Some((3, 1)));
+        assert_eq!(parse_decimal(".125"), Some((1, 8)));
+        assert_eq!(parse_decimal("4."), Some((4, 1)));
+        assert_eq!(parse_decimal("0.000"), Some((0, 1)));
+        for bad in ["", "-", ".", "1.2.3", "1e3", " 1", "0x10", "--1"] {
+            assert_eq!(parse_decimal(bad), None, "{bad:?}");
+        }
+        assert_eq!(parse_decimal(&"9".repeat(40)), None);
+    }
+
+    #[test]
+    fn test_arange_decimal() {
+        check_double_ended_iter(arange_decimal(0.0..0.5, "0.1"), [0.0, 0.1, 0.2, 0.3, 0.4]);
+        check_double_ended_iter(arange_decimal(1.0_f32..1.3, "0.1"), [1.0, 1.1, 1.2]);
+
+        // every value is the nearest float to the decimal
+        let it = arange_decimal(0.0..100.0, "0.01");
+        assert_eq!(it.len(), 10_000);
+        assert!(it.enumerate().all(|(i, x)| x
+            == format!("{}.{:02}", i / 100, i % 100)
+                .parse::<f64>()
+                .unwrap()));
+
+        // the end is excluded even when the step count divides the span inexactly
+        assert_eq!(arange_decimal(0.0..0.3, "0.1").len(), 3);
+        assert_eq!(arange_decimal(0.0..0.30000000000000004, "0.1").len(), 4);
+        assert_eq!(arange_decimal(0.0..0.29999999999999993, "0.1").len(), 3);
+        assert_eq!(arange_decimal(1.0..1.0, "0.1").len(), 0);
+    }
+
+    #[test]
+    fn test_try_arange_decimal() {
+        assert_eq!(
+            try_arange_decimal(0.0..1.0, "0").unwrap_err(),
+            Error::ZeroSteps
+        );
+        assert_eq!(
+            try_arange_decimal(0.0..1.0, "abc").unwrap_err(),
+            Error::InvalidDecimal
+        );
+        assert_eq!(
+            try_arange_decimal(0.0..f64::INFINITY, "1").unwrap_err(),
+            Error::NonFinite
+        );
+        assert_eq!(
+            try_arange_decimal(0.0..1e300, "0.5").unwrap_err(),
+            Error::Overflow
+        );
+    }
+
+    #[test]
+    fn test_arange_decimal_fmt() {
+        assert_eq!(
+            format!("{:?}", arange_decimal(0.0..1.0, "0.250")),
+            "ArangeDecimal { 0.0..=0.75, len: 4, step: 1/4 }"
+        );
+    }
+}
@@ -0,0 +1,193 @@
+use alloc::vec::Vec;
+use core::iter::FusedIterator;
+use core::ops::Range;
+
+use crate::{
+    gridspace::{checked_grid_len, GridSpaceInterpolation},
+    linspace::{IntoLinSpace, LinearInterpolation, ToLinSpace},
+    space::{Interpolate, Space},
+    GridSpace,
+};
+
+/// Creates a linear grid space where the number of dimensions is only known at runtime
+///
+/// Each axis is given as a range with its number of steps, as in [`lin_space`](crate::lin_space).
+///
+/// ```
+/// use iter_num_tools::dyn_grid_space;
+///
+/// let it = dyn_grid_space(vec![(0.0..1.0, 2), (0.0..2.0, 2)]);
+/// assert_eq!(it.shape(), vec![2, 2]);
+/// assert!(it.eq([
+///     vec![0.0, 0.0], vec![0.5, 0.0],
+///     vec![0.0, 1.0], vec![0.5, 1.0],
+/// ]));
+/// ```
+///
+/// # Panics
+///
+/// Panics if the total number of points in the grid overflows a `usize`
+pub fn dyn_grid_space<R, I>(axes: I) -> DynGridSpace<R::Item>
+where
+    R: ToLinSpace,
+    I: IntoIterator<Item = (R, usize)>,
+{
+    let axes: Vec<_> = axes
+        .into_iter()
+        .map(|(range, steps)| range.into_lin_space(steps))
+        .collect();
+    DynGridSpace::new(axes)
+}
+
+/// [`Iterator`] returned by [`dyn_grid_space`]
+///
+/// Like [`GridSpace`], but yields a [`Vec`] for each point since the number of dimensions
+/// is only known at runtime. A [`GridSpace`] can be converted into one with [`From`].
+#[derive(Clone, Debug)]
+pub struct DynGridSpace<T> {
+    axes: Vec<IntoLinSpace<T>>,
+    range: Range<usize>,
+}
+
+impl<T> DynGridSpace<T> {
+    fn new(axes: Vec<IntoLinSpace<T>>) -> Self {
+        let len =
+            checked_grid_len(axes.iter().map(|axis| axis.len)).expect("grid size overflows usize");
+        DynGridSpace {
+            axes,
+            range: 0..len,
+        }
+    }
+
+    /// The number of dimensions of the grid
+    pub fn ndim(&self) -> usize {
+        self.axes.len()
+    }
+
+    /// The number of points along each axis of the full grid
+    pub fn shape(&self) -> Vec<usize> {
+        self.axes.iter().map(|axis| axis.len).collect()
+    }
+}
+
+impl<T> DynGridSpace<T>
+where
+    LinearInterpolation<T>: Interpolate<Item = T>,
+{
+    fn interpolate(&self, mut x: usize) -> Vec<T> {
+        self.axes
+            .iter()
+            .map(|space| {
+                let z = x % space.len;
+                x /= space.len;
+                space.interpolate.interpolate(z)
+            })
+            .collect()
+    }
+}
+
+impl<T, const N: usize> From<GridSpace<T, N>> for DynGridSpace<T> {
+    fn from(space: GridSpace<T, N>) -> Self {
+        let Space {
+            interpolate, range, ..
+        } = space;
+        let GridSpaceInterpolation { axes, .. } = interpolate;
+        DynGridSpace {
+            axes: axes.into(),
+            range,
+        }
+    }
+}
+
+impl<T> Iterator for DynGridSpace<T>
+where
+    LinearInterpolation<T>: Interpolate<Item = T>,
+{
+    type Item = Vec<T>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.range.next().map(|x| self.interpolate(x))
+    }
+
+    fn nth(&mut self, n: usize) -> Option<Self::Item> {
+        self.range.nth(n).map(|x| self.interpolate(x))
+    }
+
+    fn count(self) -> usize {
+        self.len()
+    }
+
+    fn last(mut self) -> Option<Self::Item> {
+        self.next_back()
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let len = self.len();
+        (len, Some(len))
+    }
+}
+
+impl<T> DoubleEndedIterator for DynGridSpace<T>
+where
+    LinearInterpolation<T>: Interpolate<Item = T>,
+{
+    fn next_back(&mut self) -> Option<Self::Item> {
+        self.range.next_back().map(|x| self.interpolate(x))
+    }
+
+    fn nth_back(&mut self, n: usize) -> Option<Self::Item> {
+        self.range.nth_back(n).map(|x| self.interpolate(x))
+    }
+}
+
+impl<T> ExactSizeIterator for DynGridSpace<T>
+where
+    LinearInterpolation<T>: Interpolate<Item = T>,
+{
+    fn len(&self) -> usize {
+        self.range.len()
+    }
+}
+
+impl<T> FusedIterator for DynGridSpace<T> where LinearInterpolation<T>: Interpolate<Item = T> {}
+
+#[cfg(feature = "trusted_len")]
+unsafe impl<T> core::iter::TrustedLen for DynGridSpace<T> where
+    LinearInterpolation<T>: Interpolate<Item = T>
+{
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{check_double_ended_iter, grid_space};
+
+    #[test]
+    fn test_dyn_grid_space() {
+        let it = dyn_grid_space([(0.0..=1.0, 2), (0.0..=2.0, 2), (0.0..=3.0, 2)]);
+        assert_eq!(it.ndim(), 3);
+        assert_eq!(it.len(), 8);
+        check_double_ended_iter(
+            it,
+            [
+                vec![0.0, 0.0, 0.0],
+                vec![1.0, 0.0, 0.0],
+                vec![0.0, 2.0, 0.0],
+                vec![1.0, 2.0, 0.0],
+                vec![0.0, 0.0, 3.0],
+                vec![1.0, 0.0, 3.0],
+                vec![0.0, 2.0, 3.0],
+                vec![1.0, 2.0, 3.0],
+            ],
+        );
+    }
+
+    #[test]
+    fn test_dyn_grid_space_from_grid_space() {
+        let mut grid = grid_space([0.0, 0.0]..[1.0, 2.0], [2, 4]);
+        grid.next();
+        let it = DynGridSpace::from(grid.clone());
+        assert_eq!(it.shape(), vec![2, 4]);
+        assert!(it.eq(grid.map(Vec::from)));
+    }
+}
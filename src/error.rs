@@ -0,0 +1,61 @@
+use core::fmt;
+
+/// Error returned by the fallible space constructors, such as [`try_lin_space`](crate::try_lin_space)
+///
+/// More reasons may be added in future, so matches need a wildcard arm.
+/// With the `std` feature, this implements `std::error::Error`, so it can be returned with `?`.
+///
+/// ```
+/// # #[cfg(feature = "std")] {
+/// use iter_num_tools::try_lin_space;
+///
+/// fn sweep(steps: usize) -> Result<f64, Box<dyn std::error::Error>> {
+///     Ok(try_lin_space(0.0..1.0, steps)?.sum())
+/// }
+///
+/// assert_eq!(sweep(0).unwrap_err().to_string(), "space cannot have zero steps");
+/// # }
+/// ```
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum Error {
+    /// A logarithmic space started or ended at zero, which has no logarithm
+    ZeroStart,
+    /// The signs of the range do not agree. Either a logarithmic space crosses zero,
+    /// or an arange step points away from the end of the range
+    SignMismatch,
+    /// Zero steps were requested, or an arange was given a step size of zero
+    ZeroSteps,
+    /// The range or step was infinite or NaN
+    NonFinite,
+    /// The range was finite, but went past the values the space allows,
+    /// such as a timestamp outside of what a JavaScript `Date` can hold
+    OutOfRange,
+    /// The number of values in the space does not fit in a `usize`
+    Overflow,
+    /// The space has no values
+    Empty,
+    /// A decimal step was not a number like `0.1` or `-2.5`, or had too many digits
+    InvalidDecimal,
+    /// A sweep specification was not like `0:0.1:1` or `1..=100 x50 log`
+    InvalidSpec,
+}
+
+impl fmt::Display for Error {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Error::ZeroStart => f.write_str("logarithmic space cannot start or end at zero"),
+            Error::SignMismatch => f.write_str("range and step do not agree in sign"),
+            Error::ZeroSteps => f.write_str("space cannot have zero steps"),
+            Error::NonFinite => f.write_str("range and step must be finite"),
+            Error::OutOfRange => f.write_str("range is outside the allowed values"),
+            Error::Overflow => f.write_str("space is too large"),
+            Error::Empty => f.write_str("space is empty"),
+            Error::InvalidDecimal => f.write_str("step is not a decimal number"),
+            Error::InvalidSpec => f.write_str("sweep specification is not valid"),
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for Error {}
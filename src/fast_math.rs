@@ -0,0 +1,228 @@
+//! Spaces that give up the last bit of accuracy for throughput, such as when generating vertices for rendering.
+//!
+//! [`lin_space`] computes each value with a single [`mul_add`](Real::mul_add),
+//! rather than adding up the steps one after the other, so the values don't depend on each other
+//! and the compiler is free to compute several at once.
+//! This is only faster on targets with a hardware fused multiply-add, such as aarch64,
+//! or x86 with `-C target-cpu=native`. Elsewhere `mul_add` is emulated in software,
+//! so it adds the step to each value to get the next one instead,
+//! rather than computing each from its index as [`lin_space`](crate::lin_space) does.
+//!
+//! [`log_space`] multiplies each value by the ratio to get the next one,
+//! rather than computing an [`exp2`](Real::exp2) for each value.
+//!
+//! Both recompute the value from its index every 64 values, so the rounding errors from stepping can't build up
+//! along the space. A folded linear value is within `64 * ε * (|start| + |step| * (i + 64))` of the value at index `i`,
+//! and a folded logarithmic value is within a relative error of `4 * ε * (64 + |i * log2(ratio)|)`,
+//! where `ε` is the [`epsilon`](Real::epsilon) of the float type.
+//!
+//! ```
+//! use iter_num_tools::fast_math;
+//!
+//! let it = fast_math::lin_space(0.0..=1.0, 5);
+//! assert!(it.eq([0.0, 0.25, 0.5, 0.75, 1.0]));
+//!
+//! let it = fast_math::log_space(1.0..=16.0, 5);
+//! assert!(it.eq([1.0, 2.0, 4.0, 8.0, 16.0]));
+//! ```
+//!
+//! Enabled by the `fast_math` feature.
+
+use core::fmt;
+use core::ops::Range;
+use num_traits::{real::Real, FromPrimitive};
+
+use crate::{
+    forward_diff::{fold_differences, RESYNC},
+    fp,
+    linspace::{LinearInterpolation, ToLinSpace},
+    logspace::{LogarithmicInterpolation, ToLogSpace},
+    space::{Direction, Interpolate, Monotonic, Space, SpaceFmt},
+};
+
+/// Creates a linear space over range with a fixed number of steps, as in [`lin_space`](crate::lin_space)
+pub fn lin_space<R>(range: R, steps: usize) -> FastLinSpace<R::Item>
+where
+    R: ToLinSpace,
+    R::Item: Real + FromPrimitive,
+{
+    let space = range.into_lin_space(steps);
+    let LinearInterpolation { start, step } = space.interpolate;
+    Space::new(space.len, FastLinearInterpolation { start, step })
+}
+
+/// Creates a logarithmic space over range with a fixed number of steps, as in [`log_space`](crate::log_space)
+pub fn log_space<R>(range: R, steps: usize) -> FastLogSpace<R::Item>
+where
+    R: ToLogSpace,
+    R::Item: Real + FromPrimitive,
+{
+    let space = range.into_log_space(steps);
+    Space::new(space.len, FastLogarithmicInterpolation(space.interpolate))
+}
+
+/// Whether [`Real::mul_add`] is a single instruction, rather than emulated in software
+const HARDWARE_FMA: bool = cfg!(any(target_feature = "fma", target_arch = "aarch64"));
+
+#[derive(Clone, Copy, Debug, Default)]
+/// Linear interpolation for [`lin_space`], computing `start + x * step` with a fused multiply-add
+pub struct FastLinearInterpolation<T> {
+    /// The first value
+    pub start: T,
+    /// The difference between each value
+    pub step: T,
+}
+
+impl<T: Real + FromPrimitive> Interpolate for FastLinearInterpolation<T> {
+    type Item = T;
+    fn interpolate(&self, x: usize) -> T {
+        let Self { start, step } = *self;
+        let x = T::from_usize(x).unwrap();
+        if HARDWARE_FMA {
+            step.mul_add(x, start)
+        } else {
+            start + step * x
+        }
+    }
+
+    const EXACT_FOLD: bool = HARDWARE_FMA;
+
+    fn fold_range<B, F>(&self, range: Range<usize>, init: B, f: F) -> B
+    where
+        F: FnMut(B, T) -> B,
+    {
+        if HARDWARE_FMA {
+            return range.map(|x| self.interpolate(x)).fold(init, f);
+        }
+        let value_at = |x| self.interpolate(x);
+        let diffs_at = |x| [self.interpolate(x), self.step];
+        fold_differences(range, false, value_at, diffs_at, init, f)
+    }
+
+    fn fold_agrees(&self, x: usize, folded: &T) -> bool {
+        if HARDWARE_FMA {
+            return *folded == self.interpolate(x);
+        }
+        let Self { start, step } = *self;
+        let resync = T::from_usize(RESYNC).unwrap();
+        let reach = start.abs() + step.abs() * (T::from_usize(x).unwrap() + resync);
+        let tolerance = resync * T::epsilon() * reach;
+        (*folded - self.interpolate(x)).abs() <= tolerance
+    }
+}
+
+impl<T: Real + FromPrimitive> Monotonic for FastLinearInterpolation<T> {
+    fn direction(&self) -> Direction {
+        Direction::of_step(self.step.partial_cmp(&T::zero()))
+    }
+}
+
+impl<T: Real + FromPrimitive + fmt::Debug> SpaceFmt for FastLinearInterpolation<T> {
+    const NAME: &'static str = "FastLinSpace";
+    fn fmt_params(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, ", step: {:?}", self.step)
+    }
+}
+
+#[derive(Clone, Copy, Debug, Default)]
+/// Logarithmic interpolation for [`log_space`], multiplying by the ratio when stepping through the values
+pub struct FastLogarithmicInterpolation<T>(pub LogarithmicInterpolation<T>);
+
+impl<T: Real + FromPrimitive> Interpolate for FastLogarithmicInterpolation<T> {
+    type Item = T;
+    fn interpolate(&self, x: usize) -> T {
+        self.0.interpolate(x)
+    }
+
+    const EXACT_FOLD: bool = false;
+
+    fn fold_range<B, F>(&self, range: Range<usize>, init: B, mut f: F) -> B
+    where
+        F: FnMut(B, T) -> B,
+    {
+        let ratio = fp::exp2(self.0.exponent);
+        let mut acc = init;
+        let mut i = range.start;
+        while i < range.end {
+            let block = (range.end - i).min(RESYNC);
+            let mut value = self.0.interpolate(i);
+            for _ in 0..block {
+                acc = f(acc, value);
+                value = value * ratio;
+            }
+            i += block;
+        }
+        acc
+    }
+
+    fn fold_agrees(&self, x: usize, folded: &T) -> bool {
+        let direct = self.0.interpolate(x);
+        let steps =
+            T::from_usize(RESYNC).unwrap() + (self.0.exponent * T::from_usize(x).unwrap()).abs();
+        let tolerance = T::from_u8(4).unwrap() * T::epsilon() * steps * direct.abs();
+        (*folded - direct).abs() <= tolerance
+    }
+}
+
+impl<T: Real + FromPrimitive> Monotonic for FastLogarithmicInterpolation<T> {
+    fn direction(&self) -> Direction {
+        self.0.direction()
+    }
+}
+
+impl<T: Real + FromPrimitive + fmt::Debug> SpaceFmt for FastLogarithmicInterpolation<T> {
+    const NAME: &'static str = "FastLogSpace";
+    fn fmt_params(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.0.fmt_params(f)
+    }
+}
+
+/// [`Iterator`] returned by [`lin_space`]
+pub type FastLinSpace<T> = Space<FastLinearInterpolation<T>>;
+
+/// [`Iterator`] returned by [`log_space`]
+pub type FastLogSpace<T> = Space<FastLogarithmicInterpolation<T>>;
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::check_double_ended_iter;
+
+    #[test]
+    fn test_fast_lin_space() {
+        check_double_ended_iter(lin_space(1.0..=-1.0, 5), [1.0, 0.5, 0.0, -0.5, -1.0]);
+        check_double_ended_iter(lin_space(0.0_f32..1.0, 4), [0.0, 0.25, 0.5, 0.75]);
+        assert_eq!(lin_space(0.0..1.0, 10).direction(), Direction::Increasing);
+        lin_space(-1e6..=0.1, 100_000).verify_invariants();
+        lin_space(0.0_f32..=1.0, 100_001).verify_invariants();
+
+        let it = lin_space(0.0..=1.0, 3);
+        assert_eq!(
+            format!("{it:?}"),
+            "FastLinSpace { 0.0..=1.0, len: 3, step: 0.5 }"
+        );
+    }
+
+    #[test]
+    fn test_fast_log_space() {
+        check_double_ended_iter(log_space(8.0..=1.0, 4), [8.0, 4.0, 2.0, 1.0]);
+
+        // folding multiplies up the ratio, so only stays close to the exact values
+        let fast = log_space(1.0..=1000.0, 10_000);
+        let mut exact = crate::log_space(1.0..=1000.0, 10_000);
+        assert_eq!(fast.clone().last(), exact.clone().last());
+        let max_err = fast.clone().fold(0.0_f64, |err, a| {
+            let b = exact.next().unwrap();
+            err.max((a / b - 1.0).abs())
+        });
+        assert!(max_err < 1e-13);
+        fast.verify_invariants();
+        log_space(1e-3_f32..=1e3, 100_000).verify_invariants();
+
+        let it = log_space(1.0..=4.0, 3);
+        assert_eq!(
+            format!("{it:?}"),
+            "FastLogSpace { 1.0..=4.0, len: 3, ratio: 2.0 }"
+        );
+    }
+}
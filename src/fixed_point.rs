@@ -0,0 +1,218 @@
+//! Spaces over fixed point numbers from the [`fixed`] crate.
+//!
+//! The general [`lin_space`](crate::lin_space) needs to convert the number of steps and
+//! every index into the fixed point type, which overflows quickly for types with few integer bits.
+//! These work on the underlying bits in wide integers instead, so every value is rounded only once,
+//! and inclusive ranges end exactly on their end point.
+
+use core::fmt;
+use core::ops::Range;
+
+use ::fixed::traits::Fixed;
+
+use crate::{
+    axis::AxisRange,
+    error::Error,
+    space::{Interpolate, IntoSpace, Space, SpaceFmt},
+};
+
+/// Interpolation for fixed point spaces, computed on the raw bits
+#[derive(Clone, Copy, Debug)]
+pub struct FixedInterpolation<T> {
+    start: T,
+    numer: i128,
+    denom: i128,
+}
+
+fn to_wide<T: Fixed>(x: T) -> i128 {
+    match x.to_bits().try_into() {
+        Ok(bits) => bits,
+        Err(_) => panic!("fixed point value does not fit in an i128"),
+    }
+}
+
+impl<T: Fixed> Interpolate for FixedInterpolation<T> {
+    type Item = T;
+    fn interpolate(&self, x: usize) -> T {
+        let Self {
+            start,
+            numer,
+            denom,
+        } = *self;
+        let offset = (x as i128)
+            .checked_mul(numer)
+            .and_then(|n| n.checked_mul(2))
+            .expect("fixed point space overflowed");
+        // round to the nearest representable value
+        let offset = (offset + denom).div_euclid(2 * denom);
+        match T::Bits::try_from(to_wide(start) + offset) {
+            Ok(bits) => T::from_bits(bits),
+            Err(_) => panic!("fixed point space overflowed"),
+        }
+    }
+}
+
+impl<T: Fixed> SpaceFmt for FixedInterpolation<T> {
+    const NAME: &'static str = "FixedSpace";
+    fn fmt_params(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, ", step_bits: {}/{}", self.numer, self.denom)
+    }
+}
+
+/// [`Iterator`] returned by [`lin_space`] and [`arange`]
+pub type FixedSpace<T> = Space<FixedInterpolation<T>>;
+
+/// Creates a linear space over a range of fixed point numbers with a fixed number of steps
+///
+/// ```
+/// use fixed::types::I16F16;
+/// use iter_num_tools::fixed_point::lin_space;
+///
+/// let start = I16F16::from_num(0);
+/// let end = I16F16::from_num(1);
+///
+/// let it = lin_space(start..=end, 5);
+/// assert!(it.eq([0.0, 0.25, 0.5, 0.75, 1.0].map(I16F16::from_num)));
+///
+/// // more steps than an I16F16 can count to
+/// let it = lin_space(start..=end, 65_537);
+/// assert_eq!(it.last(), Some(end));
+/// ```
+pub fn lin_space<T: Fixed>(range: impl Into<AxisRange<T>>, steps: usize) -> FixedSpace<T> {
+    let (start, end, denom) = match range.into() {
+        AxisRange::Exclusive(Range { start, end }) | AxisRange::Periodic(Range { start, end }) => {
+            (start, end, steps)
+        }
+        AxisRange::Inclusive(range) => {
+            let (start, end) = range.into_inner();
+            (start, end, steps.saturating_sub(1))
+        }
+    };
+    let interpolate = FixedInterpolation {
+        start,
+        numer: to_wide(end) - to_wide(start),
+        denom: denom.max(1) as i128,
+    };
+    IntoSpace::new(steps, interpolate).into_space()
+}
+
+/// Creates a space over a range of fixed point numbers, stepping by `step` each time
+///
+/// ```
+/// use fixed::types::I8F8;
+/// use iter_num_tools::fixed_point::arange;
+///
+/// let it = arange(I8F8::from_num(0)..I8F8::from_num(1), I8F8::from_num(0.25));
+/// assert!(it.eq([0.0, 0.25, 0.5, 0.75].map(I8F8::from_num)));
+/// ```
+///
+/// # Panics
+///
+/// Panics if the step is zero, points away from the end of the range,
+/// or there are more values than fit in a `usize`. Use [`try_arange`] to check this instead.
+pub fn arange<T: Fixed>(range: Range<T>, step: T) -> FixedSpace<T> {
+    match try_arange(range, step) {
+        Ok(it) => it,
+        Err(err) => panic!("invalid arange: {err}"),
+    }
+}
+
+/// Creates a space over a range of fixed point numbers, stepping by `step` each time,
+/// returning an error instead of panicking if the step is invalid
+///
+/// ```
+/// use fixed::types::{I8F8, I64F64};
+/// use iter_num_tools::{fixed_point::try_arange, Error};
+///
+/// let (zero, one) = (I8F8::from_num(0), I8F8::from_num(1));
+/// assert_eq!(try_arange(zero..one, zero).unwrap_err(), Error::ZeroSteps);
+/// assert_eq!(try_arange(zero..one, -one).unwrap_err(), Error::SignMismatch);
+///
+/// // 2^127 values
+/// let it = try_arange(I64F64::from_num(0)..I64F64::MAX, I64F64::DELTA);
+/// assert_eq!(it.unwrap_err(), Error::Overflow);
+/// ```
+pub fn try_arange<T: Fixed>(range: Range<T>, step: T) -> Result<FixedSpace<T>, Error> {
+    let Range { start, end } = range;
+    let span = to_wide(end)
+        .checked_sub(to_wide(start))
+        .ok_or(Error::Overflow)?;
+    let step_bits = to_wide(step);
+    if step_bits == 0 {
+        return Err(Error::ZeroSteps);
+    }
+    if span != 0 && span.signum() != step_bits.signum() {
+        return Err(Error::SignMismatch);
+    }
+    let len = span.unsigned_abs().div_ceil(step_bits.unsigned_abs());
+    let len = usize::try_from(len).map_err(|_| Error::Overflow)?;
+    let interpolate = FixedInterpolation {
+        start,
+        numer: step_bits,
+        denom: 1,
+    };
+    Ok(IntoSpace::new(len, interpolate).into_space())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::check_double_ended_iter;
+    use ::fixed::types::{I16F16, I64F64, I8F8, U8F8};
+
+    #[test]
+    fn test_fixed_lin_space() {
+        let f = I16F16::from_num::<f64>;
+        check_double_ended_iter(
+            lin_space(f(-1.0)..f(1.0), 4),
+            [f(-1.0), f(-0.5), f(0.0), f(0.5)],
+        );
+
+        // the span does not fit in an I8F8, but each value does
+        let it = lin_space(I8F8::MIN..=I8F8::MAX, 3);
+        assert!(it.eq([I8F8::MIN, I8F8::from_bits(0), I8F8::MAX]));
+
+        let it = lin_space(U8F8::from_num(2)..=U8F8::from_num(0), 3);
+        assert!(it.eq([2, 1, 0].map(U8F8::from_num)));
+
+        assert_eq!(lin_space(f(0.0)..=f(1.0), 1).collect::<Vec<_>>(), [f(0.0)]);
+        assert_eq!(lin_space(f(0.0)..=f(1.0), 0).count(), 0);
+    }
+
+    #[test]
+    fn test_fixed_arange() {
+        let f = I16F16::from_num::<f64>;
+        check_double_ended_iter(
+            arange(f(1.0)..f(0.0), f(-0.375)),
+            [f(1.0), f(0.625), f(0.25)],
+        );
+        assert_eq!(arange(f(1.0)..f(1.0), f(1.0)).count(), 0);
+    }
+
+    #[test]
+    fn test_fixed_try_arange() {
+        let f = I16F16::from_num::<f64>;
+        assert_eq!(
+            try_arange(f(0.0)..f(1.0), f(0.0)).unwrap_err(),
+            Error::ZeroSteps
+        );
+        assert_eq!(
+            try_arange(f(0.0)..f(1.0), f(-0.5)).unwrap_err(),
+            Error::SignMismatch
+        );
+        assert_eq!(try_arange(f(0.0)..f(1.0), f(0.5)).unwrap().len(), 2);
+
+        // the number of values doesn't fit in a usize
+        let it = try_arange(I64F64::MIN..I64F64::MAX, I64F64::DELTA);
+        assert_eq!(it.unwrap_err(), Error::Overflow);
+        let it = try_arange(I64F64::from_num(0)..I64F64::MAX, I64F64::DELTA);
+        assert_eq!(it.unwrap_err(), Error::Overflow);
+    }
+
+    #[test]
+    #[should_panic(expected = "range and step do not agree in sign")]
+    fn test_fixed_arange_wrong_sign() {
+        let f = I16F16::from_num::<f64>;
+        arange(f(0.0)..f(1.0), f(-1.0));
+    }
+}
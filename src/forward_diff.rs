@@ -0,0 +1,401 @@
+//! Evaluates polynomial and linear spaces by repeated addition, using forward differences,
+//! rather than computing every value from scratch
+
+use core::fmt;
+use core::ops::Range;
+
+use num_traits::{FromPrimitive, Num};
+
+use crate::{
+    linspace::LinearInterpolation,
+    polyspace::PolyInterpolation,
+    space::{Interpolate, Space, SpaceFmt},
+};
+
+/// A running sum, which can optionally keep track of the rounding error lost by each addition
+/// (Kahan summation), so that error does not build up over long spaces
+#[derive(Clone, Copy)]
+struct RunningSum<T> {
+    sum: T,
+    err: T,
+}
+
+impl<T: Num + Clone> RunningSum<T> {
+    fn new(sum: T) -> Self {
+        RunningSum {
+            sum,
+            err: T::zero(),
+        }
+    }
+
+    fn add(&mut self, x: T, compensated: bool) {
+        if compensated {
+            let y = x - self.err.clone();
+            let t = self.sum.clone() + y.clone();
+            self.err = (t.clone() - self.sum.clone()) - y;
+            self.sum = t;
+        } else {
+            self.sum = self.sum.clone() + x;
+        }
+    }
+}
+
+/// How many values are stepped through with forward differences before recomputing them from the index,
+/// so the rounding errors from each addition can't build up
+pub(crate) const RESYNC: usize = 64;
+
+/// Folds the values of a polynomial over `range`, stepping from each value to the next
+/// by adding up its forward differences.
+///
+/// `diffs_at(i)` gives the forward differences at index `i`, where the last difference must be constant.
+/// They are recomputed every [`RESYNC`] values, starting from `value_at(i)` rather than `diffs_at(i)[0]`,
+/// and the last value is always `value_at(range.end - 1)`, so the fold ends exactly where `next_back` does.
+pub(crate) fn fold_differences<T, B, F, const N: usize>(
+    range: Range<usize>,
+    compensated: bool,
+    value_at: impl Fn(usize) -> T,
+    diffs_at: impl Fn(usize) -> [T; N],
+    init: B,
+    mut f: F,
+) -> B
+where
+    T: Num + Clone,
+    F: FnMut(B, T) -> B,
+{
+    let Range { mut start, end } = range;
+    let mut acc = init;
+    while start < end {
+        let block = (end - start).min(RESYNC);
+        let mut diffs = diffs_at(start).map(RunningSum::new);
+        if let Some(first) = diffs.first_mut() {
+            *first = RunningSum::new(value_at(start));
+        }
+        for i in 0..block {
+            let x = match diffs.first() {
+                _ if start + i + 1 == end => value_at(end - 1),
+                Some(first) => first.sum.clone(),
+                None => value_at(start + i),
+            };
+            acc = f(acc, x);
+            if i + 1 < block {
+                for k in 1..N {
+                    let next = diffs[k].sum.clone();
+                    diffs[k - 1].add(next, compensated);
+                }
+            }
+        }
+        start += block;
+    }
+    acc
+}
+
+/// Turns `N` consecutive values of a polynomial of degree below `N`
+/// into the value and forward differences at the first point
+pub(crate) fn differences<T: Num + Clone, const N: usize>(mut values: [T; N]) -> [T; N] {
+    for k in 1..N {
+        for j in (k..N).rev() {
+            values[j] = values[j].clone() - values[j - 1].clone();
+        }
+    }
+    values
+}
+
+/// The value and forward differences of the polynomial with the given coefficients,
+/// starting at `start` and stepping by `step`.
+///
+/// These are computed from the coefficients rather than from the values at the first few points,
+/// since taking differences of nearly equal values would lose most of their precision.
+pub(crate) fn poly_differences<T, const N: usize>(coeffs: [T; N], start: T, step: T) -> [T; N]
+where
+    T: Num + FromPrimitive + Clone,
+{
+    // shift the polynomial to start at `start`, then scale it so each step is 1
+    let mut coeffs = coeffs;
+    for k in 0..N {
+        for j in (k..N - 1).rev() {
+            coeffs[j] = coeffs[j].clone() + start.clone() * coeffs[j + 1].clone();
+        }
+    }
+    let mut scale = T::one();
+    for c in &mut coeffs {
+        *c = c.clone() * scale.clone();
+        scale = scale * step.clone();
+    }
+
+    // the kth difference of i^j at 0 is a small integer, so these are exact
+    let mut diffs: [T; N] = core::array::from_fn(|_| T::zero());
+    for (j, c) in coeffs.into_iter().enumerate() {
+        let powers: [T; N] = core::array::from_fn(|i| pow(T::from_usize(i).unwrap(), j));
+        for (d, p) in diffs.iter_mut().zip(differences(powers)) {
+            *d = d.clone() + c.clone() * p;
+        }
+    }
+    diffs
+}
+
+fn pow<T: Num + Clone>(x: T, n: usize) -> T {
+    (0..n).fold(T::one(), |acc, _| acc * x.clone())
+}
+
+/// The gap between 1 and the next value of `T`, found by converting from `f64` so it works for any [`Num`].
+/// Integers can't hold anything between 0 and 1, so get 0, as their folds are exact
+pub(crate) fn epsilon<T: Num + FromPrimitive>() -> T {
+    let mut eps = T::zero();
+    let mut gap = 0.5;
+    while gap >= f64::EPSILON {
+        match T::from_f64(1.0 + gap) {
+            Some(x) if x != T::one() => eps = T::from_f64(gap).unwrap(),
+            _ => break,
+        }
+        gap /= 2.0;
+    }
+    eps
+}
+
+/// The distance between `a` and `b`, which can't go below zero even for unsigned `T`
+pub(crate) fn abs_diff<T: Num + PartialOrd>(a: T, b: T) -> T {
+    if a < b {
+        b - a
+    } else {
+        a - b
+    }
+}
+
+/// Interpolation returned by `compensated`, which folds using compensated summation
+#[derive(Clone, Copy, Debug)]
+pub struct Compensated<I>(pub I);
+
+impl<I: SpaceFmt> SpaceFmt for Compensated<I>
+where
+    Self: Interpolate,
+{
+    const NAME: &'static str = I::NAME;
+    fn fmt_params(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.0.fmt_params(f)
+    }
+}
+
+impl<T: Num + FromPrimitive + PartialOrd + Clone, const N: usize> Interpolate
+    for Compensated<PolyInterpolation<T, N>>
+{
+    type Item = T;
+    fn interpolate(&self, x: usize) -> T {
+        self.0.interpolate(x)
+    }
+
+    const EXACT_FOLD: bool = false;
+
+    fn fold_range<B, F>(&self, range: Range<usize>, init: B, f: F) -> B
+    where
+        F: FnMut(B, T) -> B,
+    {
+        self.0.fold_differences(range, true, init, f)
+    }
+
+    fn fold_agrees(&self, x: usize, folded: &T) -> bool {
+        self.0.fold_agrees(x, folded)
+    }
+}
+
+impl<T: Num + FromPrimitive + PartialOrd + Clone> Interpolate
+    for Compensated<LinearInterpolation<T>>
+{
+    type Item = T;
+    fn interpolate(&self, x: usize) -> T {
+        self.0.interpolate(x)
+    }
+
+    const EXACT_FOLD: bool = false;
+
+    fn fold_range<B, F>(&self, range: Range<usize>, init: B, f: F) -> B
+    where
+        F: FnMut(B, T) -> B,
+    {
+        let value_at = |i| self.0.interpolate(i);
+        let diffs_at = |i| [self.0.interpolate(i), self.0.step.clone()];
+        fold_differences(range, true, value_at, diffs_at, init, f)
+    }
+
+    fn fold_agrees(&self, x: usize, folded: &T) -> bool {
+        // the sum of the first value and up to `RESYNC` steps is only rounded a couple of times,
+        // as is the value computed from the index
+        let LinearInterpolation { start, step } = self.0.clone();
+        let zero = T::zero();
+        let reach = abs_diff(start, zero.clone())
+            + abs_diff(step, zero) * T::from_usize(x + RESYNC).unwrap();
+        let tolerance = T::from_u8(4).unwrap() * epsilon::<T>() * reach;
+        abs_diff(folded.clone(), self.0.interpolate(x)) <= tolerance
+    }
+}
+
+impl<I> Space<I>
+where
+    Compensated<I>: Interpolate,
+{
+    /// Uses compensated (Kahan) summation when folding over the space,
+    /// such as with [`sum`](Iterator::sum) or [`for_each`](Iterator::for_each).
+    ///
+    /// Folding a polynomial space steps from each value to the next by adding up
+    /// forward differences, which is fast, but lets rounding errors build up between
+    /// the points where the values are recomputed from scratch.
+    /// This keeps track of those errors at the cost of a few more operations per value.
+    ///
+    /// A [`lin_space`](crate::lin_space) computes every value from its index when folding, so is exact.
+    /// Compensating it folds by adding up the step instead, like a polynomial space,
+    /// with each value within `4 * ε * (|start| + |step| * (i + 64))` of the value at index `i`,
+    /// where `ε` is the gap between 1 and the next value of the type.
+    ///
+    /// ```
+    /// use iter_num_tools::{lin_space, poly_space};
+    ///
+    /// let it = poly_space([0.0, 0.0, 1.0], 0.0..=1.0, 1001).compensated();
+    /// let direct = poly_space([0.0, 0.0, 1.0], 0.0..=1.0, 1001);
+    /// assert!(it.zip(direct).all(|(a, b): (f64, f64)| (a - b).abs() < 1e-15));
+    ///
+    /// let sum: f64 = lin_space(0.0..=1.0, 1001).compensated().sum();
+    /// assert!((sum - 500.5).abs() < 1e-12);
+    /// ```
+    pub fn compensated(self) -> Space<Compensated<I>> {
+        self.map_interpolate(Compensated)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{lin_space, poly_space, PolySpace};
+
+    #[test]
+    fn test_fold_differences() {
+        // x² at 0, 1, 2, 3, 4
+        let diffs = differences([0, 1, 4]);
+        assert_eq!(diffs, [0, 1, 2]);
+        let square = |i: usize| i * i;
+        let diffs_at = |i: usize| differences([square(i), square(i + 1), square(i + 2)]);
+        let push = |mut v: Vec<usize>, x| {
+            v.push(x);
+            v
+        };
+        let values = fold_differences(0..5, false, square, diffs_at, Vec::new(), push);
+        assert_eq!(values, [0, 1, 4, 9, 16]);
+
+        // restarts from the index after every block
+        let values = fold_differences(3..200, false, square, diffs_at, Vec::new(), push);
+        assert!(values.into_iter().eq((3..200).map(square)));
+
+        let diffs_at = |_| [1.0, 0.5];
+        assert_eq!(
+            fold_differences(0..0, true, |_| 1.0, diffs_at, 0.0, |a, x| a + x),
+            0.0
+        );
+    }
+
+    #[test]
+    fn test_poly_differences() {
+        // x² + 1 at 2, 4, 6 is 5, 17, 37
+        assert_eq!(poly_differences([1, 0, 1], 2, 2), [5, 12, 8]);
+        assert_eq!(poly_differences([3.0], 1.0, 0.5), [3.0]);
+    }
+
+    #[test]
+    fn test_running_sum() {
+        // 0.1 can't be represented exactly, so a naive sum drifts away from 1000
+        let (mut naive, mut compensated) = (RunningSum::new(0.0), RunningSum::new(0.0));
+        for _ in 0..10_000 {
+            naive.add(0.1, false);
+            compensated.add(0.1, true);
+        }
+        assert_ne!(naive.sum, 1000.0);
+        assert_eq!(compensated.sum, 1000.0);
+    }
+
+    #[test]
+    fn test_compensated() {
+        let max_err = |compensated: bool| {
+            let it = poly_space([0.5_f64, 0.25, -1.0], 0.0..=10.0, 100_001);
+            let direct = |i| it.clone().nth(i).unwrap();
+            let fold = |(i, err): (usize, f64), x: f64| (i + 1, err.max((x - direct(i)).abs()));
+            let (len, err) = match compensated {
+                true => it.clone().compensated().fold((0, 0.0), fold),
+                false => it.clone().fold((0, 0.0), fold),
+            };
+            assert_eq!(len, it.len());
+            err
+        };
+        assert!(max_err(true) < 1e-13);
+        assert!(max_err(true) <= max_err(false));
+
+        let it = poly_space([0.0, 1.0], 0.0..=1.0, 3).compensated();
+        assert_eq!(
+            format!("{it:?}"),
+            "PolySpace { 0.0..=1.0, len: 3, coeffs: [0.0, 1.0] }"
+        );
+    }
+
+    /// Checks that folding matches each value from `direct` within `tol`, ending on exactly the same value
+    fn check_fold(
+        it: impl DoubleEndedIterator<Item = f64> + Clone,
+        direct: PolySpace<f64, 4>,
+        tol: f64,
+    ) {
+        let mut direct = direct;
+        let last = it.clone().fold(None, |_, x| {
+            let y = direct.next().unwrap();
+            assert!((x - y).abs() <= tol, "{x} != {y}");
+            Some(x)
+        });
+        assert_eq!(direct.next(), None);
+        assert_eq!(last, it.clone().next_back());
+    }
+
+    #[test]
+    fn test_fold_matches_next() {
+        let it = poly_space([0.1, -3.0, 0.7, 0.01], -5.0..=7.3, 100_001);
+        let tol = 1e-12 * (0.01 * 7.3_f64.powi(3));
+        check_fold(it.clone(), it.clone(), tol);
+        check_fold(it.clone().compensated(), it.clone(), tol);
+        it.verify_invariants();
+        it.compensated().verify_invariants();
+
+        let it = poly_space([0.0_f32, 1.0], 0.0..=1.0, 100_001);
+        assert_eq!(it.clone().fold(0.0, |_, x| x), 1.0);
+        assert_eq!(it.compensated().fold(0.0, |_, x| x), 1.0);
+    }
+
+    #[test]
+    fn test_compensated_lin_space() {
+        let it = lin_space(0.0_f32..=1.0, 100_001).compensated();
+        assert_eq!(it.clone().fold(0.0, |_, x| x), 1.0);
+        it.verify_invariants();
+        lin_space(-1e6..=0.1, 100_000)
+            .compensated()
+            .verify_invariants();
+        lin_space(-10..=10, 5).compensated().verify_invariants();
+        assert_eq!(lin_space(0..10, 5).compensated().sum::<i32>(), 20);
+
+        let it = lin_space(0.0..=1.0, 3).compensated();
+        assert_eq!(
+            format!("{it:?}"),
+            "LinSpace { 0.0..=1.0, len: 3, step: 0.5 }"
+        );
+    }
+
+    #[test]
+    fn test_fold_tolerance() {
+        assert_eq!(epsilon::<f64>(), f64::EPSILON);
+        assert_eq!(epsilon::<f32>(), f32::EPSILON);
+        assert_eq!(epsilon::<i32>(), 0);
+        assert_eq!(abs_diff(3_u8, 5), 2);
+
+        // integer folds are exact, so have no tolerance at all
+        let it = poly_space([1, -2, 1], -10..10, 20);
+        assert!(it.interpolate.fold_agrees(3, &64));
+        assert!(!it.interpolate.fold_agrees(3, &65));
+
+        // floats only have to be within the documented bound
+        let it = poly_space([0.1, -3.0, 0.7, 0.01], -5.0..=7.3, 1000);
+        let direct = it.interpolate.interpolate(500);
+        assert!(it.interpolate.fold_agrees(500, &(direct + 1e-13)));
+        assert!(!it.interpolate.fold_agrees(500, &(direct + 1e-10)));
+    }
+}
@@ -0,0 +1,107 @@
+//! The float operations which aren't specified exactly by IEEE 754, so their results depend on the platform.
+//!
+//! With the `strict_fp` feature, these are computed in `f64` by the [`libm`] crate,
+//! rather than by whichever implementation the platform's standard library provides,
+//! so spaces produce the same values on every target.
+//! Addition, multiplication, division and `sqrt` are always correctly rounded, so don't need this.
+//! Rust never contracts a multiply and add into an FMA on its own, so that is reproducible too.
+
+use num_traits::real::Real;
+
+#[cfg(not(feature = "strict_fp"))]
+pub(crate) fn powf<T: Real>(x: T, y: T) -> T {
+    x.powf(y)
+}
+
+#[cfg(not(feature = "strict_fp"))]
+pub(crate) fn ln<T: Real>(x: T) -> T {
+    x.ln()
+}
+
+#[cfg(not(feature = "strict_fp"))]
+pub(crate) fn exp<T: Real>(x: T) -> T {
+    x.exp()
+}
+
+#[cfg(not(feature = "strict_fp"))]
+pub(crate) fn log2<T: Real>(x: T) -> T {
+    x.log2()
+}
+
+#[cfg(not(feature = "strict_fp"))]
+pub(crate) fn exp2<T: Real>(x: T) -> T {
+    x.exp2()
+}
+
+#[cfg(not(feature = "strict_fp"))]
+pub(crate) fn sin_cos<T: Real>(x: T) -> (T, T) {
+    x.sin_cos()
+}
+
+/// Computes `f` in `f64` with libm, falling back to `fallback` if `T` can't be converted
+#[cfg(feature = "strict_fp")]
+fn strict<T: Real, const N: usize>(
+    args: [T; N],
+    f: impl FnOnce([f64; N]) -> f64,
+    fallback: impl FnOnce([T; N]) -> T,
+) -> T {
+    let mut wide = [0.0; N];
+    for (w, x) in wide.iter_mut().zip(args) {
+        match x.to_f64() {
+            Some(x) => *w = x,
+            None => return fallback(args),
+        }
+    }
+    T::from(f(wide)).unwrap_or_else(|| fallback(args))
+}
+
+#[cfg(feature = "strict_fp")]
+pub(crate) fn powf<T: Real>(x: T, y: T) -> T {
+    strict([x, y], |[x, y]| libm::pow(x, y), |[x, y]| x.powf(y))
+}
+
+#[cfg(feature = "strict_fp")]
+pub(crate) fn ln<T: Real>(x: T) -> T {
+    strict([x], |[x]| libm::log(x), |[x]| x.ln())
+}
+
+#[cfg(feature = "strict_fp")]
+pub(crate) fn exp<T: Real>(x: T) -> T {
+    strict([x], |[x]| libm::exp(x), |[x]| x.exp())
+}
+
+#[cfg(feature = "strict_fp")]
+pub(crate) fn log2<T: Real>(x: T) -> T {
+    strict([x], |[x]| libm::log2(x), |[x]| x.log2())
+}
+
+#[cfg(feature = "strict_fp")]
+pub(crate) fn exp2<T: Real>(x: T) -> T {
+    strict([x], |[x]| libm::exp2(x), |[x]| x.exp2())
+}
+
+#[cfg(feature = "strict_fp")]
+pub(crate) fn sin_cos<T: Real>(x: T) -> (T, T) {
+    let sin = strict([x], |[x]| libm::sin(x), |[x]| x.sin());
+    let cos = strict([x], |[x]| libm::cos(x), |[x]| x.cos());
+    (sin, cos)
+}
+
+#[cfg(all(test, feature = "strict_fp"))]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_strict_fp() {
+        assert_eq!(powf(2.0_f64, 0.5), libm::pow(2.0, 0.5));
+        assert_eq!(ln(10.0_f32), libm::log(10.0) as f32);
+        assert_eq!(exp(-0.5_f32), libm::exp(-0.5) as f32);
+
+        assert_eq!(log2(10.0_f64), libm::log2(10.0));
+        assert_eq!(exp2(0.3_f32), libm::exp2(0.3_f32 as f64) as f32);
+
+        let it = crate::log_space(1.0..=1000.0, 4);
+        let exponent = libm::log2(1000.0) / 3.0;
+        assert!(it.eq((0..4).map(|i| libm::exp2(exponent * i as f64))));
+    }
+}
@@ -0,0 +1,92 @@
+//! Common functions to transform spaces with [`Space::map_fn`](crate::Space::map_fn),
+//! which can be named in types, unlike closures.
+//!
+//! ```
+//! use iter_num_tools::{func::{Offset, Scale}, lin_space, LinSpace, Map};
+//!
+//! // 0..=100 celsius in kelvin
+//! let it: Map<Map<LinSpace<f64>, Scale<f64>>, Offset<f64>> =
+//!     lin_space(0.0..=1.0, 5).map_fn(Scale(100.0)).map_fn(Offset(273.15));
+//! assert!(it.eq([273.15, 298.15, 323.15, 348.15, 373.15]));
+//! ```
+
+use core::ops::{Add, Mul};
+use num_traits::{real::Real, Signed};
+
+use crate::{fp, map::Function};
+
+/// Multiplies each value by a constant
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+pub struct Scale<T>(pub T);
+
+impl<T: Mul<Output = T> + Clone> Function<T> for Scale<T> {
+    type Output = T;
+    fn call(&self, x: T) -> T {
+        x * self.0.clone()
+    }
+}
+
+/// Adds a constant to each value
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+pub struct Offset<T>(pub T);
+
+impl<T: Add<Output = T> + Clone> Function<T> for Offset<T> {
+    type Output = T;
+    fn call(&self, x: T) -> T {
+        x + self.0.clone()
+    }
+}
+
+/// Raises each value to an integer power
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+pub struct Powi(pub i32);
+
+impl<T: Real> Function<T> for Powi {
+    type Output = T;
+    fn call(&self, x: T) -> T {
+        x.powi(self.0)
+    }
+}
+
+/// Raises 2 to the power of each value, such as to turn a space of octaves into frequency ratios
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+pub struct Exp2;
+
+impl<T: Real> Function<T> for Exp2 {
+    type Output = T;
+    fn call(&self, x: T) -> T {
+        fp::exp2(x)
+    }
+}
+
+/// Takes the absolute value of each value
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+pub struct Abs;
+
+impl<T: Signed> Function<T> for Abs {
+    type Output = T;
+    fn call(&self, x: T) -> T {
+        x.abs()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{check_double_ended_iter, lin_space};
+
+    #[test]
+    fn test_func() {
+        check_double_ended_iter(lin_space(-2..=2, 5).map_fn(Abs), [2, 1, 0, 1, 2]);
+        check_double_ended_iter(lin_space(1..4, 3).map_fn(Scale(3)), [3, 6, 9]);
+        check_double_ended_iter(lin_space(1..4, 3).map_fn(Offset(-1)), [0, 1, 2]);
+        check_double_ended_iter(
+            lin_space(-1.0..=2.0, 4).map_fn(Powi(2)),
+            [1.0, 0.0, 1.0, 4.0],
+        );
+        check_double_ended_iter(
+            lin_space(-1.0_f32..=2.0, 4).map_fn(Exp2),
+            [0.5, 1.0, 2.0, 4.0],
+        );
+    }
+}
@@ -0,0 +1,213 @@
+use core::fmt;
+
+use crate::{
+    gridspace::{GridIndexInterpolation, GridSpace, GridSpaceInterpolation},
+    linspace::LinearInterpolation,
+    space::{Interpolate, Space, SpaceFmt},
+};
+
+/// Maps the `k`th point on the boundary of a grid to its index in the full grid.
+///
+/// For each axis `j`, this stores the number of points in the grid of the axes before it,
+/// and how many of those are on the boundary, so whole blocks of points can be skipped at once.
+#[derive(Clone, Copy, Debug)]
+pub struct BoundaryInterpolation<I, const N: usize> {
+    pub inner: I,
+    shape: [usize; N],
+    periodic: [bool; N],
+    blocks: [(usize, usize); N],
+}
+
+impl<I, const N: usize> BoundaryInterpolation<I, N> {
+    /// Returns the interpolation, along with the number of points on the boundary
+    fn new(inner: I, shape: [usize; N], periodic: [bool; N]) -> (Self, usize) {
+        // (points, boundary points) in the grid of the axes so far
+        let (mut points, mut boundary) = (1_usize, 0_usize);
+        let blocks = core::array::from_fn(|j| {
+            let block = (points, boundary);
+            let n = shape[j];
+            boundary = match (periodic[j], n) {
+                (true, _) => n * boundary,
+                (false, 0 | 1) => n * points,
+                (false, n) => 2 * points + (n - 2) * boundary,
+            };
+            points *= n;
+            block
+        });
+        let interp = BoundaryInterpolation {
+            inner,
+            shape,
+            periodic,
+            blocks,
+        };
+        (interp, boundary)
+    }
+
+    /// The index into the full grid of the `k`th boundary point
+    fn flat(&self, mut k: usize) -> usize {
+        let mut index = [0; N];
+        let mut on_boundary = false;
+        for j in (0..N).rev() {
+            let (points, boundary) = self.blocks[j];
+            let n = self.shape[j];
+            if on_boundary {
+                // every point below a boundary point is on the boundary too
+                index[j] = k / points;
+                k %= points;
+            } else if self.periodic[j] {
+                index[j] = k / boundary;
+                k %= boundary;
+            } else if k < points {
+                on_boundary = true;
+            } else if k - points < (n - 2) * boundary {
+                k -= points;
+                index[j] = 1 + k / boundary;
+                k %= boundary;
+            } else {
+                k -= points + (n - 2) * boundary;
+                index[j] = n - 1;
+                on_boundary = true;
+            }
+        }
+
+        let mut flat = 0;
+        for (n, z) in self.shape.iter().zip(index).rev() {
+            flat = flat * n + z;
+        }
+        flat
+    }
+}
+
+impl<I: Interpolate, const N: usize> Interpolate for BoundaryInterpolation<I, N> {
+    type Item = I::Item;
+    fn interpolate(&self, k: usize) -> I::Item {
+        self.inner.interpolate(self.flat(k))
+    }
+}
+
+impl<T, const N: usize> SpaceFmt for BoundaryInterpolation<GridSpaceInterpolation<T, N>, N>
+where
+    LinearInterpolation<T>: Interpolate<Item = T>,
+{
+    const NAME: &'static str = "GridBoundary";
+    fn fmt_params(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, ", shape: {:?}", self.shape)
+    }
+}
+
+impl<T, const N: usize> SpaceFmt for BoundaryInterpolation<GridIndexInterpolation<T, N>, N>
+where
+    LinearInterpolation<T>: Interpolate<Item = T>,
+{
+    const NAME: &'static str = "GridBoundaryWithIndices";
+    fn fmt_params(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, ", shape: {:?}", self.shape)
+    }
+}
+
+/// [`Iterator`] returned by [`GridSpace::boundary`]
+pub type GridBoundary<T, const N: usize> =
+    Space<BoundaryInterpolation<GridSpaceInterpolation<T, N>, N>>;
+
+/// [`Iterator`] returned by [`GridBoundary::with_indices`]
+pub type GridBoundaryWithIndices<T, const N: usize> =
+    Space<BoundaryInterpolation<GridIndexInterpolation<T, N>, N>>;
+
+impl<T, const N: usize> GridSpace<T, N> {
+    /// The points of the full grid with an index of 0 or the last index along any axis,
+    /// in the same order as the grid, such as for applying boundary conditions.
+    ///
+    /// Periodic axes (see [`AxisRange::Periodic`](crate::AxisRange::Periodic)) wrap around,
+    /// so they have no boundary of their own.
+    ///
+    /// ```
+    /// use iter_num_tools::grid_space;
+    ///
+    /// let it = grid_space([0, 0]..=[3, 2], [4, 3]).boundary();
+    /// assert_eq!(it.len(), 10);
+    /// assert!(it.eq([
+    ///     [0, 0], [1, 0], [2, 0], [3, 0],
+    ///     [0, 1],                 [3, 1],
+    ///     [0, 2], [1, 2], [2, 2], [3, 2],
+    /// ]));
+    /// ```
+    pub fn boundary(self) -> GridBoundary<T, N> {
+        let shape = self.shape();
+        let periodic = self.interpolate.periodic;
+        let (interp, len) = BoundaryInterpolation::new(self.interpolate, shape, periodic);
+        Space::new(len, interp)
+    }
+}
+
+impl<T, const N: usize> GridBoundary<T, N> {
+    /// Pairs each point on the boundary with its index along each axis.
+    ///
+    /// ```
+    /// use iter_num_tools::grid_space;
+    ///
+    /// let mut it = grid_space([0.0, 0.0]..=[1.0, 1.0], 3).boundary().with_indices();
+    /// assert_eq!(it.nth(3), Some(([0, 1], [0.0, 0.5])));
+    /// assert_eq!(it.next(), Some(([2, 1], [1.0, 0.5])));
+    /// ```
+    pub fn with_indices(self) -> GridBoundaryWithIndices<T, N> {
+        self.map_interpolate(|interp| BoundaryInterpolation {
+            inner: GridIndexInterpolation(interp.inner),
+            shape: interp.shape,
+            periodic: interp.periodic,
+            blocks: interp.blocks,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{grid_space, AxisRange};
+
+    /// Checks the boundary against filtering every point of the grid
+    fn check_boundary<const N: usize>(ranges: [AxisRange<i32>; N], steps: [usize; N]) {
+        let grid = grid_space(ranges, steps);
+        let shape = grid.shape();
+        let periodic = grid.interpolate.periodic;
+        let expected: Vec<_> = grid
+            .clone()
+            .with_indices()
+            .filter(|(index, _)| {
+                (0..N).any(|j| !periodic[j] && (index[j] == 0 || index[j] + 1 == shape[j]))
+            })
+            .collect();
+
+        let boundary = grid.boundary().with_indices();
+        assert_eq!(boundary.len(), expected.len());
+        assert!(boundary.clone().eq(expected.iter().copied()));
+        assert!(boundary.rev().eq(expected.into_iter().rev()));
+    }
+
+    #[test]
+    fn test_boundary() {
+        let r = |n: i32| AxisRange::from(0..n);
+        check_boundary([r(5)], [5]);
+        check_boundary([r(4), r(5)], [4, 5]);
+        check_boundary([r(3), r(4), r(5)], [3, 4, 5]);
+        check_boundary([r(1), r(4), r(2)], [1, 4, 2]);
+        check_boundary([r(4), r(0), r(3)], [4, 0, 3]);
+        check_boundary([], []);
+    }
+
+    #[test]
+    fn test_boundary_periodic() {
+        let r = |n: i32| AxisRange::from(0..n);
+        let p = |n: i32| AxisRange::Periodic(0..n);
+        check_boundary([p(4), r(5)], [4, 5]);
+        check_boundary([r(4), p(5), r(3)], [4, 5, 3]);
+        check_boundary([p(4), p(3)], [4, 3]);
+    }
+
+    #[test]
+    fn test_boundary_fmt() {
+        let it = grid_space([0, 0]..=[2, 2], 3).boundary();
+        assert_eq!(
+            format!("{it:?}"),
+            "GridBoundary { [0, 0]..=[2, 2], len: 8, shape: [3, 3] }"
+        );
+    }
+}
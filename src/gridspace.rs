@@ -1,10 +1,15 @@
 use array_bin_ops::Array;
 
 use crate::{
+    axis::AxisRange,
+    error::Error,
     linspace::{LinearInterpolation, ToLinSpace},
-    space::{Interpolate, IntoSpace, Space},
+    space::{Interpolate, IntoSpace, Space, SpaceFmt},
+    transpose::Transpose,
 };
+use core::fmt;
 use core::ops::{Range, RangeInclusive};
+use num_traits::{FromPrimitive, Num};
 
 /// Creates a linear grid space over range with a fixed number of width and height steps
 ///
@@ -36,7 +41,20 @@ use core::ops::{Range, RangeInclusive};
 ///     [0, 0, 1], [1, 0, 1],
 ///     [0, 1, 1], [1, 1, 1],
 /// ]));
+///
+/// // mixing inclusive and exclusive axes
+/// use iter_num_tools::AxisRange;
+/// let it = grid_space([AxisRange::from(0.0..=1.0), AxisRange::from(0.0..1.0)], [3, 2]);
+/// assert!(it.eq(vec![
+///     [0.0, 0.0], [0.5, 0.0], [1.0, 0.0],
+///     [0.0, 0.5], [0.5, 0.5], [1.0, 0.5],
+/// ]));
 /// ```
+///
+/// # Panics
+///
+/// Panics if the total number of points in the grid overflows a `usize`.
+/// Use [`try_grid_space`] to get an [`Error::Overflow`] instead.
 pub fn grid_space<R, S, const N: usize>(range: R, steps: S) -> GridSpace<R::Item, N>
 where
     R: ToGridSpace<S, N>,
@@ -44,12 +62,173 @@ where
     range.into_grid_space(steps).into_space()
 }
 
+/// Creates a linear grid space over range with a fixed number of width and height steps,
+/// returning an error instead of producing an invalid space
+///
+/// ```
+/// use iter_num_tools::{try_grid_space, Error};
+///
+/// let it = try_grid_space([0.0, 0.0]..=[1.0, 2.0], 3).unwrap();
+/// assert_eq!(it.len(), 9);
+///
+/// assert_eq!(try_grid_space([0.0, 0.0]..=[1.0, 2.0], [3, 0]).unwrap_err(), Error::ZeroSteps);
+/// assert_eq!(try_grid_space([0.0; 4]..[1.0; 4], usize::MAX / 2).unwrap_err(), Error::Overflow);
+/// ```
+pub fn try_grid_space<R, S, const N: usize>(
+    range: R,
+    steps: S,
+) -> Result<GridSpace<R::Item, N>, Error>
+where
+    R: ToGridSpace<S, N>,
+{
+    range.try_into_grid_space(steps).map(IntoSpace::into_space)
+}
+
+/// Creates a grid space over the centers of the cells of an exclusive grid,
+/// which are offset from the corners by half a step along each axis
+///
+/// ```
+/// use iter_num_tools::grid_space_centered;
+///
+/// let it = grid_space_centered([0.0, 0.0]..[1.0, 2.0], [2, 4]);
+/// assert!(it.eq([
+///     [0.25, 0.25], [0.75, 0.25],
+///     [0.25, 0.75], [0.75, 0.75],
+///     [0.25, 1.25], [0.75, 1.25],
+///     [0.25, 1.75], [0.75, 1.75],
+/// ]));
+/// ```
+///
+/// # Panics
+///
+/// Panics if the total number of points in the grid overflows a `usize`
+pub fn grid_space_centered<T, S, const N: usize>(range: Range<[T; N]>, steps: S) -> GridSpace<T, N>
+where
+    T: Num + FromPrimitive + Clone,
+    Range<[T; N]>: ToGridSpace<S, N, Item = T>,
+{
+    let IntoSpace { interpolate, len } = range.into_grid_space(steps);
+    let GridSpaceInterpolation { axes, periodic } = interpolate;
+    let axes = axes.map(|space| IntoSpace::new(space.len, space.interpolate.centered()));
+    IntoGridSpace::new(len, GridSpaceInterpolation { axes, periodic }).into_space()
+}
+
+/// Creates the node grid over an inclusive range along with the grid of cell centers between those nodes.
+///
+/// The cell centers have one fewer point along each axis, and each is computed as the midpoint
+/// `(a + b) / 2` of the two neighbouring node values, so the centers agree with the nodes to the last bit.
+///
+/// ```
+/// use iter_num_tools::grid_space_dual;
+///
+/// let (nodes, centers) = grid_space_dual([0.0, 0.0]..=[1.0, 2.0], [3, 2]);
+/// assert!(nodes.eq([
+///     [0.0, 0.0], [0.5, 0.0], [1.0, 0.0],
+///     [0.0, 2.0], [0.5, 2.0], [1.0, 2.0],
+/// ]));
+/// assert!(centers.eq([[0.25, 1.0], [0.75, 1.0]]));
+/// ```
+///
+/// # Panics
+///
+/// Panics if the total number of points in the grid overflows a `usize`
+pub fn grid_space_dual<T, S, const N: usize>(
+    range: RangeInclusive<[T; N]>,
+    steps: S,
+) -> (GridSpace<T, N>, CellCenters<T, N>)
+where
+    T: Num + FromPrimitive + Clone,
+    RangeInclusive<[T; N]>: ToGridSpace<S, N, Item = T>,
+{
+    let nodes = range.into_grid_space(steps);
+    let axes = nodes
+        .interpolate
+        .axes
+        .clone()
+        .map(|space| IntoSpace::new(space.len.saturating_sub(1), space.interpolate));
+    let len = grid_len(axes.iter().map(|space| space.len));
+    let centers = CellCenterInterpolation(GridSpaceInterpolation::new(axes));
+    (nodes.into_space(), Space::new(len, centers))
+}
+
+/// Creates a grid space from the first point, the step along each axis and the number of points along each axis,
+/// such as the origin, pixel size and dimensions of a raster image.
+///
+/// Each point is found directly as `origin + step * index` along each axis,
+/// so there's no rounding from working out the end of the grid and splitting it back up into steps.
+///
+/// ```
+/// use iter_num_tools::grid_from_origin;
+///
+/// let it = grid_from_origin([10.0, 20.0], [0.5, -0.25], [3, 2]);
+/// assert!(it.eq([
+///     [10.0, 20.0], [10.5, 20.0], [11.0, 20.0],
+///     [10.0, 19.75], [10.5, 19.75], [11.0, 19.75],
+/// ]));
+/// ```
+///
+/// # Panics
+///
+/// Panics if the total number of points in the grid overflows a `usize`
+pub fn grid_from_origin<T, const N: usize>(
+    origin: [T; N],
+    step: [T; N],
+    shape: [usize; N],
+) -> GridSpace<T, N>
+where
+    T: Num + FromPrimitive + Clone,
+{
+    let lerps = Array(origin).zip_map(step, |start, step| LinearInterpolation { start, step });
+    let axes = Array(shape).zip_map(lerps, IntoSpace::new);
+    let len = grid_len(shape);
+    IntoGridSpace::new(len, GridSpaceInterpolation::new(axes)).into_space()
+}
+
 /// Helper trait for [`grid_space`]
 pub trait ToGridSpace<S, const N: usize> {
     /// The item that this is a grid space over
     type Item;
     /// Create the grid space
     fn into_grid_space(self, step: S) -> IntoGridSpace<Self::Item, N>;
+    /// Create the grid space, validating the range and steps
+    fn try_into_grid_space(self, step: S) -> Result<IntoGridSpace<Self::Item, N>, Error>;
+}
+
+/// The number of points in a grid with the given axis lengths,
+/// or `None` if it does not fit in a `usize`
+pub(crate) fn checked_grid_len(lens: impl IntoIterator<Item = usize>) -> Option<usize> {
+    lens.into_iter().try_fold(1_usize, usize::checked_mul)
+}
+
+/// The number of points in a grid with the given axis lengths
+///
+/// # Panics
+///
+/// Panics if the grid size overflows a `usize`
+pub(crate) fn grid_len(lens: impl IntoIterator<Item = usize>) -> usize {
+    checked_grid_len(lens).expect("grid size overflows usize")
+}
+
+/// Creates the linear space along each axis, checking that the total length of the grid fits
+fn try_grid<R: ToLinSpace, const N: usize>(
+    ranges: [R; N],
+    steps: [usize; N],
+    periodic: [bool; N],
+) -> Result<IntoGridSpace<R::Item, N>, Error> {
+    let axes = Array(ranges).zip_map(steps, R::try_into_lin_space);
+    if let Some(&Err(err)) = axes.iter().find(|axis| axis.is_err()) {
+        return Err(err);
+    }
+    let axes = axes.map(|axis| match axis {
+        Ok(axis) => axis,
+        Err(_) => unreachable!(),
+    });
+
+    let len = checked_grid_len(axes.iter().map(|axis| axis.len)).ok_or(Error::Overflow)?;
+    Ok(IntoGridSpace::new(
+        len,
+        GridSpaceInterpolation { axes, periodic },
+    ))
 }
 
 impl<T, const N: usize> ToGridSpace<[usize; N], N> for Range<[T; N]>
@@ -59,17 +238,14 @@ where
     type Item = <Range<T> as ToLinSpace>::Item;
 
     fn into_grid_space(self, steps: [usize; N]) -> IntoGridSpace<Self::Item, N> {
-        let Range { start, end } = self;
+        let lerps = Array(self.transpose()).zip_map(steps, ToLinSpace::into_lin_space);
+        let len = grid_len(lerps.iter().map(|space| space.len));
 
-        let mut len = 1;
-        let ranges = Array(start).zip_map(end, |start, end| start..end);
-        let lerps = Array(ranges).zip_map(steps, |range, step| {
-            let lin_space = range.into_lin_space(step);
-            len *= lin_space.len;
-            lin_space
-        });
+        IntoGridSpace::new(len, GridSpaceInterpolation::new(lerps))
+    }
 
-        IntoGridSpace::new(len, GridSpaceInterpolation(lerps))
+    fn try_into_grid_space(self, steps: [usize; N]) -> Result<IntoGridSpace<Self::Item, N>, Error> {
+        try_grid(self.transpose(), steps, [false; N])
     }
 }
 
@@ -80,17 +256,14 @@ where
     type Item = <RangeInclusive<T> as ToLinSpace>::Item;
 
     fn into_grid_space(self, steps: [usize; N]) -> IntoGridSpace<Self::Item, N> {
-        let (start, end) = self.into_inner();
+        let lerps = Array(self.transpose()).zip_map(steps, ToLinSpace::into_lin_space);
+        let len = grid_len(lerps.iter().map(|space| space.len));
 
-        let mut len = 1;
-        let ranges = Array(start).zip_map(end, RangeInclusive::new);
-        let lerps = Array(ranges).zip_map(steps, |range, step| {
-            let lin_space = range.into_lin_space(step);
-            len *= lin_space.len;
-            lin_space
-        });
+        IntoGridSpace::new(len, GridSpaceInterpolation::new(lerps))
+    }
 
-        IntoGridSpace::new(len, GridSpaceInterpolation(lerps))
+    fn try_into_grid_space(self, steps: [usize; N]) -> Result<IntoGridSpace<Self::Item, N>, Error> {
+        try_grid(self.transpose(), steps, [false; N])
     }
 }
 
@@ -104,8 +277,13 @@ where
         let Range { start, end } = self;
 
         let lerps = Array(start).zip_map(end, |start, end| (start..end).into_lin_space(steps));
+        let len = grid_len(lerps.iter().map(|space| space.len));
 
-        IntoGridSpace::new(steps.pow(N as u32), GridSpaceInterpolation(lerps))
+        IntoGridSpace::new(len, GridSpaceInterpolation::new(lerps))
+    }
+
+    fn try_into_grid_space(self, steps: usize) -> Result<IntoGridSpace<Self::Item, N>, Error> {
+        self.try_into_grid_space([steps; N])
     }
 }
 
@@ -119,21 +297,75 @@ where
         let (start, end) = self.into_inner();
 
         let lerps = Array(start).zip_map(end, |start, end| (start..=end).into_lin_space(steps));
+        let len = grid_len(lerps.iter().map(|space| space.len));
+
+        IntoGridSpace::new(len, GridSpaceInterpolation::new(lerps))
+    }
+
+    fn try_into_grid_space(self, steps: usize) -> Result<IntoGridSpace<Self::Item, N>, Error> {
+        self.try_into_grid_space([steps; N])
+    }
+}
+
+impl<T, const N: usize> ToGridSpace<[usize; N], N> for [AxisRange<T>; N]
+where
+    AxisRange<T>: ToLinSpace,
+{
+    type Item = <AxisRange<T> as ToLinSpace>::Item;
+
+    fn into_grid_space(self, steps: [usize; N]) -> IntoGridSpace<Self::Item, N> {
+        let periodic = self
+            .each_ref()
+            .map(|range| matches!(range, AxisRange::Periodic(_)));
+
+        let axes = Array(self).zip_map(steps, ToLinSpace::into_lin_space);
+        let len = grid_len(axes.iter().map(|space| space.len));
+
+        IntoGridSpace::new(len, GridSpaceInterpolation { axes, periodic })
+    }
+
+    fn try_into_grid_space(self, steps: [usize; N]) -> Result<IntoGridSpace<Self::Item, N>, Error> {
+        let periodic = self
+            .each_ref()
+            .map(|range| matches!(range, AxisRange::Periodic(_)));
+        try_grid(self, steps, periodic)
+    }
+}
+
+impl<T, const N: usize> ToGridSpace<usize, N> for [AxisRange<T>; N]
+where
+    AxisRange<T>: ToLinSpace,
+{
+    type Item = <AxisRange<T> as ToLinSpace>::Item;
+
+    fn into_grid_space(self, steps: usize) -> IntoGridSpace<Self::Item, N> {
+        let periodic = self
+            .each_ref()
+            .map(|range| matches!(range, AxisRange::Periodic(_)));
+        let axes = self.map(|range| range.into_lin_space(steps));
+        let len = grid_len(axes.iter().map(|space| space.len));
+
+        IntoGridSpace::new(len, GridSpaceInterpolation { axes, periodic })
+    }
 
-        IntoGridSpace::new(steps.pow(N as u32), GridSpaceInterpolation(lerps))
+    fn try_into_grid_space(self, steps: usize) -> Result<IntoGridSpace<Self::Item, N>, Error> {
+        self.try_into_grid_space([steps; N])
     }
 }
 
 #[derive(Clone, Copy, Debug)]
-pub struct GridSpaceInterpolation<T, const N: usize>(pub [IntoSpace<LinearInterpolation<T>>; N]);
+pub struct GridSpaceInterpolation<T, const N: usize> {
+    pub axes: [IntoSpace<LinearInterpolation<T>>; N],
+    pub periodic: [bool; N],
+}
 
 impl<T, const N: usize> Interpolate for GridSpaceInterpolation<T, N>
 where
     LinearInterpolation<T>: Interpolate<Item = T>,
 {
     type Item = [T; N];
-    fn interpolate(self, mut x: usize) -> [T; N] {
-        self.0.map(|space| {
+    fn interpolate(&self, mut x: usize) -> [T; N] {
+        self.axes.each_ref().map(|space| {
             let z = x % space.len;
             x /= space.len;
             space.interpolate.interpolate(z)
@@ -141,15 +373,454 @@ where
     }
 }
 
+impl<T, const N: usize> SpaceFmt for GridSpaceInterpolation<T, N>
+where
+    LinearInterpolation<T>: Interpolate<Item = T>,
+{
+    const NAME: &'static str = "GridSpace";
+    fn fmt_params(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            ", shape: {:?}",
+            self.axes.each_ref().map(|axis| axis.len)
+        )
+    }
+}
+
+impl<T, const N: usize> SpaceFmt for GridIndexInterpolation<T, N>
+where
+    LinearInterpolation<T>: Interpolate<Item = T>,
+{
+    const NAME: &'static str = "GridSpaceWithIndices";
+    fn fmt_params(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.0.fmt_params(f)
+    }
+}
+
+impl<T, const N: usize> GridSpaceInterpolation<T, N> {
+    pub fn new(axes: [IntoSpace<LinearInterpolation<T>>; N]) -> Self {
+        GridSpaceInterpolation {
+            axes,
+            periodic: [false; N],
+        }
+    }
+
+    /// Splits a flat index into the index along each axis
+    pub(crate) fn unravel(&self, mut x: usize) -> [usize; N] {
+        self.axes.each_ref().map(|space| {
+            let z = x % space.len;
+            x /= space.len;
+            z
+        })
+    }
+
+    /// Combines the index along each axis into a flat index
+    pub(crate) fn ravel(&self, index: [usize; N]) -> usize {
+        let mut x = 0;
+        for (space, z) in self.axes.iter().zip(index).rev() {
+            x = x * space.len + z;
+        }
+        x
+    }
+}
+
+#[derive(Clone, Copy, Debug)]
+pub struct GridIndexInterpolation<T, const N: usize>(pub GridSpaceInterpolation<T, N>);
+
+impl<T, const N: usize> Interpolate for GridIndexInterpolation<T, N>
+where
+    LinearInterpolation<T>: Interpolate<Item = T>,
+{
+    type Item = ([usize; N], [T; N]);
+    fn interpolate(&self, x: usize) -> Self::Item {
+        let index = self.0.unravel(x);
+        let values = Array(self.0.axes.each_ref())
+            .zip_map(index, |space, z| space.interpolate.interpolate(z));
+        (index, values)
+    }
+}
+
+/// The node axes, with one fewer point along each, so the index of a cell is the index of the node before it
+#[derive(Clone, Copy, Debug)]
+pub struct CellCenterInterpolation<T, const N: usize>(pub GridSpaceInterpolation<T, N>);
+
+impl<T, const N: usize> Interpolate for CellCenterInterpolation<T, N>
+where
+    T: Num,
+    LinearInterpolation<T>: Interpolate<Item = T>,
+{
+    type Item = [T; N];
+    fn interpolate(&self, x: usize) -> [T; N] {
+        let index = self.0.unravel(x);
+        Array(self.0.axes.each_ref()).zip_map(index, |space, z| {
+            let a = space.interpolate.interpolate(z);
+            let b = space.interpolate.interpolate(z + 1);
+            (a + b) / (T::one() + T::one())
+        })
+    }
+}
+
+impl<T, const N: usize> SpaceFmt for CellCenterInterpolation<T, N>
+where
+    T: Num,
+    LinearInterpolation<T>: Interpolate<Item = T>,
+{
+    const NAME: &'static str = "CellCenters";
+    fn fmt_params(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.0.fmt_params(f)
+    }
+}
+
+/// [`Iterator`] returned by [`GridSpace::with_indices`]
+pub type GridSpaceWithIndices<T, const N: usize> = Space<GridIndexInterpolation<T, N>>;
+
 /// [`Iterator`] returned by [`grid_space`]
+///
+/// `T` is the type of the coordinates along each axis and `N` is the number of axes,
+/// so each value is a `[T; N]`. These are the only parameters, so it can be stored in a struct
+/// without naming the range that the grid was created from.
+///
+/// ```
+/// use iter_num_tools::{grid_space, GridSpace};
+///
+/// struct Heightmap {
+///     points: GridSpace<f32, 2>,
+/// }
+///
+/// let map = Heightmap {
+///     points: grid_space([0.0, 0.0]..=[1.0, 1.0], 3),
+/// };
+/// assert_eq!(map.points.len(), 9);
+/// ```
 pub type GridSpace<T, const N: usize> = Space<GridSpaceInterpolation<T, N>>;
 
+/// [`Iterator`] over the cell centers returned by [`grid_space_dual`]
+pub type CellCenters<T, const N: usize> = Space<CellCenterInterpolation<T, N>>;
+
 /// [`IntoIterator`] returned by [`ToGridSpace::into_grid_space`]
 pub type IntoGridSpace<T, const N: usize> = IntoSpace<GridSpaceInterpolation<T, N>>;
 
+impl<T, const N: usize> GridSpace<T, N> {
+    /// Maps an index along each axis, which may be out of range, back into the grid.
+    ///
+    /// Indices along periodic axes (see [`AxisRange::Periodic`]) wrap around,
+    /// while an out of range index along any other axis returns `None`.
+    ///
+    /// ```
+    /// use iter_num_tools::{grid_space, AxisRange};
+    /// use core::f64::consts::PI;
+    ///
+    /// let grid = grid_space([AxisRange::Periodic(0.0..2.0 * PI), AxisRange::from(0.0..=1.0)], [8, 3]);
+    /// assert_eq!(grid.wrap_index([-1, 2]), Some([7, 2]));
+    /// assert_eq!(grid.wrap_index([9, 0]), Some([1, 0]));
+    /// assert_eq!(grid.wrap_index([0, 3]), None);
+    /// ```
+    pub fn wrap_index(&self, index: [isize; N]) -> Option<[usize; N]> {
+        let GridSpaceInterpolation { axes, periodic } = &self.interpolate;
+        let mut wrapped = [0; N];
+        for (i, z) in index.into_iter().enumerate() {
+            let len = axes[i].len;
+            wrapped[i] = if periodic[i] && len > 0 {
+                (z as i128).rem_euclid(len as i128) as usize
+            } else {
+                usize::try_from(z).ok().filter(|&z| z < len)?
+            };
+        }
+        Some(wrapped)
+    }
+
+    /// Splits an index into the full grid into the index along each axis,
+    /// in the same order as the grid is iterated, so the first axis varies the fastest.
+    ///
+    /// ```
+    /// use iter_num_tools::grid_space;
+    ///
+    /// let it = grid_space([0.0, 0.0]..[1.0, 2.0], [2, 4]);
+    /// assert_eq!(it.unravel(5), [1, 2]);
+    /// assert_eq!(it.ravel([1, 2]), 5);
+    /// assert_eq!(it.clone().nth(5), Some([0.5, 1.0]));
+    /// ```
+    ///
+    /// # Panics
+    ///
+    /// Panics if the index is past the end of the full grid
+    pub fn unravel(&self, flat: usize) -> [usize; N] {
+        let len = grid_len(self.shape());
+        assert!(
+            flat < len,
+            "index {flat} out of bounds for grid of length {len}"
+        );
+        self.interpolate.unravel(flat)
+    }
+
+    /// Combines the index along each axis into an index into the full grid.
+    /// The inverse of [`unravel`](Self::unravel).
+    ///
+    /// # Panics
+    ///
+    /// Panics if any index is past the end of its axis
+    pub fn ravel(&self, index: [usize; N]) -> usize {
+        let shape = self.shape();
+        assert!(
+            index.iter().zip(&shape).all(|(z, len)| z < len),
+            "index {index:?} out of bounds for grid of shape {shape:?}"
+        );
+        self.interpolate.ravel(index)
+    }
+
+    /// The number of values along each axis of the full grid,
+    /// such as for allocating an array to hold a value for every point.
+    ///
+    /// Until any values are consumed, [`len`](ExactSizeIterator::len) is the product of the shape.
+    ///
+    /// ```
+    /// use iter_num_tools::grid_space;
+    ///
+    /// let mut it = grid_space([0.0, 0.0]..[1.0, 2.0], [2, 4]);
+    /// assert_eq!(it.shape(), [2, 4]);
+    /// assert_eq!(it.len(), it.shape().iter().product::<usize>());
+    ///
+    /// it.next();
+    /// assert_eq!(it.shape(), [2, 4]);
+    /// ```
+    pub fn shape(&self) -> [usize; N] {
+        self.interpolate.axes.each_ref().map(|axis| axis.len)
+    }
+
+    /// Pairs each point of the grid with its index along each axis.
+    ///
+    /// ```
+    /// use iter_num_tools::grid_space;
+    ///
+    /// let it = grid_space([0.0, 0.0]..=[1.0, 2.0], [2, 3]).with_indices();
+    /// assert!(it.eq([
+    ///     ([0, 0], [0.0, 0.0]), ([1, 0], [1.0, 0.0]),
+    ///     ([0, 1], [0.0, 1.0]), ([1, 1], [1.0, 1.0]),
+    ///     ([0, 2], [0.0, 2.0]), ([1, 2], [1.0, 2.0]),
+    /// ]));
+    /// ```
+    pub fn with_indices(self) -> GridSpaceWithIndices<T, N> {
+        self.map_interpolate(GridIndexInterpolation)
+    }
+}
+
+impl<T: Num + FromPrimitive + Clone, const N: usize> GridSpace<T, N> {
+    /// Refines every axis of the grid so that there are `factor` times as many
+    /// intervals between the same first and last values.
+    ///
+    /// The remaining values are mapped onto the refined grid,
+    /// so a partially consumed grid stays partially consumed.
+    /// Exclusive axes stop at their last point, rather than filling in up to the excluded end.
+    /// Periodic axes wrap around, so their interval from the last point back to the first
+    /// is refined too, giving `factor` times as many points.
+    ///
+    /// ```
+    /// use iter_num_tools::grid_space;
+    ///
+    /// let it = grid_space([0.0, 0.0]..=[1.0, 2.0], 2).subdivide(2);
+    /// assert!(it.eq([
+    ///     [0.0, 0.0], [0.5, 0.0], [1.0, 0.0],
+    ///     [0.0, 1.0], [0.5, 1.0], [1.0, 1.0],
+    ///     [0.0, 2.0], [0.5, 2.0], [1.0, 2.0],
+    /// ]));
+    /// ```
+    ///
+    /// # Panics
+    ///
+    /// Panics if `factor` is zero
+    pub fn subdivide(self, factor: usize) -> Self {
+        assert!(factor > 0, "subdivide factor must be non-zero");
+        let Space {
+            interpolate, range, ..
+        } = self;
+        let factor_t = T::from_usize(factor).unwrap();
+
+        let periodic = interpolate.periodic;
+        let axes = Array(interpolate.axes.clone()).zip_map(periodic, |space, periodic| {
+            let IntoSpace { interpolate, len } = space;
+            let LinearInterpolation { start, step } = interpolate;
+            let len = match (len, periodic) {
+                (0, _) => 0,
+                // the axis wraps around, so there are also `factor` intervals after the last point
+                (len, true) => len * factor,
+                (len, false) => (len - 1) * factor + 1,
+            };
+            IntoSpace::new(
+                len,
+                LinearInterpolation {
+                    start,
+                    step: step / factor_t.clone(),
+                },
+            )
+        });
+        let refined = GridSpaceInterpolation { axes, periodic };
+
+        let range = if range.is_empty() {
+            0..0
+        } else {
+            let first = interpolate.unravel(range.start).map(|z| z * factor);
+            let mut last = interpolate.unravel(range.end - 1);
+            for i in 0..N {
+                // the end of a periodic axis also takes the new points before it wraps around
+                last[i] = match periodic[i] && last[i] + 1 == interpolate.axes[i].len {
+                    true => refined.axes[i].len - 1,
+                    false => last[i] * factor,
+                };
+            }
+            refined.ravel(first)..refined.ravel(last) + 1
+        };
+
+        Space::with_range(range, refined)
+    }
+
+    /// Carves out the box of indices given by `ranges` along each axis as a new grid space.
+    ///
+    /// The ranges index into the full grid, regardless of how much of it has been consumed.
+    /// Periodic axes stay periodic only if their range covers the whole axis.
+    ///
+    /// ```
+    /// use iter_num_tools::grid_space;
+    ///
+    /// let it = grid_space([0.0, 0.0]..[1.0, 2.0], [4, 4]).subgrid([1..3, 2..4]);
+    /// assert!(it.eq([
+    ///     [0.25, 1.0], [0.5, 1.0],
+    ///     [0.25, 1.5], [0.5, 1.5],
+    /// ]));
+    /// ```
+    ///
+    /// # Panics
+    ///
+    /// Panics if any range is decreasing or extends past the end of its axis
+    pub fn subgrid(self, ranges: [Range<usize>; N]) -> Self {
+        let GridSpaceInterpolation { axes, periodic } = self.interpolate;
+        let mut len = 1;
+        let full =
+            Array(axes.each_ref()).zip_map(ranges.clone(), |space, range| range == (0..space.len));
+        let periodic = Array(periodic).zip_map(full, |periodic, full| periodic && full);
+        let axes = Array(axes).zip_map(ranges, |space, range| {
+            assert!(
+                range.start <= range.end && range.end <= space.len,
+                "subgrid range {:?} out of bounds for axis of length {}",
+                range,
+                space.len
+            );
+            len *= range.len();
+            let step = space.interpolate.step.clone();
+            let start = space.interpolate.interpolate(range.start);
+            IntoSpace::new(range.len(), LinearInterpolation { start, step })
+        });
+
+        IntoGridSpace::new(len, GridSpaceInterpolation { axes, periodic }).into_space()
+    }
+
+    /// The points of the full grid which aren't on its [`boundary`](Self::boundary),
+    /// as a new grid space, such as for updating a stencil that reads the neighbours of each point.
+    ///
+    /// This is a [`subgrid`](Self::subgrid) without the first and last index along each axis,
+    /// so its indices are one less than the same point in the full grid.
+    /// Periodic axes have no boundary, so are kept whole.
+    ///
+    /// ```
+    /// use iter_num_tools::grid_space;
+    ///
+    /// let it = grid_space([0, 0]..=[3, 3], 4).interior();
+    /// assert_eq!(it.shape(), [2, 2]);
+    /// assert!(it.eq([[1, 1], [2, 1], [1, 2], [2, 2]]));
+    /// ```
+    pub fn interior(self) -> Self {
+        let shape = self.shape();
+        let periodic = self.interpolate.periodic;
+        let ranges = Array(shape).zip_map(periodic, |len, periodic| match periodic {
+            true => 0..len,
+            false => {
+                let start = len.min(1);
+                start..len.saturating_sub(1).max(start)
+            }
+        });
+        self.subgrid(ranges)
+    }
+
+    /// The inverse of [`subdivide`](Self::subdivide). Keeps every `factor`th value along each axis.
+    ///
+    /// Returns `None` if `factor` is zero or if the end points of any axis,
+    /// or the first and last remaining values, would not be kept.
+    /// Periodic axes wrap around, so need a number of points divisible by `factor` instead.
+    ///
+    /// ```
+    /// use iter_num_tools::grid_space;
+    ///
+    /// let it = grid_space([0.0, 0.0]..=[1.0, 2.0], 3).coarsen(2).unwrap();
+    /// assert!(it.eq([
+    ///     [0.0, 0.0], [1.0, 0.0],
+    ///     [0.0, 2.0], [1.0, 2.0],
+    /// ]));
+    /// ```
+    pub fn coarsen(self, factor: usize) -> Option<Self> {
+        if factor == 0 {
+            return None;
+        }
+        let Space {
+            interpolate, range, ..
+        } = self;
+        let factor_t = T::from_usize(factor).unwrap();
+
+        let periodic = interpolate.periodic;
+        // periodic axes wrap around, so need a whole number of intervals of the coarser axis,
+        // rather than a whole number of intervals between their first and last points
+        let intervals = |len: usize, periodic: bool| match periodic {
+            true => len,
+            false => len.saturating_sub(1),
+        };
+        if (interpolate.axes.iter().zip(periodic))
+            .any(|(space, periodic)| intervals(space.len, periodic) % factor != 0)
+        {
+            return None;
+        }
+
+        let axes = Array(interpolate.axes.clone()).zip_map(periodic, |space, periodic| {
+            let IntoSpace { interpolate, len } = space;
+            let LinearInterpolation { start, step } = interpolate;
+            let len = match (len, periodic) {
+                (0, _) => 0,
+                (len, true) => len / factor,
+                (len, false) => (len - 1) / factor + 1,
+            };
+            IntoSpace::new(
+                len,
+                LinearInterpolation {
+                    start,
+                    step: step * factor_t.clone(),
+                },
+            )
+        });
+        let coarse = GridSpaceInterpolation { axes, periodic };
+
+        let range = if range.is_empty() {
+            0..0
+        } else {
+            let first = interpolate.unravel(range.start);
+            let mut last = interpolate.unravel(range.end - 1);
+            if first.iter().any(|z| z % factor != 0) {
+                return None;
+            }
+            for i in 0..N {
+                // the inverse of `subdivide`, where the end of a periodic axis stays at the end
+                last[i] = match periodic[i] && last[i] + 1 == interpolate.axes[i].len {
+                    true => coarse.axes[i].len - 1,
+                    false if last[i] % factor == 0 => last[i] / factor,
+                    false => return None,
+                };
+            }
+            coarse.ravel(first.map(|z| z / factor))..coarse.ravel(last) + 1
+        };
+
+        Some(Space::with_range(range, coarse))
+    }
+}
+
 #[cfg(test)]
 mod tests {
-    use crate::check_double_ended_iter;
+    use crate::{check_double_ended_iter, lin_space};
 
     use super::*;
 
@@ -220,6 +891,292 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_grid_space_axis_range() {
+        check_double_ended_iter(
+            grid_space([AxisRange::from(0.0..1.0), AxisRange::from(0.0..=1.0)], 2),
+            [[0.0, 0.0], [0.5, 0.0], [0.0, 1.0], [0.5, 1.0]],
+        );
+    }
+
+    #[test]
+    fn test_try_grid_space() {
+        check_double_ended_iter(
+            try_grid_space([0.0, 0.0]..[1.0, 1.0], 2).unwrap(),
+            [[0.0, 0.0], [0.5, 0.0], [0.0, 0.5], [0.5, 0.5]],
+        );
+        check_double_ended_iter(
+            try_grid_space([0.0, 0.0]..=[1.0, 1.0], [2, 1]).unwrap(),
+            [[0.0, 0.0], [1.0, 0.0]],
+        );
+        assert_eq!(
+            try_grid_space([0.0, f64::NAN]..[1.0, 1.0], 2).unwrap_err(),
+            Error::NonFinite
+        );
+        assert_eq!(
+            try_grid_space(
+                [AxisRange::from(0.0..1.0), AxisRange::from(0.0..=1.0)],
+                [usize::MAX, 3]
+            )
+            .unwrap_err(),
+            Error::Overflow
+        );
+    }
+
+    #[test]
+    fn test_grid_space_with_indices() {
+        let mut it = grid_space([0.0, 0.0]..[1.0, 1.0], 2);
+        it.next();
+        check_double_ended_iter(
+            it.with_indices(),
+            [
+                ([1, 0], [0.5, 0.0]),
+                ([0, 1], [0.0, 0.5]),
+                ([1, 1], [0.5, 0.5]),
+            ],
+        );
+    }
+
+    #[test]
+    fn test_grid_space_centered() {
+        check_double_ended_iter(
+            grid_space_centered([0.0, 1.0]..[1.0, 2.0], 2),
+            [[0.25, 1.25], [0.75, 1.25], [0.25, 1.75], [0.75, 1.75]],
+        );
+    }
+
+    #[test]
+    fn test_grid_space_dual() {
+        let (nodes, centers) = grid_space_dual([0.0, 0.0]..=[1.0, 1.0], 3);
+        check_double_ended_iter(
+            centers,
+            [[0.25, 0.25], [0.75, 0.25], [0.25, 0.75], [0.75, 0.75]],
+        );
+        assert_eq!(nodes.len(), 9);
+
+        let (nodes, centers) = grid_space_dual([0.0, 0.0]..=[1.0, 1.0], [3, 1]);
+        assert_eq!(nodes.len(), 3);
+        assert_eq!(centers.len(), 0);
+
+        // each center is exactly the midpoint of its neighbouring nodes
+        let (nodes, centers) = grid_space_dual([0.1]..=[7.3], [1001]);
+        let nodes: Vec<f64> = nodes.map(|[x]| x).collect();
+        let midpoints = nodes.windows(2).map(|n| (n[0] + n[1]) / 2.0);
+        assert!(centers
+            .map(|[x]| x.to_bits())
+            .eq(midpoints.map(f64::to_bits)));
+        assert_eq!(
+            format!("{:?}", grid_space_dual([0.0]..=[1.0], [3]).1),
+            "CellCenters { [0.25]..=[0.75], len: 2, shape: [2] }"
+        );
+    }
+
+    #[test]
+    fn test_grid_from_origin() {
+        let it = grid_from_origin([1, 10], [2, -5], [3, 2]);
+        assert_eq!(it.shape(), [3, 2]);
+        check_double_ended_iter(it, [[1, 10], [3, 10], [5, 10], [1, 5], [3, 5], [5, 5]]);
+
+        // the last point is exactly origin + step * index
+        let it = grid_from_origin([0.1, 0.1], [0.1, 0.3], [10, 10]);
+        assert_eq!(it.last(), Some([0.1 + 0.1 * 9.0, 0.1 + 0.3 * 9.0]));
+        assert_eq!(grid_from_origin([0.0; 2], [1.0; 2], [0, 3]).len(), 0);
+    }
+
+    #[test]
+    fn test_grid_space_fmt() {
+        let it = grid_space([0.0, 0.0]..[1.0, 2.0], [2, 4]);
+        assert_eq!(
+            format!("{it:?}"),
+            "GridSpace { [0.0, 0.0]..=[0.5, 1.5], len: 8, shape: [2, 4] }"
+        );
+        assert_eq!(it.to_string(), "[0.0, 0.0]..=[0.5, 1.5] (8 values)");
+
+        let it = grid_space([0.0, 0.0]..[1.0, 2.0], 0);
+        assert_eq!(
+            format!("{it:?}"),
+            "GridSpace { empty, len: 0, shape: [0, 0] }"
+        );
+    }
+
+    #[test]
+    fn test_grid_space_few_steps() {
+        assert_eq!(grid_space([0.0, 0.0]..=[1.0, 2.0], [3, 0]).next(), None);
+        check_double_ended_iter(
+            grid_space([0.0, 0.0]..=[1.0, 2.0], [3, 1]),
+            [[0.0, 0.0], [0.5, 0.0], [1.0, 0.0]],
+        );
+        check_double_ended_iter(grid_space([1.0, 2.0]..=[3.0, 4.0], 1), [[1.0, 2.0]]);
+    }
+
+    #[test]
+    #[should_panic(expected = "grid size overflows usize")]
+    fn test_grid_space_overflow() {
+        grid_space([0.0; 4]..[1.0; 4], usize::MAX / 2);
+    }
+
+    #[test]
+    fn test_grid_space_periodic() {
+        let it = grid_space(
+            [AxisRange::Periodic(0.0..1.0), AxisRange::from(0.0..=1.0)],
+            [4, 2],
+        );
+        assert_eq!(it.wrap_index([-5, 1]), Some([3, 1]));
+        assert_eq!(it.wrap_index([4, -1]), None);
+        assert_eq!(it.wrap_index([1, 1]), Some([1, 1]));
+
+        // only the full periodic axis is still periodic
+        let sub = it.clone().subgrid([0..4, 0..1]);
+        assert_eq!(sub.wrap_index([-1, 0]), Some([3, 0]));
+        let sub = it.subgrid([1..4, 0..1]);
+        assert_eq!(sub.wrap_index([-1, 0]), None);
+    }
+
+    #[test]
+    fn test_grid_space_periodic_subdivide() {
+        let it = grid_space(
+            [AxisRange::Periodic(0.0..8.0), AxisRange::from(0.0..=1.0)],
+            [8, 2],
+        );
+
+        // the refined axis still wraps around, so also gets the points after the last one
+        let fine = it.clone().subdivide(2);
+        assert_eq!(fine.shape(), [16, 3]);
+        assert_eq!(fine.len(), 48);
+        assert_eq!(fine.wrap_index([-1, 0]), Some([15, 0]));
+        assert_eq!(fine.clone().last(), Some([7.5, 1.0]));
+        let xs = lin_space(0.0..8.0, 16);
+        assert!(fine.clone().take(16).eq(xs.map(|x| [x, 0.0])));
+
+        let coarse = fine.coarsen(2).unwrap();
+        assert!(coarse.eq(it.clone()));
+
+        let coarse = grid_space(
+            [AxisRange::Periodic(0.0..8.0), AxisRange::from(0.0..=1.0)],
+            [8, 5],
+        );
+        let coarse = coarse.coarsen(4).unwrap();
+        assert_eq!(coarse.shape(), [2, 2]);
+        assert_eq!(coarse.wrap_index([-1, 0]), Some([1, 0]));
+        check_double_ended_iter(coarse, [[0.0, 0.0], [4.0, 0.0], [0.0, 1.0], [4.0, 1.0]]);
+        assert!(it.clone().coarsen(3).is_none());
+
+        // a partially consumed periodic axis only wraps from its end
+        let mut it = it;
+        it.nth_back(3);
+        let fine = it.clone().subdivide(2);
+        assert_eq!(fine.clone().last(), Some([3.0, 1.0]));
+        assert!(fine.coarsen(2).unwrap().eq(it));
+    }
+
+    #[test]
+    fn test_grid_space_subdivide() {
+        let mut it = grid_space([0.0, 0.0]..[1.0, 1.0], 2);
+        it.next();
+        it.next_back();
+        check_double_ended_iter(
+            it.clone().subdivide(2),
+            [
+                [0.5, 0.0],
+                [0.0, 0.25],
+                [0.25, 0.25],
+                [0.5, 0.25],
+                [0.0, 0.5],
+            ],
+        );
+
+        check_double_ended_iter(
+            it.clone().subdivide(3).coarsen(3).unwrap(),
+            [[0.5, 0.0], [0.0, 0.5]],
+        );
+
+        assert!(it.clone().coarsen(0).is_none());
+        assert!(it.coarsen(2).is_none());
+    }
+
+    #[test]
+    fn test_grid_space_subgrid() {
+        let it = grid_space([0.0, 0.0, 0.0]..=[2.0, 2.0, 2.0], 3);
+        check_double_ended_iter(
+            it.subgrid([1..3, 0..3, 2..3]),
+            [
+                [1.0, 0.0, 2.0],
+                [2.0, 0.0, 2.0],
+                [1.0, 1.0, 2.0],
+                [2.0, 1.0, 2.0],
+                [1.0, 2.0, 2.0],
+                [2.0, 2.0, 2.0],
+            ],
+        );
+
+        let it = grid_space([0.0, 0.0]..[1.0, 1.0], 2);
+        assert_eq!(it.subgrid([1..1, 0..2]).len(), 0);
+    }
+
+    #[test]
+    fn test_grid_space_shape() {
+        let it = grid_space([0.0, 0.0, 0.0]..=[1.0, 2.0, 3.0], [2, 3, 4]);
+        assert_eq!(it.shape(), [2, 3, 4]);
+        assert_eq!(it.len(), it.shape().iter().product::<usize>());
+
+        let it = it.subdivide(2);
+        assert_eq!(it.shape(), [3, 5, 7]);
+        assert_eq!(it.len(), it.shape().iter().product::<usize>());
+
+        let it = it.subgrid([0..2, 1..5, 3..4]);
+        assert_eq!(it.shape(), [2, 4, 1]);
+        assert_eq!(it.len(), it.shape().iter().product::<usize>());
+
+        assert_eq!(grid_space([0.0; 2]..[1.0; 2], [0, 3]).shape(), [0, 3]);
+    }
+
+    #[test]
+    fn test_grid_space_ravel() {
+        let it = grid_space([0, 0, 0]..[2, 3, 4], [2, 3, 4]);
+        for (flat, point) in it.clone().enumerate() {
+            let index = it.unravel(flat);
+            assert_eq!(index.map(|z| z as i32), point);
+            assert_eq!(it.ravel(index), flat);
+        }
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_grid_space_unravel_out_of_bounds() {
+        grid_space([0.0, 0.0]..[1.0, 1.0], 2).unravel(4);
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_grid_space_ravel_out_of_bounds() {
+        grid_space([0.0, 0.0]..[1.0, 1.0], 2).ravel([0, 2]);
+    }
+
+    #[test]
+    fn test_grid_space_interior() {
+        let it = grid_space([0, 0, 0]..[5, 4, 3], [5, 4, 3]).interior();
+        assert_eq!(it.shape(), [3, 2, 1]);
+        assert_eq!(it.bounds(), Some([1, 1, 1]..=[3, 2, 1]));
+
+        for n in 0..3 {
+            let it = grid_space([0.0; 2]..[1.0; 2], [n, 4]).interior();
+            assert_eq!(it.len(), 0);
+        }
+
+        // the interior and boundary make up the whole grid
+        let grid = grid_space([AxisRange::Periodic(0..4), AxisRange::from(0..5)], [4, 5]);
+        let interior = grid.clone().interior();
+        assert_eq!(interior.shape(), [4, 3]);
+        assert_eq!(interior.interpolate.periodic, [true, false]);
+        assert_eq!(interior.len() + grid.clone().boundary().len(), grid.len());
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_grid_space_subgrid_out_of_bounds() {
+        grid_space([0.0, 0.0]..[1.0, 1.0], 2).subgrid([0..3, 0..2]);
+    }
+
     #[test]
     fn test_grid_space_exclusive_len() {
         let mut it = grid_space([0.0, 0.0]..[1.0, 2.0], [2, 4]);
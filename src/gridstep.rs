@@ -1,9 +1,12 @@
 use array_bin_ops::Array;
 
 use crate::{
-    space::{Interpolate, IntoSpace, Space},
+    axis::AxisRange,
+    gridspace::grid_len,
+    space::{Interpolate, IntoSpace, Space, SpaceFmt},
     step::Step,
 };
+use core::fmt;
 use core::ops::{Range, RangeInclusive};
 
 /// Creates a iterator over a range of arrays
@@ -38,7 +41,19 @@ use core::ops::{Range, RangeInclusive};
 ///     [0, 0, 1], [1, 0, 1],
 ///     [0, 1, 1], [1, 1, 1],
 /// ]));
+///
+/// // mixing inclusive and exclusive axes
+/// use iter_num_tools::AxisRange;
+/// let it = grid_step([AxisRange::from(0..=2), AxisRange::from(0..2)]);
+/// assert!(it.eq(vec![
+///     [0, 0], [1, 0], [2, 0],
+///     [0, 1], [1, 1], [2, 1],
+/// ]));
 /// ```
+///
+/// # Panics
+///
+/// Panics if the total number of points in the grid overflows a `usize`
 pub fn grid_step<R, const N: usize>(range: R) -> GridStep<R::Item, N>
 where
     R: ToGridStep<N>,
@@ -58,12 +73,11 @@ impl<T: Step, const N: usize> ToGridStep<N> for Range<[T; N]> {
     type Item = T;
 
     fn into_grid_step(self) -> IntoGridStep<Self::Item, N> {
-        let mut len = 1;
         let steps = Array(self.start).zip_map(self.end, |start, end| {
             let steps = T::steps_between(&start, &end).expect("grid size cannot be infinite");
-            len *= steps;
             (start, steps)
         });
+        let len = grid_len(steps.iter().map(|&(_, steps)| steps));
         IntoGridStep {
             interpolate: GridStepInterpolation(steps),
             len,
@@ -75,13 +89,40 @@ impl<T: Step, const N: usize> ToGridStep<N> for RangeInclusive<[T; N]> {
     type Item = T;
 
     fn into_grid_step(self) -> IntoGridStep<Self::Item, N> {
-        let mut len = 1;
         let (start, end) = self.into_inner();
         let steps = Array(start).zip_map(end, |start, end| {
-            let steps = T::steps_between(&start, &end).expect("grid size cannot be infinite") + 1;
-            len *= steps;
+            let steps = inclusive_steps(&start, &end);
+            (start, steps)
+        });
+        let len = grid_len(steps.iter().map(|&(_, steps)| steps));
+        IntoGridStep {
+            interpolate: GridStepInterpolation(steps),
+            len,
+        }
+    }
+}
+
+impl<T: Step, const N: usize> ToGridStep<N> for [AxisRange<T>; N] {
+    type Item = T;
+
+    fn into_grid_step(self) -> IntoGridStep<Self::Item, N> {
+        let steps = self.map(|range| {
+            let (start, steps) = match range {
+                AxisRange::Exclusive(Range { start, end })
+                | AxisRange::Periodic(Range { start, end }) => {
+                    let steps =
+                        T::steps_between(&start, &end).expect("grid size cannot be infinite");
+                    (start, steps)
+                }
+                AxisRange::Inclusive(range) => {
+                    let (start, end) = range.into_inner();
+                    let steps = inclusive_steps(&start, &end);
+                    (start, steps)
+                }
+            };
             (start, steps)
         });
+        let len = grid_len(steps.iter().map(|&(_, steps)| steps));
         IntoGridStep {
             interpolate: GridStepInterpolation(steps),
             len,
@@ -89,6 +130,12 @@ impl<T: Step, const N: usize> ToGridStep<N> for RangeInclusive<[T; N]> {
     }
 }
 
+fn inclusive_steps<T: Step>(start: &T, end: &T) -> usize {
+    T::steps_between(start, end)
+        .and_then(|steps| steps.checked_add(1))
+        .expect("grid size cannot be infinite")
+}
+
 #[derive(Clone, Copy, Debug)]
 pub struct GridStepInterpolation<T, const N: usize>(pub [(T, usize); N]);
 
@@ -97,19 +144,122 @@ where
     T: Step,
 {
     type Item = [T; N];
-    fn interpolate(self, mut x: usize) -> [T; N] {
-        self.0.map(|space| {
+    fn interpolate(&self, mut x: usize) -> [T; N] {
+        self.0.each_ref().map(|space| {
             let z = x % space.1;
             x /= space.1;
-            T::forward(space.0, z).unwrap()
+            T::forward(space.0.clone(), z).unwrap()
         })
     }
 }
 
-/// [`Iterator`] returned by [`grid_space`]
+impl<T: Step, const N: usize> SpaceFmt for GridStepInterpolation<T, N> {
+    const NAME: &'static str = "GridStep";
+    fn fmt_params(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, ", shape: {:?}", self.0.each_ref().map(|axis| axis.1))
+    }
+}
+
+/// [`Iterator`] returned by [`grid_step`]
 pub type GridStep<T, const N: usize> = Space<GridStepInterpolation<T, N>>;
 
-/// [`IntoIterator`] returned by [`ToGridSpace::into_grid_space`]
+/// The index of a point in a grid, along with the indices of its neighbours along each axis,
+/// yielded by [`GridStep::with_neighbors`]
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Neighbors<const N: usize, const R: usize> {
+    /// The index of the point in the full grid
+    pub index: usize,
+    /// `neighbors[axis][d - 1]` holds the indices of the points `d` steps before and after this one
+    /// along the axis, or `None` if that is outside of the grid
+    pub neighbors: [[[Option<usize>; 2]; R]; N],
+}
+
+impl<const N: usize, const R: usize> Neighbors<N, R> {
+    /// The index of the point `offset` steps away along `axis`,
+    /// or `None` if it is outside of the grid or further than the stencil reaches
+    pub fn get(&self, axis: usize, offset: isize) -> Option<usize> {
+        if offset == 0 {
+            return Some(self.index);
+        }
+        let [before, after] = *self.neighbors.get(axis)?.get(offset.unsigned_abs() - 1)?;
+        match offset < 0 {
+            true => before,
+            false => after,
+        }
+    }
+}
+
+#[derive(Clone, Copy, Debug)]
+pub struct GridNeighborInterpolation<T, const N: usize, const R: usize>(
+    pub GridStepInterpolation<T, N>,
+);
+
+impl<T: Step, const N: usize, const R: usize> Interpolate for GridNeighborInterpolation<T, N, R> {
+    type Item = ([T; N], Neighbors<N, R>);
+    fn interpolate(&self, x: usize) -> Self::Item {
+        let mut rest = x;
+        let mut stride = 1;
+        let neighbors = self.0 .0.each_ref().map(|&(_, len)| {
+            let z = rest % len;
+            rest /= len;
+            let axis = core::array::from_fn(|d| {
+                let d = d + 1;
+                let before = (z >= d).then(|| x - d * stride);
+                let after = (z + d < len).then(|| x + d * stride);
+                [before, after]
+            });
+            stride *= len;
+            axis
+        });
+        let neighbors = Neighbors {
+            index: x,
+            neighbors,
+        };
+        (self.0.interpolate(x), neighbors)
+    }
+}
+
+impl<T: Step, const N: usize, const R: usize> SpaceFmt for GridNeighborInterpolation<T, N, R> {
+    const NAME: &'static str = "GridStepWithNeighbors";
+    fn fmt_params(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.0.fmt_params(f)?;
+        write!(f, ", radius: {R}")
+    }
+}
+
+/// [`Iterator`] returned by [`GridStep::with_neighbors`]
+pub type GridStepWithNeighbors<T, const N: usize, const R: usize> =
+    Space<GridNeighborInterpolation<T, N, R>>;
+
+impl<T, const N: usize> GridStep<T, N> {
+    /// Pairs each point of the grid with the indices of its neighbours along each axis,
+    /// up to `R` steps away, such as for driving a finite difference stencil.
+    ///
+    /// The indices are into the full grid, in the same order as it is iterated,
+    /// so they can index a buffer holding a value for every point.
+    ///
+    /// ```
+    /// use iter_num_tools::grid_step;
+    ///
+    /// let mut it = grid_step([0, 0]..[3, 3]).with_neighbors::<1>();
+    ///
+    /// // the corner has no neighbours before it
+    /// let (point, n) = it.next().unwrap();
+    /// assert_eq!(point, [0, 0]);
+    /// assert_eq!(n.neighbors, [[[None, Some(1)]], [[None, Some(3)]]]);
+    ///
+    /// // the center has all four
+    /// let (point, n) = it.nth(3).unwrap();
+    /// assert_eq!(point, [1, 1]);
+    /// assert_eq!(n.index, 4);
+    /// assert_eq!([n.get(0, -1), n.get(0, 1), n.get(1, -1), n.get(1, 1)], [Some(3), Some(5), Some(1), Some(7)]);
+    /// ```
+    pub fn with_neighbors<const R: usize>(self) -> GridStepWithNeighbors<T, N, R> {
+        self.map_interpolate(GridNeighborInterpolation)
+    }
+}
+
+/// [`IntoIterator`] returned by [`ToGridStep::into_grid_step`]
 pub type IntoGridStep<T, const N: usize> = IntoSpace<GridStepInterpolation<T, N>>;
 
 #[cfg(test)]
@@ -152,6 +302,41 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_grid_step_axis_range() {
+        check_double_ended_iter(
+            grid_step([AxisRange::from(0..=1), AxisRange::from(2..4)]),
+            [[0, 2], [1, 2], [0, 3], [1, 3]],
+        );
+    }
+
+    #[test]
+    fn test_grid_step_with_neighbors() {
+        let it = grid_step([0, 0]..[4, 2]).with_neighbors::<2>();
+        let points: Vec<_> = it.clone().map(|(point, _)| point).collect();
+        for (x, (point, n)) in it.enumerate() {
+            assert_eq!(n.index, x);
+            for axis in 0..2 {
+                for offset in -3..=3_isize {
+                    let mut expected = point;
+                    expected[axis] += offset as i32;
+                    let expected = match offset.abs() <= 2 {
+                        true => points.iter().position(|&p| p == expected),
+                        false => None,
+                    };
+                    assert_eq!(n.get(axis, offset), expected, "{point:?} {axis} {offset}");
+                }
+            }
+        }
+        assert_eq!(
+            grid_step([0, 0]..[4, 2])
+                .with_neighbors::<0>()
+                .nth(5)
+                .map(|(_, n)| n.get(0, 1)),
+            Some(None)
+        );
+    }
+
     #[test]
     fn test_grid_space_exclusive_len() {
         let mut it = grid_step([0, 0]..[2, 4]);
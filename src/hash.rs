@@ -0,0 +1,139 @@
+//! Hashing and equality for the parameters of a space, so sampling plans can be used as keys
+
+use core::hash::{Hash, Hasher};
+
+use crate::{gridspace::GridSpaceInterpolation, linspace::LinearInterpolation};
+
+/// Values which can be compared and hashed through a canonical key,
+/// such as floats through their bits
+///
+/// This is what lets [`IntoLinSpace`](crate::IntoLinSpace) and [`IntoGridSpace`](crate::IntoGridSpace)
+/// implement [`Hash`] and [`Eq`], for caching the results of a sweep.
+/// All NaNs have the same key, and so do `0.0` and `-0.0`,
+/// so spaces which produce the same values are equal.
+///
+/// ```
+/// use std::collections::HashMap;
+/// use iter_num_tools::{lin_space, IntoLinSpace, ToLinSpace};
+///
+/// let mut cache: HashMap<IntoLinSpace<f64>, f64> = HashMap::new();
+/// let plan = (0.0..=1.0).into_lin_space(5);
+/// cache.insert(plan, plan.into_iter().sum());
+///
+/// assert_eq!(cache.get(&(0.0..=1.0).into_lin_space(5)), Some(&2.5));
+/// assert_eq!(cache.get(&(0.0..1.0).into_lin_space(5)), None);
+/// ```
+pub trait HashKey {
+    /// The canonical key
+    type Key: Hash + Eq;
+    /// Gets the key of this value
+    fn hash_key(&self) -> Self::Key;
+}
+
+macro_rules! float_hash_key {
+    ($($t:ty => $bits:ty),*) => {$(
+        impl HashKey for $t {
+            type Key = $bits;
+            fn hash_key(&self) -> $bits {
+                match *self {
+                    x if x.is_nan() => <$t>::NAN.to_bits(),
+                    // -0.0 == 0.0, so they need the same key
+                    x if x == 0.0 => 0,
+                    x => x.to_bits(),
+                }
+            }
+        }
+    )*};
+}
+
+float_hash_key!(f32 => u32, f64 => u64);
+
+macro_rules! int_hash_key {
+    ($($t:ty),*) => {$(
+        impl HashKey for $t {
+            type Key = $t;
+            fn hash_key(&self) -> $t {
+                *self
+            }
+        }
+    )*};
+}
+
+int_hash_key!(u8, u16, u32, u64, u128, usize, i8, i16, i32, i64, i128, isize);
+
+impl<T: HashKey, D: HashKey> PartialEq for LinearInterpolation<T, D> {
+    fn eq(&self, other: &Self) -> bool {
+        self.start.hash_key() == other.start.hash_key()
+            && self.step.hash_key() == other.step.hash_key()
+    }
+}
+
+impl<T: HashKey, D: HashKey> Eq for LinearInterpolation<T, D> {}
+
+impl<T: HashKey, D: HashKey> Hash for LinearInterpolation<T, D> {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.start.hash_key().hash(state);
+        self.step.hash_key().hash(state);
+    }
+}
+
+impl<T: HashKey, const N: usize> PartialEq for GridSpaceInterpolation<T, N> {
+    fn eq(&self, other: &Self) -> bool {
+        self.axes == other.axes && self.periodic == other.periodic
+    }
+}
+
+impl<T: HashKey, const N: usize> Eq for GridSpaceInterpolation<T, N> {}
+
+impl<T: HashKey, const N: usize> Hash for GridSpaceInterpolation<T, N> {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.axes.hash(state);
+        self.periodic.hash(state);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{grid_space, lin_space, AxisRange, ToGridSpace, ToLinSpace};
+    use std::collections::HashSet;
+
+    #[test]
+    fn test_lin_space_keys() {
+        let plans = [
+            (0.0..=1.0).into_lin_space(5),
+            (0.0..=1.0).into_lin_space(5),
+            (-0.0..=1.0).into_lin_space(5),
+            (0.0..=1.0).into_lin_space(6),
+            (0.0..1.25).into_lin_space(5),
+            (f64::NAN..1.0).into_lin_space(5),
+            (-f64::NAN..1.0).into_lin_space(5),
+        ];
+        let unique: HashSet<_> = plans.into_iter().collect();
+        assert_eq!(unique.len(), 3);
+
+        let mut it = lin_space(0..10, 10);
+        assert_eq!(it, lin_space(0..10, 10));
+        it.next();
+        assert_ne!(it, lin_space(0..10, 10));
+    }
+
+    #[test]
+    fn test_grid_space_keys() {
+        let a = [0.0, 0.0]..[1.0, 1.0];
+        assert_eq!(
+            a.clone().into_grid_space(4),
+            a.clone().into_grid_space([4, 4])
+        );
+        assert_ne!(a.clone().into_grid_space(4), a.into_grid_space([4, 3]));
+
+        let axes = |first| [first, AxisRange::from(0.0..1.0)];
+        assert_ne!(
+            axes(AxisRange::Periodic(0.0..1.0)).into_grid_space(4),
+            axes(AxisRange::from(0.0..1.0)).into_grid_space(4),
+        );
+        assert_eq!(
+            grid_space(axes(AxisRange::from(0.0..1.0)), 4),
+            grid_space([0.0, 0.0]..[1.0, 1.0], 4)
+        );
+    }
+}
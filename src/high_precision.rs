@@ -0,0 +1,269 @@
+//! Spaces that compute their values in a wider float type, only rounding down to the item type at the end.
+//!
+//! Narrow floats lose precision quickly when interpolating. The index is rounded when
+//! converted to the float type, and the step gets multiplied up along with its rounding error.
+//! These spaces do all of that in the [`Widen::Wide`] type instead.
+//!
+//! `f32` is widened to `f64`, so a space can have up to 2<sup>53</sup> distinct indices
+//! rather than 2<sup>24</sup>.
+//!
+//! ```
+//! use iter_num_tools::{high_precision, lin_space};
+//!
+//! // the closest f32 to 16777001 / 20000000
+//! let exact = (16_777_001.0 / 20_000_000.0_f64) as f32;
+//!
+//! let mut it = lin_space(0.0_f32..=1.0, 20_000_001);
+//! assert_ne!(it.nth(16_777_001), Some(exact));
+//!
+//! let mut it = high_precision::lin_space(0.0_f32..=1.0, 20_000_001);
+//! assert_eq!(it.nth(16_777_001), Some(exact));
+//! ```
+//!
+//! With the `half` feature, this is also implemented for `half::f16` and `half::bf16`,
+//! which are widened to `f32`.
+//!
+//! ```
+//! # #[cfg(feature = "half")] {
+//! use half::f16;
+//! use iter_num_tools::high_precision;
+//!
+//! let it = high_precision::lin_space(f16::ZERO..=f16::ONE, 5);
+//! assert!(it.map(f16::to_f32).eq([0.0, 0.25, 0.5, 0.75, 1.0]));
+//! # }
+//! ```
+
+use core::fmt;
+use core::marker::PhantomData;
+use core::ops::{Range, RangeInclusive};
+
+use crate::{
+    arange::ToArange,
+    axis::AxisRange,
+    linspace::{LinearInterpolation, ToLinSpace},
+    logspace::{LogarithmicInterpolation, ToLogSpace},
+    space::{Interpolate, IntoSpace, Space, SpaceFmt},
+};
+
+/// A type which can be converted to and from a wider type to compute with
+pub trait Widen: Sized {
+    /// The type to compute with
+    type Wide;
+    /// Converts into the wider type, exactly
+    fn widen(self) -> Self::Wide;
+    /// Rounds the wider type back down to this type
+    fn narrow(wide: Self::Wide) -> Self;
+}
+
+/// Interpolation in the [`Widen::Wide`] type, narrowing each value to `T`
+pub struct HighPrecision<I, T> {
+    /// The interpolation in the wider type
+    pub wide: I,
+    narrow: PhantomData<fn() -> T>,
+}
+
+impl<I: Clone, T> Clone for HighPrecision<I, T> {
+    fn clone(&self) -> Self {
+        HighPrecision::new(self.wide.clone())
+    }
+}
+
+impl<I: Copy, T> Copy for HighPrecision<I, T> {}
+
+impl<I: fmt::Debug, T> fmt::Debug for HighPrecision<I, T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_tuple("HighPrecision").field(&self.wide).finish()
+    }
+}
+
+impl<I, T> HighPrecision<I, T> {
+    fn new(wide: I) -> Self {
+        HighPrecision {
+            wide,
+            narrow: PhantomData,
+        }
+    }
+}
+
+impl<I, T> Interpolate for HighPrecision<I, T>
+where
+    T: Widen,
+    I: Interpolate<Item = T::Wide>,
+{
+    type Item = T;
+    fn interpolate(&self, x: usize) -> T {
+        T::narrow(self.wide.interpolate(x))
+    }
+
+    const EXACT_FOLD: bool = I::EXACT_FOLD;
+
+    fn fold_range<B, F>(&self, range: Range<usize>, init: B, mut f: F) -> B
+    where
+        F: FnMut(B, T) -> B,
+    {
+        self.wide
+            .fold_range(range, init, |acc, x| f(acc, T::narrow(x)))
+    }
+}
+
+impl<I, T> SpaceFmt for HighPrecision<I, T>
+where
+    T: Widen,
+    I: SpaceFmt<Item = T::Wide>,
+{
+    const NAME: &'static str = "HighPrecision";
+    fn fmt_params(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, ", inner: {}", I::NAME)?;
+        self.wide.fmt_params(f)
+    }
+}
+
+fn narrow_space<I, T>(space: IntoSpace<I>) -> Space<HighPrecision<I, T>> {
+    Space::new(space.len, HighPrecision::new(space.interpolate))
+}
+
+fn widen_range<T: Widen>(range: AxisRange<T>) -> AxisRange<T::Wide> {
+    match range {
+        AxisRange::Exclusive(range) => AxisRange::Exclusive(range.start.widen()..range.end.widen()),
+        AxisRange::Periodic(range) => AxisRange::Periodic(range.start.widen()..range.end.widen()),
+        AxisRange::Inclusive(range) => {
+            let (start, end) = range.into_inner();
+            AxisRange::Inclusive(start.widen()..=end.widen())
+        }
+    }
+}
+
+/// [`Iterator`] returned by [`lin_space`] and [`arange`]
+pub type HighPrecisionLinSpace<T> =
+    Space<HighPrecision<LinearInterpolation<<T as Widen>::Wide>, T>>;
+
+/// [`Iterator`] returned by [`log_space`]
+pub type HighPrecisionLogSpace<T> =
+    Space<HighPrecision<LogarithmicInterpolation<<T as Widen>::Wide>, T>>;
+
+/// Creates a linear space over range with a fixed number of steps, as in [`lin_space`](crate::lin_space)
+pub fn lin_space<T: Widen>(range: impl Into<AxisRange<T>>, steps: usize) -> HighPrecisionLinSpace<T>
+where
+    AxisRange<T::Wide>: ToLinSpace<Item = T::Wide>,
+{
+    narrow_space(widen_range(range.into()).into_lin_space(steps))
+}
+
+/// Creates a logarithmic space over range with a fixed number of steps, as in [`log_space`](crate::log_space)
+pub fn log_space<T: Widen>(range: impl Into<AxisRange<T>>, steps: usize) -> HighPrecisionLogSpace<T>
+where
+    Range<T::Wide>: ToLogSpace<Item = T::Wide>,
+    RangeInclusive<T::Wide>: ToLogSpace<Item = T::Wide>,
+{
+    narrow_space(match widen_range(range.into()) {
+        AxisRange::Exclusive(range) | AxisRange::Periodic(range) => range.into_log_space(steps),
+        AxisRange::Inclusive(range) => range.into_log_space(steps),
+    })
+}
+
+/// Creates a space over range, stepping by `step` each time, as in [`arange`](crate::arange)
+pub fn arange<T: Widen>(range: Range<T>, step: T) -> HighPrecisionLinSpace<T>
+where
+    Range<T::Wide>: ToArange<T::Wide, Item = T::Wide>,
+{
+    let range = range.start.widen()..range.end.widen();
+    narrow_space(range.into_arange(step.widen()))
+}
+
+impl Widen for f32 {
+    type Wide = f64;
+    fn widen(self) -> f64 {
+        self as f64
+    }
+    fn narrow(wide: f64) -> Self {
+        wide as f32
+    }
+}
+
+#[cfg(feature = "half")]
+mod half_impl {
+    use super::Widen;
+    use half::{bf16, f16};
+
+    impl Widen for f16 {
+        type Wide = f32;
+        fn widen(self) -> f32 {
+            self.to_f32()
+        }
+        fn narrow(wide: f32) -> Self {
+            f16::from_f32(wide)
+        }
+    }
+
+    impl Widen for bf16 {
+        type Wide = f32;
+        fn widen(self) -> f32 {
+            self.to_f32()
+        }
+        fn narrow(wide: f32) -> Self {
+            bf16::from_f32(wide)
+        }
+    }
+
+    #[cfg(test)]
+    mod tests {
+        use super::*;
+        use crate::{check_double_ended_iter, high_precision};
+
+        #[test]
+        fn test_half_lin_space() {
+            let f = f16::from_f32;
+            let it = high_precision::lin_space(f(0.0)..=f(1.0), 5);
+            check_double_ended_iter(it, [0.0, 0.25, 0.5, 0.75, 1.0].map(f));
+
+            // 4097 can't be represented in an f16, so the index would be rounded
+            let it = high_precision::lin_space(f(0.0)..=f(1.0), 4097);
+            assert_eq!(it.clone().nth(4095), Some(f(4095.0 / 4096.0)));
+            assert_eq!(it.last(), Some(f(1.0)));
+        }
+
+        #[test]
+        fn test_half_log_space() {
+            let f = bf16::from_f32;
+            let it = high_precision::log_space(f(1.0)..=f(100.0), 3);
+            check_double_ended_iter(it, [1.0, 10.0, 100.0].map(f));
+
+            let it = high_precision::arange(f(0.0)..f(1.0), f(0.25));
+            check_double_ended_iter(it, [0.0, 0.25, 0.5, 0.75].map(f));
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::check_double_ended_iter;
+
+    #[test]
+    fn test_f32_lin_space() {
+        let it = lin_space(0.0_f32..1.0, 4);
+        check_double_ended_iter(it, [0.0, 0.25, 0.5, 0.75]);
+
+        // past 2^24, f32 indices round together
+        let steps = 1 << 25;
+        let narrow = crate::lin_space(0.0_f32..steps as f32, steps);
+        assert_eq!(
+            narrow.clone().nth(1 << 24),
+            narrow.clone().nth((1 << 24) + 1)
+        );
+        let wide = lin_space(0.0_f32..steps as f32, steps);
+        assert_eq!(wide.clone().nth((1 << 24) + 2), Some(16_777_218.0));
+        assert_eq!(wide.last(), Some(33_554_431.0));
+    }
+
+    #[test]
+    fn test_f32_arange_fmt() {
+        let it = arange(0.0_f32..1.0, 0.5);
+        assert_eq!(
+            format!("{it:?}"),
+            "HighPrecision { 0.0..=0.5, len: 2, inner: LinSpace, step: 0.5 }"
+        );
+        let it = log_space(1.0_f32..=100.0, 3);
+        assert!(it.eq([1.0, 10.0, 100.0]));
+    }
+}
@@ -0,0 +1,197 @@
+use array_bin_ops::Array;
+use core::fmt;
+
+use crate::{
+    error::Error,
+    gridspace::{GridSpace, GridSpaceInterpolation},
+    linspace::{LinearInterpolation, ToLinSpace},
+    space::{Interpolate, Space, SpaceFmt},
+};
+
+/// Creates a grid with a linear space along each axis, which may have more points than fit in a `usize`
+///
+/// High dimensional grids quickly grow past what a `usize` can count, especially on 32-bit targets,
+/// so can't be made with [`grid_space`](crate::grid_space).
+/// This counts the points with a `u128` instead, so the grid can still be sampled at any point,
+/// and [sliced](HugeGridSpace::slice) into spaces small enough to iterate over.
+///
+/// ```
+/// use iter_num_tools::huge_grid_space;
+///
+/// let grid = huge_grid_space(core::array::from_fn(|_| 0.0..=1.0), [1 << 20; 6]);
+/// assert_eq!(grid.len(), 1 << 120);
+/// assert_eq!(grid.get((1 << 120) - 1), Some([1.0; 6]));
+///
+/// let it = grid.slice(1 << 100, 2);
+/// let z = 1.0 / ((1 << 20) - 1) as f64;
+/// assert!(it.eq([[0.0, 0.0, 0.0, 0.0, 0.0, z], [z, 0.0, 0.0, 0.0, 0.0, z]]));
+/// ```
+///
+/// # Panics
+///
+/// Panics if the total number of points in the grid overflows a `u128`
+pub fn huge_grid_space<R, const N: usize>(
+    ranges: [R; N],
+    steps: [usize; N],
+) -> HugeGridSpace<R::Item, N>
+where
+    R: ToLinSpace,
+{
+    let axes = Array(ranges).zip_map(steps, R::into_lin_space);
+    let len = axes
+        .iter()
+        .try_fold(1_u128, |len, axis| len.checked_mul(axis.len as u128))
+        .expect("grid size overflows u128");
+    HugeGridSpace {
+        grid: GridSpaceInterpolation::new(axes),
+        len,
+    }
+}
+
+/// A grid which may be too large to iterate over, returned by [`huge_grid_space`]
+#[derive(Clone, Copy, Debug)]
+pub struct HugeGridSpace<T, const N: usize> {
+    grid: GridSpaceInterpolation<T, N>,
+    len: u128,
+}
+
+impl<T, const N: usize> HugeGridSpace<T, N>
+where
+    LinearInterpolation<T>: Interpolate<Item = T>,
+{
+    /// The number of points in the grid
+    pub fn len(&self) -> u128 {
+        self.len
+    }
+
+    /// Whether the grid has no points
+    pub fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    /// The number of points along each axis
+    pub fn shape(&self) -> [usize; N] {
+        self.grid.axes.each_ref().map(|axis| axis.len)
+    }
+
+    /// The point at `index`, in the same order as [`grid_space`](crate::grid_space),
+    /// or `None` if it is past the end of the grid
+    pub fn get(&self, index: u128) -> Option<[T; N]> {
+        (index < self.len).then(|| interpolate_huge(&self.grid, index))
+    }
+
+    /// A space over `len` points of the grid, starting at `offset`.
+    ///
+    /// Like [`Space::slice`], it stops early if there are too few points in the grid.
+    pub fn slice(&self, offset: u128, len: usize) -> HugeGridSlice<T, N>
+    where
+        T: Clone,
+    {
+        let offset = offset.min(self.len);
+        let len = (self.len - offset).min(len as u128) as usize;
+        Space::new(
+            len,
+            HugeGridSliceInterpolation {
+                grid: self.grid.clone(),
+                offset,
+            },
+        )
+    }
+
+    /// Converts into a [`GridSpace`] over every point,
+    /// or returns [`Error::Overflow`] if there are too many to fit in a `usize`
+    pub fn try_into_space(self) -> Result<GridSpace<T, N>, Error> {
+        let len = usize::try_from(self.len).map_err(|_| Error::Overflow)?;
+        Ok(Space::new(len, self.grid))
+    }
+}
+
+fn interpolate_huge<T, const N: usize>(grid: &GridSpaceInterpolation<T, N>, mut x: u128) -> [T; N]
+where
+    LinearInterpolation<T>: Interpolate<Item = T>,
+{
+    grid.axes.each_ref().map(|space| {
+        let len = space.len as u128;
+        let z = x % len;
+        x /= len;
+        space.interpolate.interpolate(z as usize)
+    })
+}
+
+#[derive(Clone, Copy, Debug)]
+pub struct HugeGridSliceInterpolation<T, const N: usize> {
+    pub grid: GridSpaceInterpolation<T, N>,
+    pub offset: u128,
+}
+
+impl<T, const N: usize> Interpolate for HugeGridSliceInterpolation<T, N>
+where
+    LinearInterpolation<T>: Interpolate<Item = T>,
+{
+    type Item = [T; N];
+    fn interpolate(&self, x: usize) -> [T; N] {
+        interpolate_huge(&self.grid, self.offset + x as u128)
+    }
+}
+
+impl<T, const N: usize> SpaceFmt for HugeGridSliceInterpolation<T, N>
+where
+    LinearInterpolation<T>: Interpolate<Item = T>,
+{
+    const NAME: &'static str = "HugeGridSlice";
+    fn fmt_params(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, ", offset: {}", self.offset)?;
+        self.grid.fmt_params(f)
+    }
+}
+
+/// [`Iterator`] returned by [`HugeGridSpace::slice`]
+pub type HugeGridSlice<T, const N: usize> = Space<HugeGridSliceInterpolation<T, N>>;
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{check_double_ended_iter, grid_space};
+
+    #[test]
+    fn test_huge_grid_space() {
+        // small grids match grid_space
+        let grid = huge_grid_space([0..4, 0..6], [4, 3]);
+        assert_eq!((grid.len(), grid.shape()), (12, [4, 3]));
+        let it = grid.try_into_space().unwrap();
+        assert!(it.eq(grid_space([0, 0]..[4, 6], [4, 3])));
+        check_double_ended_iter(grid.slice(6, 3), [[2, 2], [3, 2], [0, 4]]);
+
+        assert_eq!(grid.get(11), Some([3, 4]));
+        assert_eq!(grid.get(12), None);
+        assert_eq!(grid.slice(10, 5).len(), 2);
+        assert_eq!(grid.slice(20, 5).len(), 0);
+    }
+
+    #[test]
+    fn test_huge_grid_space_overflow() {
+        let steps = [u32::MAX as usize; 4];
+        let grid = huge_grid_space(core::array::from_fn(|_| 0.0..=1.0), steps);
+        assert!(!grid.is_empty());
+        assert_eq!(grid.try_into_space().unwrap_err(), Error::Overflow);
+        assert_eq!(grid.get(grid.len() - 1), Some([1.0; 4]));
+        assert_eq!(grid.slice(grid.len() - 1, 10).len(), 1);
+
+        let it = grid.slice(1, 1);
+        assert_eq!(
+            format!("{it:?}"),
+            format!(
+                "HugeGridSlice {{ {:?}..={0:?}, len: 1, offset: 1, shape: {:?} }}",
+                [1.0 / (u32::MAX - 1) as f64, 0.0, 0.0, 0.0],
+                steps
+            )
+        );
+        assert!(huge_grid_space([0..1, 0..1], [0, 5]).is_empty());
+    }
+
+    #[test]
+    #[should_panic(expected = "grid size overflows u128")]
+    fn test_huge_grid_space_too_large() {
+        huge_grid_space(core::array::from_fn(|_| 0.0..=1.0), [u32::MAX as usize; 5]);
+    }
+}
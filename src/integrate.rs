@@ -0,0 +1,135 @@
+//! Numerical integration over evenly spaced samples.
+//!
+//! Since every value in a [`LinSpace`] or [`Arange`](crate::Arange) is the same step apart,
+//! these rules can weight the samples directly without storing them or looking at
+//! the distance between neighbours.
+
+use num_traits::{real::Real, FromPrimitive};
+
+use crate::LinSpace;
+
+/// Integrates `f` over the remaining values of the space using the trapezoidal rule
+///
+/// ```
+/// use iter_num_tools::{integrate, lin_space};
+///
+/// let area = integrate::trapezoid(lin_space(0.0..=2.0, 5), |x| 3.0 * x + 1.0);
+/// assert_eq!(area, 8.0);
+/// ```
+///
+/// Fewer than two values span no area, so integrate to zero.
+pub fn trapezoid<T, F>(space: LinSpace<T>, mut f: F) -> T
+where
+    T: Real + FromPrimitive,
+    F: FnMut(T) -> T,
+{
+    if space.len() < 2 {
+        return T::zero();
+    }
+    let step = space.interpolate.step;
+    let last = space.len() - 1;
+    let two = T::from_u8(2).unwrap();
+
+    let mut sum = T::zero();
+    for (i, x) in space.enumerate() {
+        let y = f(x);
+        sum = sum + if i == 0 || i == last { y / two } else { y };
+    }
+    sum * step
+}
+
+/// Integrates `f` over the remaining values of the space using Simpson's rule
+///
+/// With an odd number of intervals, the last three intervals use Simpson's 3/8 rule instead.
+/// Both rules are exact for cubics.
+///
+/// ```
+/// use iter_num_tools::{integrate, lin_space};
+///
+/// let area = integrate::simpson(lin_space(0.0_f64..=3.0, 7), |x| x * x * x);
+/// assert!((area - 81.0 / 4.0).abs() < 1e-12);
+///
+/// // 3 intervals
+/// let area = integrate::simpson(lin_space(0.0_f64..=3.0, 4), |x| x * x * x);
+/// assert!((area - 81.0 / 4.0).abs() < 1e-12);
+/// ```
+///
+/// A single interval falls back to the trapezoidal rule, and fewer than two values integrate to zero.
+pub fn simpson<T, F>(space: LinSpace<T>, mut f: F) -> T
+where
+    T: Real + FromPrimitive,
+    F: FnMut(T) -> T,
+{
+    let intervals = space.len().saturating_sub(1);
+    if intervals < 2 {
+        return trapezoid(space, f);
+    }
+    let step = space.interpolate.step;
+
+    // the intervals covered by the regular 1/3 rule. Any leftover 3 intervals use the 3/8 rule
+    let simpson_end = if intervals % 2 == 0 {
+        intervals
+    } else {
+        intervals - 3
+    };
+
+    let [one, two, three, four] = [1, 2, 3, 4].map(|x| T::from_u8(x).unwrap());
+    let mut thirds = T::zero();
+    let mut three_eighths = T::zero();
+    for (i, x) in space.enumerate() {
+        let y = f(x);
+        if i <= simpson_end && simpson_end > 0 {
+            let weight = if i == 0 || i == simpson_end {
+                one
+            } else if i % 2 == 1 {
+                four
+            } else {
+                two
+            };
+            thirds = thirds + weight * y;
+        }
+        if i >= simpson_end && simpson_end < intervals {
+            let weight = if i == simpson_end || i == intervals {
+                one
+            } else {
+                three
+            };
+            three_eighths = three_eighths + weight * y;
+        }
+    }
+
+    let eight = T::from_u8(8).unwrap();
+    thirds * step / three + three_eighths * three * step / eight
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{arange, lin_space};
+
+    #[test]
+    fn test_trapezoid() {
+        assert_eq!(trapezoid(lin_space(0.0..=1.0, 3), |x| x), 0.5);
+        assert_eq!(trapezoid(arange(0.0..2.0, 0.5), |x| 2.0 * x), 2.25);
+        assert_eq!(trapezoid(lin_space(0.0..=1.0, 1), |x| x), 0.0);
+        assert_eq!(trapezoid(lin_space(0.0..=1.0, 0), |x| x), 0.0);
+
+        // only the remaining values are integrated
+        let mut it = lin_space(0.0..=2.0, 3);
+        it.next();
+        assert_eq!(trapezoid(it, |x| x), 1.5);
+    }
+
+    #[test]
+    fn test_simpson() {
+        let cubic = |x: f64| x * x * x - 2.0 * x + 1.0;
+        let exact = 4.0 - 4.0 + 2.0;
+        for steps in 3..10 {
+            let area = simpson(lin_space(0.0..=2.0, steps), cubic);
+            assert!((area - exact).abs() < 1e-12, "{steps} steps: {area}");
+        }
+
+        assert_eq!(simpson(lin_space(0.0..=1.0, 2), |x| x), 0.5);
+        assert_eq!(simpson(lin_space(0.0..=1.0, 0), |x| x), 0.0);
+    }
+}
@@ -0,0 +1,142 @@
+use core::cmp::Ordering;
+use num_traits::{real::Real, FromPrimitive};
+
+use crate::{linspace::LinearInterpolation, space::Interpolate, LinSpace};
+
+/// Piecewise linear interpolation between values sampled over a [`LinSpace`]
+///
+/// Because the samples are evenly spaced, finding the interval to interpolate in is a single division.
+///
+/// ```
+/// use iter_num_tools::{lin_space, Interp1d};
+///
+/// let space = lin_space(0.0..=3.0, 4);
+/// let values: Vec<f64> = space.clone().map(|x| x * x).collect();
+///
+/// let interp = Interp1d::new(space, &values);
+/// assert_eq!(interp.eval(1.5), Some(2.5));
+/// assert_eq!(interp.eval(3.0), Some(9.0));
+/// assert_eq!(interp.eval(3.5), None);
+/// ```
+#[derive(Clone, Copy, Debug)]
+pub struct Interp1d<'a, T> {
+    start: T,
+    step: T,
+    values: &'a [T],
+}
+
+impl<'a, T: Real + FromPrimitive> Interp1d<'a, T> {
+    /// Creates the interpolation from the remaining points of a space and the values sampled at those points
+    ///
+    /// # Panics
+    ///
+    /// Panics if the space and values are not the same length
+    pub fn new(space: LinSpace<T>, values: &'a [T]) -> Self {
+        assert_eq!(
+            space.len(),
+            values.len(),
+            "space and values must be the same length"
+        );
+        let LinearInterpolation { step, .. } = space.interpolate;
+        Interp1d {
+            start: space.interpolate.interpolate(space.range.start),
+            step,
+            values,
+        }
+    }
+
+    /// The sampled values
+    pub fn values(&self) -> &'a [T] {
+        self.values
+    }
+
+    /// Linearly interpolates between the two samples either side of `x`.
+    ///
+    /// Returns `None` if `x` is outside the sampled points.
+    /// A sample point gives exactly its sampled value, even if working out its index rounds slightly off,
+    /// so the first and last points are always inside.
+    pub fn eval(&self, x: T) -> Option<T> {
+        let last = self.values.len().checked_sub(1)?;
+        if last == 0 {
+            return (x == self.start).then(|| self.values[0]);
+        }
+
+        let mut t = (x - self.start) / self.step;
+        // `x` and `start` were each rounded once, which moves `t` by up to this much
+        let two = T::one() + T::one();
+        let rounding = T::epsilon() * (x.abs() + self.start.abs()) / self.step.abs() * two;
+        if (t - t.round()).abs() <= rounding {
+            t = t.round();
+        }
+        let i = t.floor().to_usize()?;
+        let frac = t - t.floor();
+        match i.cmp(&last) {
+            Ordering::Less => {
+                let (a, b) = (self.values[i], self.values[i + 1]);
+                Some(a + (b - a) * frac)
+            }
+            Ordering::Equal if frac.is_zero() => Some(self.values[last]),
+            _ => None,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::lin_space;
+
+    #[test]
+    fn test_interp1d() {
+        let values = [0.0, 2.0, 1.0];
+        let interp = Interp1d::new(lin_space(1.0..=2.0, 3), &values);
+        assert_eq!(interp.eval(1.0), Some(0.0));
+        assert_eq!(interp.eval(1.25), Some(1.0));
+        assert_eq!(interp.eval(1.75), Some(1.5));
+        assert_eq!(interp.eval(2.0), Some(1.0));
+        assert_eq!(interp.eval(0.5), None);
+        assert_eq!(interp.eval(f64::NAN), None);
+
+        // descending spaces work too
+        let interp = Interp1d::new(lin_space(2.0..=1.0, 3), &values);
+        assert_eq!(interp.eval(1.25), Some(1.5));
+
+        let mut space = lin_space(0.0..=2.0, 3);
+        space.next();
+        let interp = Interp1d::new(space, &values[1..]);
+        assert_eq!(interp.eval(1.5), Some(1.5));
+        assert_eq!(interp.eval(0.5), None);
+    }
+
+    #[test]
+    fn test_interp1d_samples() {
+        let spaces = [
+            lin_space(0.1..=0.7, 112),
+            lin_space(0.3..=1.9, 128),
+            lin_space(-3.7..=2.9, 1001),
+            lin_space(1e6..=1e6 + 1.0, 77),
+            lin_space(5.3..=-0.1, 300),
+            lin_space(0.0..1.3, 99),
+        ];
+        for space in spaces {
+            let values: Vec<f64> = space.clone().map(|x| x.sin()).collect();
+            let interp = Interp1d::new(space.clone(), &values);
+            for (x, y) in space.zip(&values) {
+                assert_eq!(interp.eval(x), Some(*y), "at {x}");
+            }
+        }
+    }
+
+    #[test]
+    fn test_interp1d_single() {
+        let interp = Interp1d::new(lin_space(1.0..=2.0, 1), &[5.0]);
+        assert_eq!(interp.eval(1.0), Some(5.0));
+        assert_eq!(interp.eval(1.5), None);
+    }
+
+    #[test]
+    #[should_panic(expected = "space and values must be the same length")]
+    fn test_interp1d_mismatched() {
+        Interp1d::new(lin_space(1.0..=2.0, 3), &[0.0, 1.0]);
+    }
+}
@@ -0,0 +1,220 @@
+//! Spaces over JavaScript timestamps, which are `f64` milliseconds since the Unix epoch.
+//!
+//! These can be passed straight across a `wasm-bindgen` boundary, from `Date.getTime()`
+//! or `performance.now()`, and back into `new Date(ms)`. Everything here is `no_std`,
+//! so this works on `wasm32-unknown-unknown` without pulling in any std-only code.
+//!
+//! ```
+//! use iter_num_tools::js;
+//!
+//! // every 6 hours through 2024-01-01
+//! let start = 1_704_067_200_000.0;
+//! let it = js::timestamps(start..start + js::DAY, 6.0 * js::HOUR);
+//! assert!(it.eq([0.0, 6.0, 12.0, 18.0].map(|h| start + h * js::HOUR)));
+//! ```
+
+use core::fmt;
+use core::ops::Range;
+use num_traits::real::Real;
+
+use crate::{
+    arange::ToArange,
+    axis::AxisRange,
+    error::Error,
+    linspace::{LinearInterpolation, ToLinSpace},
+    space::{Interpolate, IntoSpace, Space, SpaceFmt},
+};
+
+/// One millisecond
+pub const MILLISECOND: f64 = 1.0;
+/// One second, in milliseconds
+pub const SECOND: f64 = 1000.0 * MILLISECOND;
+/// One minute, in milliseconds
+pub const MINUTE: f64 = 60.0 * SECOND;
+/// One hour, in milliseconds
+pub const HOUR: f64 = 60.0 * MINUTE;
+/// One day, in milliseconds
+pub const DAY: f64 = 24.0 * HOUR;
+
+/// The largest timestamp a JavaScript `Date` can hold, 100 million days either side of the epoch
+pub const MAX_TIMESTAMP: f64 = 8.64e15;
+
+/// Creates a space over the timestamps in a range, stepping by `step` milliseconds each time,
+/// as in [`arange`](crate::arange)
+///
+/// # Panics
+///
+/// Panics if the step is zero or points away from the end of the range.
+/// Use [`try_timestamps`] to check this instead.
+pub fn timestamps(range: Range<f64>, step: f64) -> Timestamps {
+    match try_timestamps(range, step) {
+        Ok(it) => it,
+        Err(err) => panic!("invalid timestamps: {err}"),
+    }
+}
+
+/// Creates a space over the timestamps in a range, stepping by `step` milliseconds each time,
+/// returning an error if the range is not a valid `Date` range or the step is invalid
+///
+/// ```
+/// use iter_num_tools::{js, Error};
+///
+/// assert_eq!(js::try_timestamps(0.0..1e16, js::DAY).unwrap_err(), Error::OutOfRange);
+/// assert_eq!(js::try_timestamps(0.0..f64::NAN, js::DAY).unwrap_err(), Error::NonFinite);
+/// assert_eq!(js::try_timestamps(0.0..js::DAY, -js::HOUR).unwrap_err(), Error::SignMismatch);
+/// ```
+pub fn try_timestamps(range: Range<f64>, step: f64) -> Result<Timestamps, Error> {
+    check_range(range.start, range.end)?;
+    range.try_into_arange(step).map(into_timestamps)
+}
+
+/// Creates a space over the timestamps in a range with a fixed number of steps,
+/// rounded to whole milliseconds as a `Date` would be, as in [`lin_space`](crate::lin_space)
+///
+/// ```
+/// use iter_num_tools::js;
+///
+/// let it = js::timestamps_lin(0.0..=1000.0, 4);
+/// assert!(it.eq([0.0, 333.0, 667.0, 1000.0]));
+/// ```
+///
+/// # Panics
+///
+/// Panics if the range is not a valid `Date` range. Use [`try_timestamps_lin`] to check this instead.
+pub fn timestamps_lin(range: impl Into<AxisRange<f64>>, steps: usize) -> Timestamps {
+    match try_timestamps_lin(range, steps) {
+        Ok(it) => it,
+        Err(err) => panic!("invalid timestamps: {err}"),
+    }
+}
+
+/// Creates a space over the timestamps in a range with a fixed number of steps,
+/// returning an error if the range is not a valid `Date` range
+pub fn try_timestamps_lin(
+    range: impl Into<AxisRange<f64>>,
+    steps: usize,
+) -> Result<Timestamps, Error> {
+    let range = range.into();
+    let (start, end) = match &range {
+        AxisRange::Exclusive(range) | AxisRange::Periodic(range) => (range.start, range.end),
+        AxisRange::Inclusive(range) => (*range.start(), *range.end()),
+    };
+    check_range(start, end)?;
+    range.try_into_lin_space(steps).map(|lin| {
+        let mut it = into_timestamps(lin);
+        it.interpolate.round = true;
+        it
+    })
+}
+
+fn check_range(start: f64, end: f64) -> Result<(), Error> {
+    if !start.is_finite() || !end.is_finite() {
+        return Err(Error::NonFinite);
+    }
+    if Real::abs(start) > MAX_TIMESTAMP || Real::abs(end) > MAX_TIMESTAMP {
+        return Err(Error::OutOfRange);
+    }
+    Ok(())
+}
+
+fn into_timestamps(lin: IntoSpace<LinearInterpolation<f64>>) -> Timestamps {
+    Space::new(
+        lin.len,
+        TimestampInterpolation {
+            lin: lin.interpolate,
+            round: false,
+        },
+    )
+}
+
+/// Linear interpolation over timestamps, returned by [`timestamps`] and [`timestamps_lin`]
+#[derive(Clone, Copy, Debug)]
+pub struct TimestampInterpolation {
+    /// The interpolation in milliseconds
+    pub lin: LinearInterpolation<f64>,
+    /// Whether to round each timestamp to a whole millisecond
+    pub round: bool,
+}
+
+impl Interpolate for TimestampInterpolation {
+    type Item = f64;
+    fn interpolate(&self, x: usize) -> f64 {
+        let ms = self.lin.interpolate(x);
+        match self.round {
+            true => Real::round(ms),
+            false => ms,
+        }
+    }
+}
+
+impl SpaceFmt for TimestampInterpolation {
+    const NAME: &'static str = "Timestamps";
+    fn fmt_params(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, ", step: {:?}ms", self.lin.step)
+    }
+}
+
+/// [`Iterator`] returned by [`timestamps`] and [`timestamps_lin`]
+pub type Timestamps = Space<TimestampInterpolation>;
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::check_double_ended_iter;
+
+    #[test]
+    fn test_timestamps() {
+        let it = timestamps(0.0..SECOND, 250.0);
+        check_double_ended_iter(it, [0.0, 250.0, 500.0, 750.0]);
+
+        let it = timestamps(DAY..0.0, -6.0 * HOUR);
+        assert_eq!(it.len(), 4);
+        assert_eq!(
+            format!("{it:?}"),
+            "Timestamps { 86400000.0..=21600000.0, len: 4, step: -21600000.0ms }"
+        );
+    }
+
+    #[test]
+    fn test_timestamps_lin() {
+        let it = timestamps_lin(0.0..10.0, 3);
+        check_double_ended_iter(it, [0.0, 3.0, 7.0]);
+
+        // no rounding error builds up, even with fractional steps near the end of time
+        let it = timestamps_lin(-MAX_TIMESTAMP..=MAX_TIMESTAMP, 7);
+        assert_eq!(it.bounds(), Some(-MAX_TIMESTAMP..=MAX_TIMESTAMP));
+    }
+
+    #[test]
+    fn test_try_timestamps() {
+        assert_eq!(
+            try_timestamps_lin(0.0..=f64::INFINITY, 3).unwrap_err(),
+            Error::NonFinite
+        );
+        assert_eq!(
+            try_timestamps_lin(-f64::INFINITY..=0.0, 3).unwrap_err(),
+            Error::NonFinite
+        );
+        assert_eq!(
+            try_timestamps(0.0..f64::NAN, SECOND).unwrap_err(),
+            Error::NonFinite
+        );
+        assert_eq!(
+            try_timestamps_lin(0.0..=1e16, 3).unwrap_err(),
+            Error::OutOfRange
+        );
+        assert_eq!(
+            try_timestamps(-MAX_TIMESTAMP - DAY..0.0, DAY).unwrap_err(),
+            Error::OutOfRange
+        );
+        assert!(try_timestamps_lin(-MAX_TIMESTAMP..=MAX_TIMESTAMP, 3).is_ok());
+        assert_eq!(
+            try_timestamps_lin(0.0..=SECOND, 0).unwrap_err(),
+            Error::ZeroSteps
+        );
+        assert_eq!(
+            try_timestamps(0.0..SECOND, 0.0).unwrap_err(),
+            Error::ZeroSteps
+        );
+    }
+}
@@ -131,31 +131,128 @@
 #![warn(missing_docs)]
 #![cfg_attr(feature = "trusted_len", feature(trusted_len))]
 #![cfg_attr(feature = "iter_advance_by", feature(iter_advance_by))]
-#![cfg_attr(not(test), no_std)]
+#![cfg_attr(not(any(test, feature = "std")), no_std)]
+
+#[cfg(feature = "alloc")]
+extern crate alloc;
 
 #[cfg(test)]
 #[macro_use]
 extern crate pretty_assertions;
 
+mod adapters;
 mod arange;
 mod arange_grid;
+#[cfg(feature = "proptest")]
+mod arbitrary;
+mod axis;
+#[cfg(feature = "dashu-float")]
+pub mod big_float;
+mod builder;
+mod coords;
+#[cfg(any(feature = "chrono", feature = "time"))]
+mod datetime;
+mod decimal;
+#[cfg(feature = "alloc")]
+mod dyn_gridspace;
+mod error;
+#[cfg(feature = "fast_math")]
+pub mod fast_math;
+#[cfg(feature = "fixed")]
+pub mod fixed_point;
+mod forward_diff;
+mod fp;
+pub mod func;
+mod grid_boundary;
 mod gridspace;
 mod gridstep;
+mod hash;
+pub mod high_precision;
+mod huge;
+pub mod integrate;
+mod interp;
+#[cfg(feature = "js")]
+pub mod js;
 mod linspace;
+mod logitspace;
 mod logspace;
+mod map;
+mod matrixspace;
+mod oscillator;
+mod parse;
+mod piecewise;
+mod polyspace;
+mod powerspace;
+mod precise;
+#[cfg(feature = "uom")]
+pub mod quantity;
+mod ramp;
+mod raster;
+mod sample;
+mod simplex;
 mod space;
+mod sphere;
 mod step;
+mod sweep;
+#[cfg(feature = "testing")]
+pub mod testing;
+mod transpose;
+mod unbounded;
 
-pub use arange::{arange, Arange, IntoArange, ToArange};
+pub use adapters::{
+    chain_spaces, zip_spaces, Chain, MapWithBounds, Pairwise, Reversed, Stride, Windows, WithIndex,
+    WithT, Zip,
+};
+pub use arange::{
+    arange, arange_from, try_arange, Arange, ArangeConfig, ArangeFrom, IntoArange, ToArange,
+};
 pub use arange_grid::{arange_grid, ArangeGrid, IntoArangeGrid, ToArangeGrid};
-pub use gridspace::{grid_space, GridSpace, IntoGridSpace, ToGridSpace};
-pub use gridstep::{grid_step, GridStep, IntoGridStep, ToGridStep};
-pub use linspace::{lin_space, IntoLinSpace, LinSpace, ToLinSpace};
-pub use logspace::{log_space, IntoLogSpace, LogSpace, ToLogSpace};
+pub use axis::AxisRange;
+pub use builder::{LogSpaceBuilder, SpaceBuilder};
+pub use coords::{
+    cylindrical_grid, polar_grid, spherical_grid, CylindricalGrid, PolarGrid, SphericalGrid,
+};
+pub use decimal::{arange_decimal, try_arange_decimal, ArangeDecimal};
+#[cfg(feature = "alloc")]
+pub use dyn_gridspace::{dyn_grid_space, DynGridSpace};
+pub use error::Error;
+pub use grid_boundary::{GridBoundary, GridBoundaryWithIndices};
+pub use gridspace::{
+    grid_from_origin, grid_space, grid_space_centered, grid_space_dual, try_grid_space,
+    CellCenters, GridSpace, GridSpaceWithIndices, IntoGridSpace, ToGridSpace,
+};
+pub use gridstep::{
+    grid_step, GridStep, GridStepWithNeighbors, IntoGridStep, Neighbors, ToGridStep,
+};
+pub use hash::HashKey;
+pub use huge::{huge_grid_space, HugeGridSlice, HugeGridSpace};
+pub use interp::Interp1d;
+pub use linspace::{lin_space, try_lin_space, IntoLinSpace, LinSpace, ToLinSpace};
+pub use logitspace::{logit_space, try_logit_space, IntoLogitSpace, LogitSpace, ToLogitSpace};
+pub use logspace::{log_space, try_log_space, IntoLogSpace, LogSpace, ToLogSpace};
+pub use map::{Function, Map};
+pub use matrixspace::{
+    matrix_space, try_matrix_space, IntoMatrixSpace, MatrixSpace, ToMatrixSpace,
+};
+pub use oscillator::{cos_space, sin_space, CosSpace, SinSpace};
+pub use parse::{SweepSpace, SweepSpec};
+pub use piecewise::{piecewise, Piecewise};
+pub use polyspace::{poly_space, PolySpace};
+pub use powerspace::{decade_space, octave_space, PowerSpace};
+pub use precise::{lin_space_precise, PreciseFloat, PreciseLinSpace};
+pub use ramp::RampGenerator;
+pub use raster::{affine_grid, pixel_grid, AffineGrid, PixelGrid, PixelGridUv};
+pub use sample::{sample, Sampled};
+pub use simplex::{simplex_grid, SimplexGrid};
+pub use space::{Direction, Space};
+pub use sphere::{disk_space, sphere_space, DiskSpace, SphereSpace};
+pub use sweep::{grid_space_tuple, sweep, IntoSweep, Sweep, ToGridTuple, ToSweep};
+pub use transpose::Transpose;
+pub use unbounded::{arange_grid_from, ArangeGridFrom, Unbounded};
 
 #[cfg(test)]
 #[track_caller]
-pub fn check_double_ended_iter<T: PartialEq + core::fmt::Debug, const N: usize>(
+pub(crate) fn check_double_ended_iter<T: PartialEq + core::fmt::Debug, const N: usize>(
     i: impl DoubleEndedIterator<Item = T> + Clone,
     mut expected: [T; N],
 ) {
@@ -1,6 +1,13 @@
-use crate::space::{Interpolate, IntoSpace, Space};
+use crate::{
+    error::Error,
+    space::{
+        coarsen_range, subdivide_range, Direction, Interpolate, IntoSpace, Monotonic, Space,
+        SpaceFmt,
+    },
+};
+use core::fmt;
 use core::ops::{Range, RangeInclusive};
-use num_traits::{FromPrimitive, Num};
+use num_traits::{real::Real, FromPrimitive, Num};
 
 /// Creates a linear space over range with a fixed number of steps
 ///
@@ -14,7 +21,23 @@ use num_traits::{FromPrimitive, Num};
 /// // Exclusive
 /// let it = lin_space(20.0..21.0, 2);
 /// assert!(it.eq(vec![20.0, 20.5]));
+///
+/// // Zero steps are empty, a single step is just the start
+/// assert!(lin_space(20.0..=21.0, 0).eq(Vec::<f64>::new()));
+/// assert!(lin_space(20.0..=21.0, 1).eq(vec![20.0]));
 /// ```
+///
+/// Any [`Num`] type can be used, so exact types such as `num_rational::Ratio<i64>`
+/// produce spaces with no rounding error, ending exactly on an inclusive end.
+///
+/// Each index is converted to the item type before being multiplied by the step,
+/// so an `f32` space of more than 2<sup>24</sup> values will round neighbouring indices together.
+/// Use [`high_precision::lin_space`](crate::high_precision::lin_space) to compute the values in `f64` instead.
+///
+/// # Panics
+///
+/// Panics if the number of steps can't be represented in the item type, such as 300 steps of a `u8`.
+/// Use [`try_lin_space`] to check this instead.
 #[inline]
 pub fn lin_space<R>(range: R, steps: usize) -> LinSpace<R::Item>
 where
@@ -23,10 +46,45 @@ where
     range.into_lin_space(steps).into_space()
 }
 
-#[derive(Clone, Copy, Debug)]
-pub struct LinearInterpolation<T> {
-    pub start: T,
-    pub step: T,
+/// Creates a linear space over range with a fixed number of steps,
+/// returning an error instead of producing an invalid space
+///
+/// ```
+/// use iter_num_tools::{try_lin_space, Error};
+///
+/// let it = try_lin_space(20.0..=21.0, 3).unwrap();
+/// assert!(it.eq(vec![20.0, 20.5, 21.0]));
+///
+/// assert_eq!(try_lin_space(20.0..21.0, 0).unwrap_err(), Error::ZeroSteps);
+/// ```
+pub fn try_lin_space<R>(range: R, steps: usize) -> Result<LinSpace<R::Item>, Error>
+where
+    R: ToLinSpace,
+{
+    range.try_into_lin_space(steps).map(IntoSpace::into_space)
+}
+
+/// Interpolates `start + x * step`.
+///
+/// `P` is the type of the points and `D` the type of the difference between them.
+/// These are the same for numbers, but differ for affine types like timestamps,
+/// where the step is a duration.
+#[derive(Clone, Copy, Debug, Default)]
+pub struct LinearInterpolation<P, D = P> {
+    pub start: P,
+    pub step: D,
+}
+
+impl<T: Num + Clone> LinearInterpolation<T> {
+    /// Shifts the start forward by half a step, to the center of the first interval
+    pub(crate) fn centered(self) -> Self {
+        let Self { start, step } = self;
+        let half = step.clone() / (T::one() + T::one());
+        LinearInterpolation {
+            start: start + half,
+            step,
+        }
+    }
 }
 
 /// A helper trait for [`lin_space`]
@@ -35,45 +93,401 @@ pub trait ToLinSpace {
     type Item;
     /// Create the lin space
     fn into_lin_space(self, step: usize) -> IntoLinSpace<Self::Item>;
+    /// Create the lin space, validating the range and steps
+    fn try_into_lin_space(self, step: usize) -> Result<IntoLinSpace<Self::Item>, Error>;
 }
 
-impl<T: Num + FromPrimitive + Copy> ToLinSpace for Range<T> {
+impl<T: Num + FromPrimitive + Clone> ToLinSpace for Range<T> {
     type Item = T;
 
     fn into_lin_space(self, steps: usize) -> IntoLinSpace<Self::Item> {
         let Range { start, end } = self;
-        let step = (end - start) / T::from_usize(steps).unwrap();
+        let step = match steps {
+            0 => T::zero(),
+            _ => (end - start.clone()) / T::from_usize(steps).expect(STEPS_OVERFLOW),
+        };
         IntoLinSpace::new(steps, LinearInterpolation { start, step })
     }
+
+    fn try_into_lin_space(self, steps: usize) -> Result<IntoLinSpace<Self::Item>, Error> {
+        if !is_finite(&self.start) || !is_finite(&self.end) {
+            return Err(Error::NonFinite);
+        }
+        if steps == 0 {
+            return Err(Error::ZeroSteps);
+        }
+        T::from_usize(steps).ok_or(Error::Overflow)?;
+        Ok(self.into_lin_space(steps))
+    }
 }
 
-impl<T: Num + FromPrimitive + Copy> ToLinSpace for RangeInclusive<T> {
+impl<T: Num + FromPrimitive + Clone> ToLinSpace for RangeInclusive<T> {
     type Item = T;
 
     fn into_lin_space(self, steps: usize) -> IntoLinSpace<Self::Item> {
         let (start, end) = self.into_inner();
-        let step = (end - start) / T::from_usize(steps - 1).unwrap();
+        let step = match steps {
+            0 | 1 => T::zero(),
+            _ => (end - start.clone()) / T::from_usize(steps - 1).expect(STEPS_OVERFLOW),
+        };
         IntoLinSpace::new(steps, LinearInterpolation { start, step })
     }
+
+    fn try_into_lin_space(self, steps: usize) -> Result<IntoLinSpace<Self::Item>, Error> {
+        if !is_finite(self.start()) || !is_finite(self.end()) {
+            return Err(Error::NonFinite);
+        }
+        match steps {
+            0 => return Err(Error::ZeroSteps),
+            _ => T::from_usize(steps).ok_or(Error::Overflow)?,
+        };
+        Ok(self.into_lin_space(steps))
+    }
+}
+
+const STEPS_OVERFLOW: &str = "number of steps overflows the item type";
+
+/// `x - x` is only non-zero if `x` is infinite or NaN.
+/// This lets us check finiteness without requiring a float bound
+#[allow(clippy::eq_op)]
+pub(crate) fn is_finite<T: Num + Clone>(x: &T) -> bool {
+    (x.clone() - x.clone()).is_zero()
 }
 
-impl<T: Num + FromPrimitive> Interpolate for LinearInterpolation<T> {
+impl<T: Num + FromPrimitive + Clone> Interpolate for LinearInterpolation<T> {
     type Item = T;
-    fn interpolate(self, x: usize) -> T {
-        let Self { start, step } = self;
+    fn interpolate(&self, x: usize) -> T {
+        let Self { start, step } = self.clone();
         start + T::from_usize(x).unwrap() * step
     }
 }
 
+impl<T: Num + FromPrimitive + Clone + PartialOrd> Monotonic for LinearInterpolation<T> {
+    fn direction(&self) -> Direction {
+        Direction::of_step(self.step.partial_cmp(&T::zero()))
+    }
+}
+
+impl<T: Num + FromPrimitive + Clone + fmt::Debug> SpaceFmt for LinearInterpolation<T> {
+    const NAME: &'static str = "LinSpace";
+    fn fmt_params(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, ", step: {:?}", self.step)
+    }
+}
+
 /// [`Iterator`] returned by [`lin_space`]
-pub type LinSpace<T> = Space<LinearInterpolation<T>>;
+///
+/// `D` is the type of the step, which is the same as the values for numbers.
+///
+/// With the `proptest` feature, `LinSpace<f32>` and `LinSpace<f64>` implement `proptest::arbitrary::Arbitrary`.
+pub type LinSpace<P, D = P> = Space<LinearInterpolation<P, D>>;
 
 /// [`IntoIterator`] returned by [`ToLinSpace::into_lin_space`]
-pub type IntoLinSpace<T> = IntoSpace<LinearInterpolation<T>>;
+pub type IntoLinSpace<P, D = P> = IntoSpace<LinearInterpolation<P, D>>;
+
+impl<T: Num + FromPrimitive + Clone> LinSpace<T> {
+    /// Refines the remaining values so that there are `factor` times as many
+    /// intervals between the same first and last values
+    ///
+    /// The space doesn't remember whether it came from an exclusive range,
+    /// so the refined values still stop at the last value, rather than filling in up to the excluded end.
+    ///
+    /// ```
+    /// use iter_num_tools::lin_space;
+    ///
+    /// let it = lin_space(0.0..=2.0, 3).subdivide(2);
+    /// assert!(it.eq([0.0, 0.5, 1.0, 1.5, 2.0]));
+    ///
+    /// // 2.0 was excluded, so 1.5 isn't added
+    /// let it = lin_space(0.0..2.0, 2).subdivide(2);
+    /// assert!(it.eq([0.0, 0.5, 1.0]));
+    /// ```
+    ///
+    /// # Panics
+    ///
+    /// Panics if `factor` is zero
+    pub fn subdivide(self, factor: usize) -> Self {
+        assert!(factor > 0, "subdivide factor must be non-zero");
+        let Space {
+            interpolate, range, ..
+        } = self;
+        let LinearInterpolation { start, step } = interpolate;
+        let step = step / T::from_usize(factor).unwrap();
+        Space::with_range(
+            subdivide_range(range, factor),
+            LinearInterpolation { start, step },
+        )
+    }
+
+    /// The inverse of [`subdivide`](Self::subdivide). Keeps every `factor`th value.
+    ///
+    /// Returns `None` if `factor` is zero or if the first and last values
+    /// would not be kept.
+    ///
+    /// ```
+    /// use iter_num_tools::lin_space;
+    ///
+    /// let it = lin_space(0.0..=2.0, 5).coarsen(2).unwrap();
+    /// assert!(it.eq([0.0, 1.0, 2.0]));
+    ///
+    /// assert!(lin_space(0.0..=2.0, 4).coarsen(2).is_none());
+    /// ```
+    pub fn coarsen(self, factor: usize) -> Option<Self> {
+        let Space {
+            interpolate, range, ..
+        } = self;
+        let range = coarsen_range(range, factor)?;
+        let LinearInterpolation { start, step } = interpolate;
+        let step = step * T::from_usize(factor).unwrap();
+        Some(Space::with_range(
+            range,
+            LinearInterpolation { start, step },
+        ))
+    }
+
+    /// The index in the space where `value` would be, which is fractional if it falls between two values.
+    ///
+    /// Like [`with_index`](Space::with_index), this counts from the first value the space was created with.
+    ///
+    /// ```
+    /// use iter_num_tools::lin_space;
+    ///
+    /// let it = lin_space(1.0..=2.0, 5);
+    /// assert_eq!(it.position_of(1.5), 2.0);
+    /// assert_eq!(it.position_of(1.125), 0.5);
+    /// ```
+    pub fn position_of(&self, value: T) -> T {
+        self.interpolate.position_of(value)
+    }
+
+    /// The sum of the remaining values, found in constant time from the formula for an arithmetic series.
+    ///
+    /// This is `n / 2` times the sum of the first and last values when `n` is even, or `n` times the middle value when it's odd,
+    /// so for integers it only overflows when the sum itself does.
+    /// For floats it may round slightly differently to adding up each value with [`Iterator::sum`].
+    ///
+    /// ```
+    /// use iter_num_tools::{arange, lin_space};
+    ///
+    /// assert_eq!(lin_space(1..=100, 100).sum_exact(), 5050);
+    /// assert_eq!(arange(0.0..2.0, 0.25).sum_exact(), 7.0);
+    /// ```
+    pub fn sum_exact(&self) -> T {
+        let n = self.range.len();
+        if n == 0 {
+            return T::zero();
+        }
+        let Range { start, end } = self.range;
+        if n % 2 == 0 {
+            let first = self.interpolate.interpolate(start);
+            let last = self.interpolate.interpolate(end - 1);
+            (first + last) * T::from_usize(n / 2).unwrap()
+        } else {
+            let middle = self.interpolate.interpolate(start + n / 2);
+            middle * T::from_usize(n).unwrap()
+        }
+    }
+
+    /// The mean of the remaining values, found in constant time, or `None` if there are none.
+    ///
+    /// This is halfway between the first and last values.
+    /// For integers, it rounds towards the first value like integer division.
+    ///
+    /// ```
+    /// use iter_num_tools::lin_space;
+    ///
+    /// assert_eq!(lin_space(1.0..=4.0, 4).mean(), Some(2.5));
+    /// assert_eq!(lin_space(1.0..=4.0, 0).mean(), None);
+    /// ```
+    pub fn mean(&self) -> Option<T> {
+        let n = self.range.len();
+        if n == 0 {
+            return None;
+        }
+        let first = self.interpolate.interpolate(self.range.start);
+        let two = T::one() + T::one();
+        let half_span = T::from_usize(n - 1).unwrap() * self.interpolate.step.clone() / two;
+        Some(first + half_span)
+    }
+
+    /// The median of the remaining values, found in constant time, or `None` if there are none.
+    ///
+    /// The values are evenly spaced, so this is the same as the [`mean`](Self::mean).
+    pub fn median(&self) -> Option<T> {
+        self.mean()
+    }
+
+    /// The population variance of the remaining values, found in constant time, or `None` if there are none.
+    ///
+    /// This is `step² * (n² - 1) / 12`.
+    /// For integers, it rounds towards zero like integer division.
+    ///
+    /// ```
+    /// use iter_num_tools::lin_space;
+    ///
+    /// assert_eq!(lin_space(1.0..=4.0, 4).variance(), Some(1.25));
+    /// assert_eq!(lin_space(1.0..=4.0, 1).variance(), Some(0.0));
+    /// ```
+    pub fn variance(&self) -> Option<T> {
+        let n = self.range.len();
+        if n == 0 {
+            return None;
+        }
+        let step = self.interpolate.step.clone();
+        let n = T::from_usize(n).unwrap();
+        let twelve = T::from_u8(12).unwrap();
+        Some(step.clone() * step * (n.clone() * n - T::one()) / twelve)
+    }
+}
+
+impl<T: Real + FromPrimitive> LinSpace<T> {
+    /// Searches the remaining values for `value`, like [`slice::binary_search`].
+    ///
+    /// Returns `Ok` with the index of the first match if the value is in the space, or `Err` with the index
+    /// where it could be inserted while keeping the values in order.
+    /// Rather than searching, this works out where the value should be directly,
+    /// then checks its neighbours. Decreasing spaces are searched in their own order.
+    ///
+    /// ```
+    /// use iter_num_tools::{arange, lin_space};
+    ///
+    /// let it = lin_space(0.0..=1.0, 5);
+    /// assert_eq!(it.binary_search_by_value(0.5), Ok(2));
+    /// assert_eq!(it.binary_search_by_value(0.6), Err(3));
+    /// assert_eq!(it.binary_search_by_value(-1.0), Err(0));
+    ///
+    /// let it = arange(3.0..0.0, -1.0);
+    /// assert_eq!(it.binary_search_by_value(1.5), Err(2));
+    /// ```
+    pub fn binary_search_by_value(&self, value: T) -> Result<usize, usize> {
+        let offset = self.position_of(value) - T::from_usize(self.range.start).unwrap();
+        let guess = offset.ceil().to_usize().unwrap_or(0);
+        self.search_with_guess(&value, guess)
+    }
+}
+
+impl<T: Num + Clone> LinearInterpolation<T> {
+    pub(crate) fn position_of(&self, value: T) -> T {
+        (value - self.start.clone()) / self.step.clone()
+    }
+}
+
+/// Converts the remaining values back into an exclusive range,
+/// which ends one step after the last value
+///
+/// ```
+/// use iter_num_tools::lin_space;
+///
+/// let range = core::ops::Range::from(lin_space(0.0..2.0, 4));
+/// assert_eq!(range, 0.0..2.0);
+/// ```
+impl<T: Num + FromPrimitive + Clone> From<LinSpace<T>> for Range<T> {
+    fn from(space: LinSpace<T>) -> Self {
+        let Space {
+            interpolate, range, ..
+        } = space;
+        interpolate.interpolate(range.start)..interpolate.interpolate(range.end)
+    }
+}
+
+/// Converts the remaining values back into an inclusive range from the first to the last value.
+///
+/// Fails with [`Error::Empty`] if there are no values remaining
+///
+/// ```
+/// use iter_num_tools::{lin_space, Error};
+/// use core::ops::RangeInclusive;
+///
+/// let range = RangeInclusive::try_from(lin_space(0.0..=2.0, 5));
+/// assert_eq!(range, Ok(0.0..=2.0));
+///
+/// let range = RangeInclusive::try_from(lin_space(0.0..=2.0, 0));
+/// assert_eq!(range, Err(Error::Empty));
+/// ```
+impl<T: Num + FromPrimitive + Clone> TryFrom<LinSpace<T>> for RangeInclusive<T> {
+    type Error = Error;
+
+    fn try_from(space: LinSpace<T>) -> Result<Self, Self::Error> {
+        space.bounds().ok_or(Error::Empty)
+    }
+}
 
 #[cfg(test)]
 mod tests {
     use super::*;
+    use num_rational::Ratio;
+
+    /// Checks the search against searching the collected values
+    fn check_search(mut it: LinSpace<f64>, values: &[f64]) {
+        it.next();
+        it.next_back();
+        let collected: Vec<f64> = it.clone().collect();
+        let descending = collected.first() > collected.last();
+        for &x in values {
+            let i = collected.partition_point(|&y| if descending { y > x } else { y < x });
+            let expected = match collected.get(i) == Some(&x) {
+                true => Ok(i),
+                false => Err(i),
+            };
+            assert_eq!(it.binary_search_by_value(x), expected, "{x}");
+        }
+    }
+
+    #[test]
+    fn test_binary_search_by_value() {
+        let values: Vec<f64> = (-30..=130).map(|x| x as f64 / 10.0).collect();
+        check_search(lin_space(0.0..=10.0, 101), &values);
+        check_search(lin_space(10.0..=0.0, 11), &values);
+        check_search(lin_space(0.1..=7.3, 3_001), &values);
+        check_search(lin_space(1.0..=1.0, 4), &values);
+        check_search(lin_space(0.0..1.0, 2), &values);
+        assert_eq!(lin_space(0.0..1.0, 0).binary_search_by_value(0.0), Err(0));
+        assert_eq!(
+            lin_space(0.0..1.0, 5).binary_search_by_value(f64::NAN),
+            Err(0)
+        );
+    }
+
+    #[test]
+    fn test_sum_exact() {
+        let mut it = lin_space(-3..=9, 5);
+        assert_eq!(it.sum_exact(), 15);
+        it.next();
+        it.next_back();
+        assert_eq!(it.sum_exact(), it.clone().sum::<i32>());
+        assert_eq!(lin_space(0..5, 0).sum_exact(), 0);
+
+        // only the sum needs to fit, not n times the first value
+        assert_eq!(lin_space(0u8..=20, 21).sum_exact(), 210);
+        assert_eq!(lin_space(0u8..=20, 20).sum_exact(), 190);
+        assert_eq!(lin_space(-6i8..=6, 13).sum_exact(), 0);
+        assert_eq!(lin_space(250u8..=250, 1).sum_exact(), 250);
+        assert_eq!(lin_space(120i8..=127, 1).sum_exact(), 120);
+        assert_eq!(lin_space(-60i8..=61, 122).sum_exact(), 61);
+
+        let it = lin_space(0.0..1.0, 1000);
+        assert!((it.sum_exact() - it.sum::<f64>()).abs() < 1e-9);
+    }
+
+    #[test]
+    fn test_mean_variance() {
+        let mut it = lin_space(0.0..=10.0, 11);
+        it.next_back();
+        assert_eq!(
+            (it.mean(), it.median(), it.variance()),
+            (Some(4.5), Some(4.5), Some(8.25))
+        );
+
+        let values: Vec<f64> = it.collect();
+        let mean = values.iter().sum::<f64>() / values.len() as f64;
+        let variance = values.iter().map(|x| (x - mean).powi(2)).sum::<f64>() / values.len() as f64;
+        assert_eq!((mean, variance), (4.5, 8.25));
+
+        let it = lin_space(10.0..=-10.0, 5);
+        assert_eq!((it.mean(), it.variance()), (Some(0.0), Some(50.0)));
+        let it = lin_space(0..1, 0);
+        assert_eq!((it.mean(), it.median(), it.variance()), (None, None, None));
+    }
 
     #[test]
     fn test_lin_space_inclusive() {
@@ -126,6 +540,70 @@ mod tests {
         assert_eq!(lin_space(0.0..=5.0, 6).last(), Some(5.0));
     }
 
+    #[test]
+    fn test_lin_space_few_steps() {
+        assert!(lin_space(1.0..=5.0, 0).eq(Vec::<f64>::new()));
+        assert!(lin_space(1.0..5.0, 0).eq(Vec::<f64>::new()));
+        assert!(lin_space(1.0..=5.0, 1).eq(vec![1.0]));
+        assert!(lin_space(1.0..5.0, 1).eq(vec![1.0]));
+        assert!(lin_space(1..=5, 0).eq(Vec::<i32>::new()));
+        assert!(lin_space(1..=5, 1).eq(vec![1]));
+    }
+
+    #[test]
+    fn test_try_lin_space() {
+        assert!(try_lin_space(0.0..=1.0, 3).unwrap().eq(vec![0.0, 0.5, 1.0]));
+        assert_eq!(try_lin_space(0.0..=1.0, 0).unwrap_err(), Error::ZeroSteps);
+        assert_eq!(try_lin_space(0.0..1.0, 0).unwrap_err(), Error::ZeroSteps);
+        assert!(try_lin_space(0.0..=1.0, 1).unwrap().eq(vec![0.0]));
+        assert!(try_lin_space(0.0..1.0, 1).unwrap().eq(vec![0.0]));
+
+        assert_eq!(
+            try_lin_space(0.0..f64::INFINITY, 3).unwrap_err(),
+            Error::NonFinite
+        );
+        assert_eq!(
+            try_lin_space(f64::NAN..=1.0, 3).unwrap_err(),
+            Error::NonFinite
+        );
+        assert_eq!(try_lin_space(0u8..=1, 300).unwrap_err(), Error::Overflow);
+    }
+
+    #[test]
+    fn test_lin_space_into_range() {
+        let mut it = lin_space(0..10, 5);
+        it.next();
+        it.next_back();
+        assert_eq!(Range::from(it.clone()), 2..8);
+        assert_eq!(RangeInclusive::try_from(it), Ok(2..=6));
+
+        let it = lin_space(0.0..1.0, 0);
+        assert_eq!(Range::from(it.clone()), 0.0..0.0);
+        assert_eq!(RangeInclusive::try_from(it), Err(Error::Empty));
+    }
+
+    #[test]
+    fn test_lin_space_subdivide() {
+        let mut it = lin_space(0.0..5.0, 5);
+        it.next();
+        assert!(it
+            .clone()
+            .subdivide(2)
+            .eq(vec![1.0, 1.5, 2.0, 2.5, 3.0, 3.5, 4.0]));
+
+        let it = it.subdivide(4).coarsen(4).unwrap();
+        assert!(it.eq(vec![1.0, 2.0, 3.0, 4.0]));
+
+        // an exclusive range keeps its last value, not its excluded end
+        let it = lin_space(0.0..1.0, 4).subdivide(2);
+        assert_eq!(it.bounds(), Some(0.0..=0.75));
+        assert_eq!(it.len(), 7);
+        assert!(lin_space(0.0..1.0, 4).eq(it.coarsen(2).unwrap()));
+
+        assert!(lin_space(0.0..5.0, 5).coarsen(0).is_none());
+        assert!(lin_space(0.0..5.0, 0).subdivide(2).eq(Vec::<f64>::new()));
+    }
+
     #[test]
     #[cfg(feature = "iter_advance_by")]
     fn test_lin_space_advance_by() {
@@ -136,4 +614,37 @@ mod tests {
         it.advance_back_by(2).unwrap();
         assert_eq!(it.next_back(), Some(3.0));
     }
+
+    #[test]
+    fn test_lin_space_rational() {
+        let r = Ratio::<i64>::new;
+        crate::check_double_ended_iter(
+            lin_space(r(0, 1)..=r(1, 1), 4),
+            [r(0, 1), r(1, 3), r(2, 3), r(1, 1)],
+        );
+
+        let it = lin_space(r(1, 3)..=r(2, 3), 1_000);
+        assert_eq!(it.bounds(), Some(r(1, 3)..=r(2, 3)));
+        assert_eq!(it.clone().nth(333), Some(r(1, 3) + r(333, 2997)));
+
+        let it = try_lin_space(r(0, 1)..r(1, 1), 3).unwrap().subdivide(2);
+        assert!(it.eq([0, 1, 2, 3, 4].map(|n| r(n, 6))));
+    }
+
+    #[test]
+    fn test_lin_space_fold() {
+        // folding computes each value from its index, the same as next
+        let it = lin_space(0.0_f64..=328608.8304575479, 8);
+        let mut direct = it.clone();
+        it.clone().for_each(|x| assert_eq!(Some(x), direct.next()));
+        assert_eq!(it.clone().fold(0.0, |_, x| x), 328608.8304575479);
+
+        let it = lin_space(0.0_f32..=1.0, 100_001);
+        assert_eq!(it.clone().fold(0.0, |_, x| x), 1.0);
+        let mut last = None;
+        it.for_each(|x| last = Some(x));
+        assert_eq!(last, Some(1.0));
+
+        assert_eq!(lin_space(0..10, 5).sum::<i32>(), 20);
+    }
 }
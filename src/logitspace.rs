@@ -0,0 +1,178 @@
+use core::fmt;
+use core::ops::{Range, RangeInclusive};
+use num_traits::{real::Real, FromPrimitive};
+
+use crate::{
+    error::Error,
+    fp,
+    linspace::{LinearInterpolation, ToLinSpace},
+    space::{Direction, Interpolate, IntoSpace, Monotonic, Space, SpaceFmt},
+};
+
+/// Creates a space over a range of probabilities with a fixed number of steps,
+/// concentrating the values near 0 and 1.
+///
+/// The values are evenly spaced after the logit transform, `ln(p / (1 - p))`,
+/// which is useful for sweeping probabilities where the extremes matter the most.
+///
+/// ```
+/// use iter_num_tools::logit_space;
+///
+/// let it = logit_space(0.01..=0.99, 5);
+/// let expected: [f64; 5] = [0.01, 0.0913, 0.5, 0.9087, 0.99];
+/// assert!(it.zip(expected).all(|(x, y)| (x - y).abs() < 1e-4));
+/// ```
+///
+/// The range must be within `(0, 1)`, otherwise the values will be NaN.
+/// Use [`try_logit_space`] to validate the range.
+pub fn logit_space<R>(range: R, steps: usize) -> LogitSpace<R::Item>
+where
+    R: ToLogitSpace,
+{
+    range.into_logit_space(steps).into_space()
+}
+
+/// Creates a logit space over a range with a fixed number of steps,
+/// returning an error instead of producing an invalid space
+///
+/// ```
+/// use iter_num_tools::{try_logit_space, Error};
+///
+/// assert_eq!(try_logit_space(0.0..=0.5, 5).unwrap_err(), Error::NonFinite);
+/// assert_eq!(try_logit_space(0.5..=1.5, 5).unwrap_err(), Error::NonFinite);
+/// assert_eq!(try_logit_space(0.1..=0.9, 0).unwrap_err(), Error::ZeroSteps);
+///
+/// assert_eq!(try_logit_space(0.1..=0.9, 3).unwrap().len(), 3);
+/// ```
+pub fn try_logit_space<R>(range: R, steps: usize) -> Result<LogitSpace<R::Item>, Error>
+where
+    R: ToLogitSpace,
+{
+    range.try_into_logit_space(steps).map(IntoSpace::into_space)
+}
+
+/// Linear interpolation between the logits of the range, mapped back through the sigmoid
+#[derive(Clone, Copy, Debug, Default)]
+pub struct LogitInterpolation<T>(pub LinearInterpolation<T>);
+
+fn logit<T: Real>(p: T) -> T {
+    fp::ln(p / (T::one() - p))
+}
+
+fn sigmoid<T: Real>(x: T) -> T {
+    (T::one() + fp::exp(-x)).recip()
+}
+
+impl<T: Real + FromPrimitive> Interpolate for LogitInterpolation<T> {
+    type Item = T;
+    fn interpolate(&self, x: usize) -> T {
+        sigmoid(self.0.interpolate(x))
+    }
+}
+
+impl<T: Real + FromPrimitive> Monotonic for LogitInterpolation<T> {
+    fn direction(&self) -> Direction {
+        // the sigmoid is increasing
+        self.0.direction()
+    }
+}
+
+impl<T: Real + FromPrimitive + fmt::Debug> SpaceFmt for LogitInterpolation<T> {
+    const NAME: &'static str = "LogitSpace";
+    fn fmt_params(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, ", logit step: {:?}", self.0.step)
+    }
+}
+
+/// A helper trait for [`logit_space`]
+pub trait ToLogitSpace {
+    /// The item that this is a logit space over
+    type Item;
+    /// Create the logit space
+    fn into_logit_space(self, step: usize) -> IntoLogitSpace<Self::Item>;
+    /// Create the logit space, validating the range and steps
+    fn try_into_logit_space(self, step: usize) -> Result<IntoLogitSpace<Self::Item>, Error>;
+}
+
+fn into_logit<T>(lin: IntoSpace<LinearInterpolation<T>>) -> IntoLogitSpace<T> {
+    IntoLogitSpace::new(lin.len, LogitInterpolation(lin.interpolate))
+}
+
+impl<T: Real + FromPrimitive> ToLogitSpace for Range<T> {
+    type Item = T;
+
+    fn into_logit_space(self, steps: usize) -> IntoLogitSpace<Self::Item> {
+        into_logit((logit(self.start)..logit(self.end)).into_lin_space(steps))
+    }
+
+    fn try_into_logit_space(self, steps: usize) -> Result<IntoLogitSpace<Self::Item>, Error> {
+        (logit(self.start)..logit(self.end))
+            .try_into_lin_space(steps)
+            .map(into_logit)
+    }
+}
+
+impl<T: Real + FromPrimitive> ToLogitSpace for RangeInclusive<T> {
+    type Item = T;
+
+    fn into_logit_space(self, steps: usize) -> IntoLogitSpace<Self::Item> {
+        let (start, end) = self.into_inner();
+        into_logit((logit(start)..=logit(end)).into_lin_space(steps))
+    }
+
+    fn try_into_logit_space(self, steps: usize) -> Result<IntoLogitSpace<Self::Item>, Error> {
+        let (start, end) = self.into_inner();
+        (logit(start)..=logit(end))
+            .try_into_lin_space(steps)
+            .map(into_logit)
+    }
+}
+
+/// [`Iterator`] returned by [`logit_space`]
+pub type LogitSpace<T> = Space<LogitInterpolation<T>>;
+
+/// [`IntoIterator`] returned by [`ToLogitSpace::into_logit_space`]
+pub type IntoLogitSpace<T> = IntoSpace<LogitInterpolation<T>>;
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_logit_space_inclusive() {
+        let it = logit_space(0.1..=0.9, 3);
+        assert_eq!(it.len(), 3);
+        let values: Vec<f64> = it.collect();
+        assert!((values[0] - 0.1).abs() < 1e-12);
+        assert!((values[1] - 0.5).abs() < 1e-12);
+        assert!((values[2] - 0.9).abs() < 1e-12);
+    }
+
+    #[test]
+    fn test_logit_space_exclusive() {
+        let it = logit_space(0.2..0.8, 2);
+        let values: Vec<f64> = it.rev().collect();
+        assert!((values[0] - 0.5).abs() < 1e-12);
+        assert!((values[1] - 0.2).abs() < 1e-12);
+    }
+
+    #[test]
+    fn test_logit_space_symmetric() {
+        // values are symmetric around 0.5, and closer together near the ends
+        let values: Vec<f64> = logit_space(0.001..=0.999, 7).collect();
+        for (a, b) in values.iter().zip(values.iter().rev()) {
+            assert!((a + b - 1.0).abs() < 1e-12);
+        }
+        assert!(values[1] - values[0] < values[3] - values[2]);
+    }
+
+    #[test]
+    fn test_try_logit_space() {
+        assert_eq!(try_logit_space(0.5..0.5, 0).unwrap_err(), Error::ZeroSteps);
+        assert!(try_logit_space(0.5..=0.5, 1).unwrap().eq(vec![0.5]));
+        assert_eq!(
+            try_logit_space(-0.5..=0.5, 3).unwrap_err(),
+            Error::NonFinite
+        );
+    }
+}
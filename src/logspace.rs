@@ -1,7 +1,13 @@
+use core::fmt;
 use core::ops::{Range, RangeInclusive};
 use num_traits::{real::Real, FromPrimitive};
 
-use crate::space::{Interpolate, IntoSpace, Space};
+use crate::{
+    error::Error,
+    fp,
+    linspace::is_finite,
+    space::{Direction, Interpolate, IntoSpace, Monotonic, Space, SpaceFmt},
+};
 
 /// Creates a logarithmic space over range with a fixed number of steps
 ///
@@ -23,6 +29,12 @@ use crate::space::{Interpolate, IntoSpace, Space};
 /// // all approx equal
 /// assert!(zip_eq(it, expected).all(|(x, y)| (x-y).abs() < 1e-10));
 /// ```
+///
+/// Zero steps produces an empty space. An inclusive range with a single step
+/// produces only the start value.
+///
+/// The range must not start or end at zero, or cross zero, otherwise the values will be NaN.
+/// Use [`try_log_space`] to validate the range.
 pub fn log_space<R>(range: R, steps: usize) -> LogSpace<R::Item>
 where
     R: ToLogSpace,
@@ -30,10 +42,50 @@ where
     range.into_log_space(steps).into_space()
 }
 
-#[derive(Clone, Copy, Debug)]
+/// Creates a logarithmic space over range with a fixed number of steps,
+/// returning an error instead of producing an invalid space
+///
+/// ```
+/// use iter_num_tools::{try_log_space, Error};
+///
+/// assert_eq!(try_log_space(0.0..=10.0, 5).unwrap_err(), Error::ZeroStart);
+/// assert_eq!(try_log_space(-1.0..=10.0, 5).unwrap_err(), Error::SignMismatch);
+/// assert_eq!(try_log_space(1.0..=10.0, 0).unwrap_err(), Error::ZeroSteps);
+///
+/// assert_eq!(try_log_space(1.0..=100.0, 3).unwrap().len(), 3);
+/// ```
+pub fn try_log_space<R>(range: R, steps: usize) -> Result<LogSpace<R::Item>, Error>
+where
+    R: ToLogSpace,
+{
+    range.try_into_log_space(steps).map(IntoSpace::into_space)
+}
+
+/// Each value is `start * 2^(exponent * x)`.
+///
+/// Raising to a multiple of the exponent stops the errors building up along the space,
+/// where multiplying by a rounded ratio over and over would let them grow with the number of steps.
+/// The exponent is still rounded, and `2^y` scales an error in `y` by the size of `y`,
+/// so each value is within a relative error of about `(|log2(end / start)| + 2) * ε` of the exact one,
+/// where `ε` is the [machine epsilon](f64::EPSILON).
+/// That's a few ulps over a few powers of ten, but can be hundreds over a range like `1e-150..=1e150`.
+#[derive(Clone, Copy, Debug, Default)]
 pub struct LogarithmicInterpolation<T> {
     pub start: T,
-    pub step: T,
+    pub exponent: T,
+}
+
+impl<T: Real> LogarithmicInterpolation<T> {
+    /// The interpolation from `start` which reaches `end` after `steps` steps
+    fn new(start: T, end: T, steps: T) -> Self {
+        let exponent = fp::log2(end / start) / steps;
+        LogarithmicInterpolation { start, exponent }
+    }
+
+    /// The ratio between each value and the one before
+    pub(crate) fn ratio(&self) -> T {
+        fp::exp2(self.exponent)
+    }
 }
 
 /// A helper trait for [`log_space`]
@@ -42,13 +94,46 @@ pub trait ToLogSpace {
     type Item;
     /// Create the log space
     fn into_log_space(self, step: usize) -> IntoLogSpace<Self::Item>;
+    /// Create the log space, validating the range and steps
+    fn try_into_log_space(self, step: usize) -> Result<IntoLogSpace<Self::Item>, Error>;
+}
+
+fn validate_log_range<T: Real>(start: T, end: T, steps: usize) -> Result<(), Error> {
+    if !is_finite(&start) || !is_finite(&end) {
+        return Err(Error::NonFinite);
+    }
+    if steps == 0 {
+        return Err(Error::ZeroSteps);
+    }
+    if start.is_zero() || end.is_zero() {
+        return Err(Error::ZeroStart);
+    }
+    if start.is_sign_negative() != end.is_sign_negative() {
+        return Err(Error::SignMismatch);
+    }
+    Ok(())
 }
 
 impl<T: Real> Interpolate for LogarithmicInterpolation<T> {
     type Item = T;
-    fn interpolate(self, x: usize) -> T {
-        let Self { start, step } = self;
-        start * step.powi(x as i32)
+    fn interpolate(&self, x: usize) -> T {
+        let Self { start, exponent } = *self;
+        let x = T::from(x).unwrap();
+        start * fp::exp2(exponent * x)
+    }
+}
+
+impl<T: Real> Monotonic for LogarithmicInterpolation<T> {
+    fn direction(&self) -> Direction {
+        let Self { start, exponent } = *self;
+        Direction::of_step((start * exponent).partial_cmp(&T::zero()))
+    }
+}
+
+impl<T: Real + fmt::Debug> SpaceFmt for LogarithmicInterpolation<T> {
+    const NAME: &'static str = "LogSpace";
+    fn fmt_params(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, ", ratio: {:?}", self.ratio())
     }
 }
 
@@ -57,8 +142,16 @@ impl<T: Real + FromPrimitive> ToLogSpace for Range<T> {
 
     fn into_log_space(self, steps: usize) -> IntoLogSpace<Self::Item> {
         let Range { start, end } = self;
-        let step = (end / start).powf(T::from_usize(steps).unwrap().recip());
-        IntoLogSpace::new(steps, LogarithmicInterpolation { start, step })
+        let interpolate = match steps {
+            0 => LogarithmicInterpolation::new(start, start, T::one()),
+            _ => LogarithmicInterpolation::new(start, end, T::from_usize(steps).unwrap()),
+        };
+        IntoLogSpace::new(steps, interpolate)
+    }
+
+    fn try_into_log_space(self, steps: usize) -> Result<IntoLogSpace<Self::Item>, Error> {
+        validate_log_range(self.start, self.end, steps)?;
+        Ok(self.into_log_space(steps))
     }
 }
 
@@ -67,22 +160,149 @@ impl<T: Real + FromPrimitive> ToLogSpace for RangeInclusive<T> {
 
     fn into_log_space(self, steps: usize) -> IntoLogSpace<Self::Item> {
         let (start, end) = self.into_inner();
-        let step = (end / start).powf(T::from_usize(steps - 1).unwrap().recip());
-        IntoLogSpace::new(steps, LogarithmicInterpolation { start, step })
+        let interpolate = match steps {
+            0 | 1 => LogarithmicInterpolation::new(start, start, T::one()),
+            _ => LogarithmicInterpolation::new(start, end, T::from_usize(steps - 1).unwrap()),
+        };
+        IntoLogSpace::new(steps, interpolate)
+    }
+
+    fn try_into_log_space(self, steps: usize) -> Result<IntoLogSpace<Self::Item>, Error> {
+        validate_log_range(*self.start(), *self.end(), steps)?;
+        Ok(self.into_log_space(steps))
     }
 }
 
 /// [`Iterator`] returned by [`log_space`]
+///
+/// With the `proptest` feature, `LogSpace<f32>` and `LogSpace<f64>` implement `proptest::arbitrary::Arbitrary`.
 pub type LogSpace<T> = Space<LogarithmicInterpolation<T>>;
 /// [`IntoIterator`] returned by [`ToLogSpace::into_log_space`]
 pub type IntoLogSpace<T> = IntoSpace<LogarithmicInterpolation<T>>;
 
+impl<T: Real + FromPrimitive> LogSpace<T> {
+    /// Searches the remaining values for `value`, like [`slice::binary_search`].
+    ///
+    /// Works out where the value should be from its logarithm, then checks its neighbours.
+    /// See [`LinSpace::binary_search_by_value`](crate::LinSpace::binary_search_by_value)
+    ///
+    /// ```
+    /// use iter_num_tools::log_space;
+    ///
+    /// let it = log_space(1.0..=16.0, 5);
+    /// assert_eq!(it.binary_search_by_value(4.0), Ok(2));
+    /// assert_eq!(it.binary_search_by_value(5.0), Err(3));
+    /// ```
+    pub fn binary_search_by_value(&self, value: T) -> Result<usize, usize> {
+        let LogarithmicInterpolation { start, exponent } = self.interpolate;
+        let position = fp::log2(value / start) / exponent;
+        let offset = position - T::from_usize(self.range.start).unwrap();
+        let guess = offset.ceil().to_usize().unwrap_or(0);
+        self.search_with_guess(&value, guess)
+    }
+
+    /// The sum of the remaining values, found in constant time from the formula for a geometric series.
+    ///
+    /// This is `first * (ratio^n - 1) / (ratio - 1)`, so it may round slightly differently
+    /// to adding up each value with [`Iterator::sum`].
+    ///
+    /// ```
+    /// use iter_num_tools::log_space;
+    ///
+    /// assert_eq!(log_space(1.0..=16.0, 5).sum_exact(), 31.0);
+    /// ```
+    pub fn sum_exact(&self) -> T {
+        let n = self.range.len();
+        if n == 0 {
+            return T::zero();
+        }
+        let first = self.interpolate.interpolate(self.range.start);
+        let ratio = self.interpolate.ratio();
+        let n = T::from_usize(n).unwrap();
+        if ratio == T::one() {
+            return n * first;
+        }
+        first * (fp::powf(ratio, n) - T::one()) / (ratio - T::one())
+    }
+
+    /// The product of the remaining values, found in constant time.
+    ///
+    /// This is `first^n * ratio^(n * (n - 1) / 2)`, so it may round slightly differently
+    /// to multiplying each value with [`Iterator::product`].
+    ///
+    /// ```
+    /// use iter_num_tools::log_space;
+    ///
+    /// assert_eq!(log_space(1.0..=16.0, 5).product_exact(), 1024.0);
+    /// ```
+    pub fn product_exact(&self) -> T {
+        let n = self.range.len();
+        let first = self.interpolate.interpolate(self.range.start);
+        let ratio = self.interpolate.ratio();
+        let two = T::one() + T::one();
+        let odd = n % 2 == 1;
+        let n_minus_one = T::from_usize(n.saturating_sub(1)).unwrap();
+        let n = T::from_usize(n).unwrap();
+        let product = fp::powf(first.abs(), n) * fp::powf(ratio, n * n_minus_one / two);
+        if first.is_sign_negative() && odd {
+            -product
+        } else {
+            product
+        }
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
 
     use itertools::zip_eq;
 
+    #[test]
+    fn test_binary_search_by_value() {
+        for it in [
+            log_space(1.0..=1e6, 61),
+            log_space(-1e3..=-1.0, 7),
+            log_space(2.0..=0.5, 5),
+        ] {
+            let collected: Vec<f64> = it.clone().collect();
+            let descending = collected[0] > collected[collected.len() - 1];
+            for x in collected
+                .iter()
+                .copied()
+                .chain([-2.0, 0.0, 0.7, 3.0, 5e5, 1e7])
+            {
+                let expected = match descending {
+                    true => collected.binary_search_by(|y| x.partial_cmp(y).unwrap()),
+                    false => collected.binary_search_by(|y| y.partial_cmp(&x).unwrap()),
+                };
+                assert_eq!(it.binary_search_by_value(x), expected, "{x}");
+            }
+        }
+    }
+
+    #[test]
+    fn test_sum_product_exact() {
+        let mut it = log_space(-2.0..=-512.0, 5);
+        it.next();
+        assert_eq!(
+            (it.sum_exact(), it.product_exact()),
+            (-680.0, 8.0 * 32.0 * 128.0 * 512.0)
+        );
+        it.next_back();
+        assert_eq!(it.product_exact(), -8.0 * 32.0 * 128.0);
+
+        let it = log_space(3.0..=3.0, 4);
+        assert_eq!((it.sum_exact(), it.product_exact()), (12.0, 81.0));
+
+        let it = log_space(1.0..10.0, 0);
+        assert_eq!((it.sum_exact(), it.product_exact()), (0.0, 1.0));
+
+        let it = log_space(0.5..=2.0, 101);
+        assert!((it.sum_exact() - it.clone().sum::<f64>()).abs() < 1e-9);
+        assert!((it.product_exact() - it.product::<f64>()).abs() < 1e-9);
+    }
+
     #[test]
     fn test_log_space_inclusive() {
         let it = log_space(1.0..=1000.0, 4);
@@ -107,6 +327,44 @@ mod tests {
         assert!(zip_eq(it.rev(), vec![100.0, 10.0, 1.0]).all(|(a, b)| (a - b).abs() < 1e-10))
     }
 
+    #[test]
+    fn test_log_space_many_steps() {
+        // values stay within the documented error of the exact powers, however far along the space
+        let it = log_space(1e-150..=1e150, 30_001);
+        let bound = (1e300_f64.log2() + 2.0) * f64::EPSILON;
+        for (x, i) in it.step_by(100).zip(-150..) {
+            let exact: f64 = format!("1e{i}").parse().unwrap();
+            assert!((x / exact - 1.0).abs() < bound, "{x} != {exact}");
+        }
+    }
+
+    #[test]
+    fn test_log_space_few_steps() {
+        assert!(log_space(1.0..=1000.0, 0).eq(Vec::<f64>::new()));
+        assert!(log_space(1.0..1000.0, 0).eq(Vec::<f64>::new()));
+        assert!(log_space(10.0..=1000.0, 1).eq(vec![10.0]));
+        assert!(log_space(10.0..1000.0, 1).eq(vec![10.0]));
+    }
+
+    #[test]
+    fn test_try_log_space() {
+        let it = try_log_space(-1.0..-1000.0, 3).unwrap();
+        assert!(zip_eq(it, vec![-1.0, -10.0, -100.0]).all(|(a, b)| (a - b).abs() < 1e-10));
+
+        assert_eq!(try_log_space(0.0..=10.0, 5).unwrap_err(), Error::ZeroStart);
+        assert_eq!(try_log_space(1.0..0.0, 5).unwrap_err(), Error::ZeroStart);
+        assert_eq!(
+            try_log_space(1.0..-10.0, 5).unwrap_err(),
+            Error::SignMismatch
+        );
+        assert_eq!(try_log_space(1.0..=10.0, 0).unwrap_err(), Error::ZeroSteps);
+        assert!(try_log_space(1.0..=10.0, 1).unwrap().eq(vec![1.0]));
+        assert_eq!(
+            try_log_space(1.0..f64::INFINITY, 3).unwrap_err(),
+            Error::NonFinite
+        );
+    }
+
     #[test]
     fn test_log_space_exclusive_len() {
         let mut it = log_space(1.0..=1000.0, 4);
@@ -0,0 +1,118 @@
+use core::fmt;
+use core::ops::Range;
+
+use crate::space::{Direction, Interpolate, Monotonic, Space, SpaceFmt, SpaceType};
+
+/// A function applied to each value by [`Space::map_fn`]
+///
+/// It is implemented for closures, but their type can't be named.
+/// Function pointers such as `fn(f64) -> f64` and the common functions in [`func`](crate::func)
+/// can be named, so the mapped space can be stored in a struct field or returned from a function.
+pub trait Function<T> {
+    /// The type of the transformed values
+    type Output;
+    /// Transforms a value
+    fn call(&self, x: T) -> Self::Output;
+}
+
+impl<T, U, F: Fn(T) -> U> Function<T> for F {
+    type Output = U;
+    fn call(&self, x: T) -> U {
+        self(x)
+    }
+}
+
+#[derive(Clone, Copy, Debug)]
+pub struct MapInterpolation<I, F> {
+    pub inner: I,
+    pub f: F,
+}
+
+impl<I: Interpolate, F: Function<I::Item>> Interpolate for MapInterpolation<I, F> {
+    type Item = F::Output;
+    fn interpolate(&self, x: usize) -> F::Output {
+        self.f.call(self.inner.interpolate(x))
+    }
+
+    const EXACT_FOLD: bool = I::EXACT_FOLD;
+
+    fn fold_range<B, G>(&self, range: Range<usize>, init: B, mut g: G) -> B
+    where
+        G: FnMut(B, F::Output) -> B,
+    {
+        self.inner
+            .fold_range(range, init, |acc, x| g(acc, self.f.call(x)))
+    }
+}
+
+impl<I: Interpolate, F: Function<I::Item>> Monotonic for MapInterpolation<I, F> {
+    fn direction(&self) -> Direction {
+        Direction::NonMonotonic
+    }
+}
+
+impl<I: SpaceFmt, F: Function<I::Item>> SpaceFmt for MapInterpolation<I, F> {
+    const NAME: &'static str = "Map";
+    fn fmt_params(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, ", inner: {}", I::NAME)?;
+        self.inner.fmt_params(f)
+    }
+}
+
+/// [`Iterator`] returned by [`Space::map_fn`], mapping the values of the space `S`, such as a [`LinSpace`](crate::LinSpace)
+pub type Map<S, F> = Space<MapInterpolation<<S as SpaceType>::Interpolation, F>>;
+
+impl<I: Interpolate> Space<I> {
+    /// Transforms every value with `f`, like [`Iterator::map`], but the result is still a [space](Space#adapters).
+    /// Unless `f` is a closure, its type can be written out, such as to store it in a struct.
+    ///
+    /// ```
+    /// use iter_num_tools::{lin_space, LinSpace, Map};
+    ///
+    /// struct Curve {
+    ///     samples: Map<LinSpace<f64>, fn(f64) -> f64>,
+    /// }
+    ///
+    /// let curve = Curve {
+    ///     samples: lin_space(0.0..=16.0, 5).map_fn(f64::sqrt as fn(f64) -> f64),
+    /// };
+    /// assert_eq!(curve.samples.bounds(), Some(0.0..=4.0));
+    /// assert!(curve.samples.rev().eq([4.0, 12f64.sqrt(), 8f64.sqrt(), 2.0, 0.0]));
+    /// ```
+    pub fn map_fn<F: Function<I::Item>>(self, f: F) -> Map<Self, F> {
+        self.map_interpolate(|inner| MapInterpolation { inner, f })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{check_double_ended_iter, lin_space, LinSpace};
+
+    /// Counts the digits of a number
+    #[derive(Clone, Copy)]
+    struct Digits;
+
+    impl Function<u32> for Digits {
+        type Output = usize;
+        fn call(&self, x: u32) -> usize {
+            x.to_string().len()
+        }
+    }
+
+    #[test]
+    fn test_map_fn() {
+        let it: Map<LinSpace<u32>, Digits> = lin_space(0..=1000, 5).map_fn(Digits);
+        check_double_ended_iter(it, [1, 3, 3, 3, 4]);
+
+        let mut it = lin_space(0..8, 8).map_fn((|x| x * x) as fn(u32) -> u32);
+        it.nth(2);
+        assert_eq!(it.len(), 5);
+        assert_eq!(it.clone().sum::<u32>(), 9 + 16 + 25 + 36 + 49);
+        assert_eq!(it.direction(), Direction::NonMonotonic);
+        assert_eq!(
+            format!("{it:?}"),
+            "Map { 9..=49, len: 5, inner: LinSpace, step: 1 }"
+        );
+    }
+}
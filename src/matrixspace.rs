@@ -0,0 +1,290 @@
+use core::fmt;
+use core::ops::{Range, RangeInclusive};
+
+use crate::{
+    error::Error,
+    gridspace::{checked_grid_len, grid_len},
+    linspace::{LinearInterpolation, ToLinSpace},
+    space::{Interpolate, IntoSpace, Space, SpaceFmt},
+};
+
+/// Creates a linear grid space over a range of matrices, interpolating each entry separately
+///
+/// This is a [`grid_space`](crate::grid_space) where each entry of the matrix is an axis.
+/// The entries vary in row-major order, so the first entry of the first row changes the fastest.
+///
+/// ```
+/// use iter_num_tools::matrix_space;
+///
+/// let it = matrix_space([[0.0, 0.0], [0.0, 0.0]]..=[[1.0, 0.0], [0.0, 2.0]], [[2, 1], [1, 3]]);
+/// assert!(it.eq([
+///     [[0.0, 0.0], [0.0, 0.0]], [[1.0, 0.0], [0.0, 0.0]],
+///     [[0.0, 0.0], [0.0, 1.0]], [[1.0, 0.0], [0.0, 1.0]],
+///     [[0.0, 0.0], [0.0, 2.0]], [[1.0, 0.0], [0.0, 2.0]],
+/// ]));
+///
+/// // the same number of steps for every entry
+/// let it = matrix_space([[0; 3]; 2]..[[2; 3]; 2], 2);
+/// assert_eq!(it.len(), 64);
+/// ```
+///
+/// # Panics
+///
+/// Panics if the total number of points overflows a `usize`.
+/// Use [`try_matrix_space`] to get an [`Error::Overflow`] instead.
+pub fn matrix_space<R, S, const M: usize, const N: usize>(
+    range: R,
+    steps: S,
+) -> MatrixSpace<R::Item, M, N>
+where
+    R: ToMatrixSpace<S, M, N>,
+{
+    range.into_matrix_space(steps).into_space()
+}
+
+/// Creates a linear grid space over a range of matrices,
+/// returning an error instead of producing an invalid space
+///
+/// ```
+/// use iter_num_tools::{try_matrix_space, Error};
+///
+/// assert_eq!(try_matrix_space([[0.0; 2]; 2]..[[1.0; 2]; 2], 0).unwrap_err(), Error::ZeroSteps);
+/// assert_eq!(try_matrix_space([[0.0; 2]; 2]..[[1.0; 2]; 2], usize::MAX).unwrap_err(), Error::Overflow);
+/// ```
+pub fn try_matrix_space<R, S, const M: usize, const N: usize>(
+    range: R,
+    steps: S,
+) -> Result<MatrixSpace<R::Item, M, N>, Error>
+where
+    R: ToMatrixSpace<S, M, N>,
+{
+    range
+        .try_into_matrix_space(steps)
+        .map(IntoSpace::into_space)
+}
+
+/// Helper trait for [`matrix_space`]
+pub trait ToMatrixSpace<S, const M: usize, const N: usize> {
+    /// The item of each entry of the matrices
+    type Item;
+    /// Create the matrix space
+    fn into_matrix_space(self, steps: S) -> IntoMatrixSpace<Self::Item, M, N>;
+    /// Create the matrix space, validating the range and steps
+    fn try_into_matrix_space(self, steps: S) -> Result<IntoMatrixSpace<Self::Item, M, N>, Error>;
+}
+
+/// Pairs up the start and end of each entry, along with its steps
+fn entries<T, const M: usize, const N: usize>(
+    start: [[T; M]; N],
+    end: [[T; M]; N],
+    steps: [[usize; M]; N],
+) -> [[(T, T, usize); M]; N] {
+    let mut rows = start.into_iter().zip(end).zip(steps);
+    core::array::from_fn(|_| {
+        let ((start, end), steps) = rows.next().unwrap();
+        let mut entries = start.into_iter().zip(end).zip(steps);
+        core::array::from_fn(|_| {
+            let ((start, end), steps) = entries.next().unwrap();
+            (start, end, steps)
+        })
+    })
+}
+
+fn matrix<T, const M: usize, const N: usize>(
+    entries: [[IntoSpace<LinearInterpolation<T>>; M]; N],
+) -> IntoMatrixSpace<T, M, N> {
+    let len = grid_len(entries.iter().flatten().map(|entry| entry.len));
+    IntoMatrixSpace::new(len, MatrixInterpolation(entries))
+}
+
+fn try_matrix<R: ToLinSpace, const M: usize, const N: usize>(
+    ranges: [[(R, usize); M]; N],
+) -> Result<IntoMatrixSpace<R::Item, M, N>, Error> {
+    let entries = ranges.map(|row| row.map(|(range, steps)| range.try_into_lin_space(steps)));
+    if let Some(&Err(err)) = entries.iter().flatten().find(|entry| entry.is_err()) {
+        return Err(err);
+    }
+    let entries = entries.map(|row| {
+        row.map(|entry| match entry {
+            Ok(entry) => entry,
+            Err(_) => unreachable!(),
+        })
+    });
+    let len =
+        checked_grid_len(entries.iter().flatten().map(|entry| entry.len)).ok_or(Error::Overflow)?;
+    Ok(IntoMatrixSpace::new(len, MatrixInterpolation(entries)))
+}
+
+impl<T, const M: usize, const N: usize> ToMatrixSpace<[[usize; M]; N], M, N> for Range<[[T; M]; N]>
+where
+    Range<T>: ToLinSpace,
+{
+    type Item = <Range<T> as ToLinSpace>::Item;
+
+    fn into_matrix_space(self, steps: [[usize; M]; N]) -> IntoMatrixSpace<Self::Item, M, N> {
+        let entries = entries(self.start, self.end, steps);
+        matrix(entries.map(|row| row.map(|(start, end, steps)| (start..end).into_lin_space(steps))))
+    }
+
+    fn try_into_matrix_space(
+        self,
+        steps: [[usize; M]; N],
+    ) -> Result<IntoMatrixSpace<Self::Item, M, N>, Error> {
+        let entries = entries(self.start, self.end, steps);
+        try_matrix(entries.map(|row| row.map(|(start, end, steps)| (start..end, steps))))
+    }
+}
+
+impl<T, const M: usize, const N: usize> ToMatrixSpace<[[usize; M]; N], M, N>
+    for RangeInclusive<[[T; M]; N]>
+where
+    RangeInclusive<T>: ToLinSpace,
+{
+    type Item = <RangeInclusive<T> as ToLinSpace>::Item;
+
+    fn into_matrix_space(self, steps: [[usize; M]; N]) -> IntoMatrixSpace<Self::Item, M, N> {
+        let (start, end) = self.into_inner();
+        let entries = entries(start, end, steps);
+        matrix(
+            entries.map(|row| row.map(|(start, end, steps)| (start..=end).into_lin_space(steps))),
+        )
+    }
+
+    fn try_into_matrix_space(
+        self,
+        steps: [[usize; M]; N],
+    ) -> Result<IntoMatrixSpace<Self::Item, M, N>, Error> {
+        let (start, end) = self.into_inner();
+        let entries = entries(start, end, steps);
+        try_matrix(entries.map(|row| row.map(|(start, end, steps)| (start..=end, steps))))
+    }
+}
+
+impl<T, const M: usize, const N: usize> ToMatrixSpace<usize, M, N> for Range<[[T; M]; N]>
+where
+    Range<T>: ToLinSpace,
+{
+    type Item = <Range<T> as ToLinSpace>::Item;
+
+    fn into_matrix_space(self, steps: usize) -> IntoMatrixSpace<Self::Item, M, N> {
+        self.into_matrix_space([[steps; M]; N])
+    }
+
+    fn try_into_matrix_space(
+        self,
+        steps: usize,
+    ) -> Result<IntoMatrixSpace<Self::Item, M, N>, Error> {
+        self.try_into_matrix_space([[steps; M]; N])
+    }
+}
+
+impl<T, const M: usize, const N: usize> ToMatrixSpace<usize, M, N> for RangeInclusive<[[T; M]; N]>
+where
+    RangeInclusive<T>: ToLinSpace,
+{
+    type Item = <RangeInclusive<T> as ToLinSpace>::Item;
+
+    fn into_matrix_space(self, steps: usize) -> IntoMatrixSpace<Self::Item, M, N> {
+        self.into_matrix_space([[steps; M]; N])
+    }
+
+    fn try_into_matrix_space(
+        self,
+        steps: usize,
+    ) -> Result<IntoMatrixSpace<Self::Item, M, N>, Error> {
+        self.try_into_matrix_space([[steps; M]; N])
+    }
+}
+
+#[derive(Clone, Copy, Debug)]
+pub struct MatrixInterpolation<T, const M: usize, const N: usize>(
+    pub [[IntoSpace<LinearInterpolation<T>>; M]; N],
+);
+
+impl<T, const M: usize, const N: usize> Interpolate for MatrixInterpolation<T, M, N>
+where
+    LinearInterpolation<T>: Interpolate<Item = T>,
+{
+    type Item = [[T; M]; N];
+    fn interpolate(&self, mut x: usize) -> [[T; M]; N] {
+        self.0.each_ref().map(|row| {
+            row.each_ref().map(|space| {
+                let z = x % space.len;
+                x /= space.len;
+                space.interpolate.interpolate(z)
+            })
+        })
+    }
+}
+
+impl<T, const M: usize, const N: usize> SpaceFmt for MatrixInterpolation<T, M, N>
+where
+    LinearInterpolation<T>: Interpolate<Item = T>,
+{
+    const NAME: &'static str = "MatrixSpace";
+    fn fmt_params(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let shape = self
+            .0
+            .each_ref()
+            .map(|row| row.each_ref().map(|space| space.len));
+        write!(f, ", shape: {shape:?}")
+    }
+}
+
+/// [`Iterator`] returned by [`matrix_space`]
+pub type MatrixSpace<T, const M: usize, const N: usize> = Space<MatrixInterpolation<T, M, N>>;
+
+/// [`IntoIterator`] returned by [`ToMatrixSpace::into_matrix_space`]
+pub type IntoMatrixSpace<T, const M: usize, const N: usize> =
+    IntoSpace<MatrixInterpolation<T, M, N>>;
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::check_double_ended_iter;
+
+    #[test]
+    fn test_matrix_space() {
+        let it = matrix_space([[0, 0]]..[[2, 6]], [[2, 3]]);
+        check_double_ended_iter(
+            it,
+            [[[0, 0]], [[1, 0]], [[0, 2]], [[1, 2]], [[0, 4]], [[1, 4]]],
+        );
+
+        let it = matrix_space([[0.0], [0.0]]..=[[1.0], [1.0]], 2);
+        check_double_ended_iter(
+            it,
+            [
+                [[0.0], [0.0]],
+                [[1.0], [0.0]],
+                [[0.0], [1.0]],
+                [[1.0], [1.0]],
+            ],
+        );
+    }
+
+    #[test]
+    fn test_matrix_space_fmt() {
+        let it = matrix_space([[0.0, 0.0]; 2]..=[[1.0, 2.0]; 2], [[2, 3], [1, 1]]);
+        assert_eq!(it.len(), 6);
+        assert_eq!(
+            format!("{it:?}"),
+            "MatrixSpace { [[0.0, 0.0], [0.0, 0.0]]..=[[1.0, 2.0], [0.0, 0.0]], len: 6, shape: [[2, 3], [1, 1]] }"
+        );
+    }
+
+    #[test]
+    fn test_try_matrix_space() {
+        assert_eq!(
+            try_matrix_space([[0.0; 2]; 2]..=[[1.0; 2]; 2], [[3, 3], [3, 0]]).unwrap_err(),
+            Error::ZeroSteps
+        );
+        let it = try_matrix_space([[0.0; 2]; 2]..=[[1.0; 2]; 2], [[3, 3], [3, 1]]).unwrap();
+        assert_eq!(it.len(), 27);
+        assert_eq!(
+            try_matrix_space([[0.0; 2]; 2]..=[[f64::NAN; 2]; 2], 3).unwrap_err(),
+            Error::NonFinite
+        );
+        assert!(try_matrix_space([[0.0; 2]; 2]..=[[1.0; 2]; 2], 3).is_ok());
+    }
+}
@@ -0,0 +1,260 @@
+use core::fmt;
+use core::ops::Range;
+use num_traits::{real::Real, FromPrimitive};
+
+use crate::{
+    fp,
+    linspace::{LinearInterpolation, ToLinSpace},
+    space::{Interpolate, IntoSpace, Space, SpaceFmt},
+};
+
+/// How many samples the rotation fast path steps through before recomputing `sin_cos` exactly,
+/// so the rounding errors from each rotation can't build up
+const RESYNC: usize = 64;
+
+/// Creates a space over the sine of evenly spaced phases, in radians
+///
+/// Each value is computed directly with `sin`, except when folding over the whole space
+/// (such as with [`sum`](Iterator::sum), [`for_each`](Iterator::for_each) or [`Space::fill`]),
+/// where each value is found by rotating the last by the phase step, which needs only a few multiplications.
+/// The rotation is restarted from `sin` every 64 values, so a folded value at index `i`
+/// is within `4 * ε * (64 + |start| + |i * step|)` of the value from `sin`,
+/// where `start` and `step` are the first phase and the step between phases,
+/// and `ε` is the [`epsilon`](Real::epsilon) of the float type.
+///
+/// To sample an oscillator of frequency `f` at sample rate `r` for `n` samples,
+/// use the phases `0.0..τ * f * n / r` with `n` steps.
+///
+/// ```
+/// use iter_num_tools::sin_space;
+/// use std::f64::consts::TAU;
+///
+/// let it = sin_space(0.0..TAU, 4);
+/// let expected = [0.0, 1.0, 0.0, -1.0_f64];
+/// assert!(it.zip(expected).all(|(x, y)| (x - y).abs() < 1e-15));
+/// ```
+pub fn sin_space<R>(phases: R, steps: usize) -> SinSpace<R::Item>
+where
+    R: ToLinSpace,
+    R::Item: Real + FromPrimitive,
+{
+    let IntoSpace { interpolate, len } = phases.into_lin_space(steps);
+    Space::new(len, SinInterpolation { phase: interpolate })
+}
+
+/// Creates a space over the cosine of evenly spaced phases, in radians
+///
+/// This is computed in the same way as [`sin_space`].
+///
+/// ```
+/// use iter_num_tools::cos_space;
+/// use std::f64::consts::TAU;
+///
+/// let it = cos_space(0.0..TAU, 4);
+/// let expected = [1.0, 0.0, -1.0, 0.0_f64];
+/// assert!(it.zip(expected).all(|(x, y)| (x - y).abs() < 1e-15));
+/// ```
+pub fn cos_space<R>(phases: R, steps: usize) -> CosSpace<R::Item>
+where
+    R: ToLinSpace,
+    R::Item: Real + FromPrimitive,
+{
+    let IntoSpace { interpolate, len } = phases.into_lin_space(steps);
+    Space::new(len, CosInterpolation { phase: interpolate })
+}
+
+/// Folds `(sin, cos)` of the phases in the range, stepping between them by rotation
+fn fold_sin_cos<T, B, F>(
+    phase: &LinearInterpolation<T>,
+    range: Range<usize>,
+    init: B,
+    mut f: F,
+) -> B
+where
+    T: Real + FromPrimitive,
+    F: FnMut(B, (T, T)) -> B,
+{
+    let (step_sin, step_cos) = fp::sin_cos(phase.step);
+    let mut acc = init;
+    let mut i = range.start;
+    while i < range.end {
+        let block = (range.end - i).min(RESYNC);
+        let (mut sin, mut cos) = fp::sin_cos(phase.interpolate(i));
+        for _ in 0..block {
+            acc = f(acc, (sin, cos));
+            (sin, cos) = (
+                sin * step_cos + cos * step_sin,
+                cos * step_cos - sin * step_sin,
+            );
+        }
+        i += block;
+    }
+    acc
+}
+
+/// Whether `folded` is within the error [`fold_sin_cos`] can pick up at index `x` of `direct`
+fn rotation_agrees<T: Real + FromPrimitive>(
+    phase: &LinearInterpolation<T>,
+    x: usize,
+    folded: T,
+    direct: T,
+) -> bool {
+    let reach = T::from_usize(RESYNC).unwrap()
+        + phase.start.abs()
+        + (phase.step * T::from_usize(x).unwrap()).abs();
+    let tolerance = T::from_u8(4).unwrap() * T::epsilon() * reach;
+    (folded - direct).abs() <= tolerance
+}
+
+#[derive(Clone, Copy, Debug)]
+pub struct SinInterpolation<T> {
+    pub phase: LinearInterpolation<T>,
+}
+
+impl<T: Real + FromPrimitive> Interpolate for SinInterpolation<T> {
+    type Item = T;
+    fn interpolate(&self, x: usize) -> T {
+        fp::sin_cos(self.phase.interpolate(x)).0
+    }
+
+    const EXACT_FOLD: bool = false;
+
+    fn fold_range<B, F>(&self, range: Range<usize>, init: B, mut f: F) -> B
+    where
+        F: FnMut(B, T) -> B,
+    {
+        fold_sin_cos(&self.phase, range, init, |acc, (sin, _)| f(acc, sin))
+    }
+
+    fn fold_agrees(&self, x: usize, folded: &T) -> bool {
+        rotation_agrees(&self.phase, x, *folded, self.interpolate(x))
+    }
+}
+
+impl<T: Real + FromPrimitive + fmt::Debug> SpaceFmt for SinInterpolation<T> {
+    const NAME: &'static str = "SinSpace";
+    fn fmt_params(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            ", phase: {:?}, step: {:?}",
+            self.phase.start, self.phase.step
+        )
+    }
+}
+
+#[derive(Clone, Copy, Debug)]
+pub struct CosInterpolation<T> {
+    pub phase: LinearInterpolation<T>,
+}
+
+impl<T: Real + FromPrimitive> Interpolate for CosInterpolation<T> {
+    type Item = T;
+    fn interpolate(&self, x: usize) -> T {
+        fp::sin_cos(self.phase.interpolate(x)).1
+    }
+
+    const EXACT_FOLD: bool = false;
+
+    fn fold_range<B, F>(&self, range: Range<usize>, init: B, mut f: F) -> B
+    where
+        F: FnMut(B, T) -> B,
+    {
+        fold_sin_cos(&self.phase, range, init, |acc, (_, cos)| f(acc, cos))
+    }
+
+    fn fold_agrees(&self, x: usize, folded: &T) -> bool {
+        rotation_agrees(&self.phase, x, *folded, self.interpolate(x))
+    }
+}
+
+impl<T: Real + FromPrimitive + fmt::Debug> SpaceFmt for CosInterpolation<T> {
+    const NAME: &'static str = "CosSpace";
+    fn fmt_params(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            ", phase: {:?}, step: {:?}",
+            self.phase.start, self.phase.step
+        )
+    }
+}
+
+/// [`Iterator`] returned by [`sin_space`]
+pub type SinSpace<T> = Space<SinInterpolation<T>>;
+
+/// [`Iterator`] returned by [`cos_space`]
+pub type CosSpace<T> = Space<CosInterpolation<T>>;
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use core::f64::consts::{FRAC_PI_2, PI, TAU};
+
+    fn assert_close(left: impl IntoIterator<Item = f64>, right: impl IntoIterator<Item = f64>) {
+        let (left, right): (Vec<_>, Vec<_>) =
+            (left.into_iter().collect(), right.into_iter().collect());
+        assert_eq!(left.len(), right.len());
+        for (a, b) in left.into_iter().zip(right) {
+            assert!((a - b).abs() < 1e-12, "{a} != {b}");
+        }
+    }
+
+    #[test]
+    fn test_sin_cos_space() {
+        assert_close(sin_space(0.0..=PI, 3), [0.0, 1.0, 0.0]);
+        assert_close(cos_space(0.0..=PI, 3), [1.0, 0.0, -1.0]);
+        assert_close(sin_space(0.0..=PI, 3).rev(), [0.0, 1.0, 0.0]);
+        assert_close(cos_space(FRAC_PI_2..=-FRAC_PI_2, 3).rev(), [0.0, 1.0, 0.0]);
+        assert_eq!(sin_space(0.0..TAU, 0).count(), 0);
+    }
+
+    #[test]
+    fn test_sin_cos_space_fold() {
+        // long enough to cross several resyncs, starting partway through
+        let mut it = sin_space(1.0..1000.0, 10_000);
+        it.nth(10);
+        it.next_back();
+        let direct: Vec<_> = it.clone().rev().collect();
+        let mut folded = Vec::new();
+        it.for_each(|x| folded.push(x));
+        folded.reverse();
+        assert_close(folded, direct);
+
+        let it = cos_space(-3.0..=3.0, 500);
+        let mut buf = [0.0; 500];
+        assert_eq!(it.fill_slice(&mut buf), 500);
+        assert_close(buf.into_iter().rev(), it.rev());
+    }
+
+    #[test]
+    fn test_sin_cos_space_fold_error() {
+        // the rotation stays within its documented bound of sin and cos, but isn't exact
+        let it = sin_space(0.0..=100.0, 1000);
+        let mut x = 0;
+        let mut exact = true;
+        it.clone().for_each(|folded| {
+            let direct = it.interpolate.interpolate(x);
+            assert!(
+                it.interpolate.fold_agrees(x, &folded),
+                "{folded} != {direct}"
+            );
+            exact &= folded == direct;
+            x += 1;
+        });
+        assert!(!exact);
+
+        for it in [cos_space(-1e5..=1e5, 100_000), cos_space(3.0..-2.0, 777)] {
+            it.verify_invariants();
+        }
+        sin_space(1e3..=1e3 + TAU, 1000).verify_invariants();
+        cos_space(0.0_f32..=1000.0, 10_000).verify_invariants();
+    }
+
+    #[test]
+    fn test_sin_space_fmt() {
+        let it = sin_space(0.0..=1.0, 3);
+        assert_eq!(
+            format!("{it:?}"),
+            "SinSpace { 0.0..=0.8414709848078965, len: 3, phase: 0.0, step: 0.5 }"
+        );
+    }
+}
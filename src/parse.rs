@@ -0,0 +1,345 @@
+use core::fmt;
+use core::ops::Range;
+use core::str::FromStr;
+use num_traits::{real::Real, FromPrimitive};
+
+use crate::{
+    arange::ArangeConfig,
+    axis::AxisRange,
+    error::Error,
+    linspace::{IntoLinSpace, LinearInterpolation, ToLinSpace},
+    logspace::{IntoLogSpace, LogarithmicInterpolation, ToLogSpace},
+    space::{Direction, Interpolate, Monotonic, Space, SpaceFmt},
+};
+
+/// Parses a linear space from a sweep specification, such as a command line argument.
+///
+/// Either `start:step:end`, which steps from `start` up to and including `end` if it lands on it,
+/// or a range and a number of steps, like `0..=1 x5`, optionally followed by `lin`.
+///
+/// ```
+/// use iter_num_tools::{Error, IntoLinSpace};
+///
+/// let spec: IntoLinSpace<f64> = "0.0:0.25:1.0".parse().unwrap();
+/// assert!(spec.into_space().eq([0.0, 0.25, 0.5, 0.75, 1.0]));
+///
+/// let spec: IntoLinSpace<f64> = "0..1 x4".parse().unwrap();
+/// assert!(spec.into_space().eq([0.0, 0.25, 0.5, 0.75]));
+///
+/// assert_eq!("0..1 x0".parse::<IntoLinSpace<f64>>().unwrap_err(), Error::ZeroSteps);
+/// assert_eq!("0..1".parse::<IntoLinSpace<f64>>().unwrap_err(), Error::InvalidSpec);
+/// ```
+impl<T: Real + FromPrimitive + FromStr> FromStr for IntoLinSpace<T> {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Self, Error> {
+        if s.contains(':') {
+            let mut parts = s.split(':').map(|x| parse::<T>(x.trim()));
+            let (Some(start), Some(step), Some(end), None) =
+                (parts.next(), parts.next(), parts.next(), parts.next())
+            else {
+                return Err(Error::InvalidSpec);
+            };
+            // keep the end when rounding leaves it just past a whole number of steps
+            let epsilon = -T::epsilon().sqrt();
+            return ArangeConfig { epsilon }.try_into_arange(start?..end?, step?);
+        }
+        match parse_steps(s)? {
+            (range, steps, None | Some("lin")) => range.try_into_lin_space(steps),
+            _ => Err(Error::InvalidSpec),
+        }
+    }
+}
+
+/// Parses a logarithmic space from a sweep specification, such as a command line argument.
+///
+/// A range and a number of steps, like `1..=1000 x4`, optionally followed by `log`.
+///
+/// ```
+/// use iter_num_tools::IntoLogSpace;
+///
+/// let spec: IntoLogSpace<f64> = "1..=16 x5 log".parse().unwrap();
+/// assert!(spec.into_space().eq([1.0, 2.0, 4.0, 8.0, 16.0]));
+/// ```
+impl<T: Real + FromPrimitive + FromStr> FromStr for IntoLogSpace<T> {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Self, Error> {
+        match parse_steps(s)? {
+            (AxisRange::Inclusive(range), steps, None | Some("log")) => {
+                range.try_into_log_space(steps)
+            }
+            (AxisRange::Exclusive(range), steps, None | Some("log")) => {
+                range.try_into_log_space(steps)
+            }
+            _ => Err(Error::InvalidSpec),
+        }
+    }
+}
+
+/// A linear or logarithmic space parsed from a sweep specification, such as a command line argument.
+///
+/// Specifications ending in `log` are parsed as an [`IntoLogSpace`], and anything else as an [`IntoLinSpace`],
+/// so this accepts `0:0.1:1`, `0..=1 x11` and `1..=1000 x4 log`.
+///
+/// ```
+/// use iter_num_tools::SweepSpec;
+///
+/// let spec: SweepSpec<f64> = "0:0.5:2".parse().unwrap();
+/// assert!(spec.into_space().eq([0.0, 0.5, 1.0, 1.5, 2.0]));
+///
+/// let spec: SweepSpec<f64> = "1..=16 x5 log".parse().unwrap();
+/// assert!(matches!(spec, SweepSpec::Logarithmic(_)));
+/// assert!(spec.into_space().eq([1.0, 2.0, 4.0, 8.0, 16.0]));
+/// ```
+///
+/// With the `clap` feature, this can be used as the type of a `clap` argument, such as `--temps <SWEEP>`.
+#[derive(Clone, Copy, Debug)]
+pub enum SweepSpec<T> {
+    /// A linear space, like `0..=1 x11`, or an arange, like `0:0.1:1`
+    Linear(IntoLinSpace<T>),
+    /// A logarithmic space, like `1..=1000 x4 log`
+    Logarithmic(IntoLogSpace<T>),
+}
+
+impl<T: Real + FromPrimitive + FromStr> FromStr for SweepSpec<T> {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Self, Error> {
+        match s.split_whitespace().last() {
+            Some("log") => s.parse().map(SweepSpec::Logarithmic),
+            _ => s.parse().map(SweepSpec::Linear),
+        }
+    }
+}
+
+impl<T> SweepSpec<T> {
+    /// The number of values in the space
+    pub fn len(&self) -> usize {
+        match self {
+            SweepSpec::Linear(space) => space.len,
+            SweepSpec::Logarithmic(space) => space.len,
+        }
+    }
+
+    /// Whether the space has no values
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// Creates the space, which yields the values of whichever kind of space was parsed
+    pub fn into_space(self) -> SweepSpace<T> {
+        match self {
+            SweepSpec::Linear(space) => {
+                Space::new(space.len, SweepInterpolation::Linear(space.interpolate))
+            }
+            SweepSpec::Logarithmic(space) => Space::new(
+                space.len,
+                SweepInterpolation::Logarithmic(space.interpolate),
+            ),
+        }
+    }
+}
+
+impl<T: Real + FromPrimitive> IntoIterator for SweepSpec<T> {
+    type Item = T;
+    type IntoIter = SweepSpace<T>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.into_space()
+    }
+}
+
+#[cfg(feature = "clap")]
+impl<T> clap::builder::ValueParserFactory for SweepSpec<T>
+where
+    T: Real + FromPrimitive + FromStr + Send + Sync + 'static,
+{
+    type Parser = fn(&str) -> Result<Self, Error>;
+
+    fn value_parser() -> Self::Parser {
+        str::parse
+    }
+}
+
+#[derive(Clone, Copy, Debug)]
+pub enum SweepInterpolation<T> {
+    Linear(LinearInterpolation<T>),
+    Logarithmic(LogarithmicInterpolation<T>),
+}
+
+impl<T: Real + FromPrimitive> Interpolate for SweepInterpolation<T> {
+    type Item = T;
+    fn interpolate(&self, x: usize) -> T {
+        match self {
+            SweepInterpolation::Linear(interp) => interp.interpolate(x),
+            SweepInterpolation::Logarithmic(interp) => interp.interpolate(x),
+        }
+    }
+
+    fn fold_range<B, F>(&self, range: Range<usize>, init: B, f: F) -> B
+    where
+        F: FnMut(B, T) -> B,
+    {
+        match self {
+            SweepInterpolation::Linear(interp) => interp.fold_range(range, init, f),
+            SweepInterpolation::Logarithmic(interp) => interp.fold_range(range, init, f),
+        }
+    }
+}
+
+impl<T: Real + FromPrimitive> Monotonic for SweepInterpolation<T> {
+    fn direction(&self) -> Direction {
+        match self {
+            SweepInterpolation::Linear(interp) => interp.direction(),
+            SweepInterpolation::Logarithmic(interp) => interp.direction(),
+        }
+    }
+}
+
+impl<T: Real + FromPrimitive + fmt::Debug> SpaceFmt for SweepInterpolation<T> {
+    const NAME: &'static str = "SweepSpace";
+    fn fmt_params(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            SweepInterpolation::Linear(interp) => interp.fmt_params(f),
+            SweepInterpolation::Logarithmic(interp) => interp.fmt_params(f),
+        }
+    }
+}
+
+/// [`Iterator`] returned by [`SweepSpec::into_space`]
+pub type SweepSpace<T> = Space<SweepInterpolation<T>>;
+
+fn parse<T: FromStr>(s: &str) -> Result<T, Error> {
+    s.parse().map_err(|_| Error::InvalidSpec)
+}
+
+/// Splits a specification like `1..=100 x50 log` into the range, the number of steps and the kind of space
+fn parse_steps<T: FromStr>(s: &str) -> Result<(AxisRange<T>, usize, Option<&str>), Error> {
+    let mut parts = s.split_whitespace();
+    let (range, steps, kind) = (parts.next(), parts.next(), parts.next());
+    let (Some(range), Some(steps), None) = (range, steps, parts.next()) else {
+        return Err(Error::InvalidSpec);
+    };
+    let steps = parse(steps.strip_prefix('x').ok_or(Error::InvalidSpec)?)?;
+    let range = match range.split_once("..=") {
+        Some((start, end)) => AxisRange::Inclusive(parse(start)?..=parse(end)?),
+        None => {
+            let (start, end) = range.split_once("..").ok_or(Error::InvalidSpec)?;
+            AxisRange::Exclusive(parse(start)?..parse(end)?)
+        }
+    };
+    Ok((range, steps, kind))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::check_double_ended_iter;
+
+    #[test]
+    fn test_parse_arange() {
+        let spec: IntoLinSpace<f64> = "1:-0.5:-1".parse().unwrap();
+        check_double_ended_iter(spec.into_space(), [1.0, 0.5, 0.0, -0.5, -1.0]);
+
+        // 0.1 doesn't divide 1 exactly, but the end is still kept
+        let spec: IntoLinSpace<f64> = " 0 : 0.1 : 1 ".parse().unwrap();
+        assert_eq!(spec.len, 11);
+        let spec: IntoLinSpace<f32> = "0:0.3:1".parse().unwrap();
+        assert_eq!(spec.len, 4);
+
+        let parse = |s: &str| s.parse::<IntoLinSpace<f64>>().unwrap_err();
+        assert_eq!(parse("0:1"), Error::InvalidSpec);
+        assert_eq!(parse("0:1:2:3"), Error::InvalidSpec);
+        assert_eq!(parse("0:a:1"), Error::InvalidSpec);
+        assert_eq!(parse("0:0:1"), Error::ZeroSteps);
+        assert_eq!(parse("0:-1:1"), Error::SignMismatch);
+    }
+
+    #[test]
+    fn test_parse_lin_space() {
+        let spec: IntoLinSpace<f64> = "-1..=1 x5 lin".parse().unwrap();
+        check_double_ended_iter(spec.into_space(), [-1.0, -0.5, 0.0, 0.5, 1.0]);
+        let spec: IntoLinSpace<f32> = "1e1..2e1   x2".parse().unwrap();
+        check_double_ended_iter(spec.into_space(), [10.0, 15.0]);
+
+        let parse = |s: &str| s.parse::<IntoLinSpace<f64>>().unwrap_err();
+        assert_eq!(parse("0..=1 x5 log"), Error::InvalidSpec);
+        assert_eq!(parse("0..=1 5"), Error::InvalidSpec);
+        assert_eq!(parse("0..=1 x5 lin extra"), Error::InvalidSpec);
+        assert_eq!(parse("0-1 x5"), Error::InvalidSpec);
+        assert_eq!(parse("0..=inf x5"), Error::NonFinite);
+
+        // a single step is just the start
+        let spec: IntoLinSpace<f64> = "0..=1 x1".parse().unwrap();
+        check_double_ended_iter(spec.into_space(), [0.0]);
+    }
+
+    #[test]
+    fn test_parse_log_space() {
+        let spec: IntoLogSpace<f64> = "1e3..1 x3".parse().unwrap();
+        assert_eq!(spec.len, 3);
+        assert!(spec
+            .into_space()
+            .zip([1000.0, 100.0, 10.0])
+            .all(|(a, b)| (a - b).abs() < 1e-10));
+
+        let parse = |s: &str| s.parse::<IntoLogSpace<f64>>().unwrap_err();
+        assert_eq!(parse("1:2:3"), Error::InvalidSpec);
+        assert_eq!(parse("1..=16 x5 lin"), Error::InvalidSpec);
+        assert_eq!(parse("0..=16 x5 log"), Error::ZeroStart);
+        assert_eq!(parse("-1..=16 x5"), Error::SignMismatch);
+    }
+
+    #[test]
+    fn test_sweep_spec() {
+        let spec: SweepSpec<f64> = "2..0 x4 lin".parse().unwrap();
+        assert!(matches!(spec, SweepSpec::Linear(_)));
+        assert_eq!(spec.len(), 4);
+        check_double_ended_iter(spec.into_iter(), [2.0, 1.5, 1.0, 0.5]);
+
+        let it = "1..=4 x3 log"
+            .parse::<SweepSpec<f64>>()
+            .unwrap()
+            .into_space();
+        assert_eq!(it.direction(), Direction::Increasing);
+        assert_eq!(
+            format!("{it:?}"),
+            "SweepSpace { 1.0..=4.0, len: 3, ratio: 2.0 }"
+        );
+
+        assert!(!"0..1 x1".parse::<SweepSpec<f32>>().unwrap().is_empty());
+        let parse = |s: &str| s.parse::<SweepSpec<f64>>().unwrap_err();
+        assert_eq!(parse("0:1:2 log"), Error::InvalidSpec);
+        assert_eq!(parse("0..=1 x5 exp"), Error::InvalidSpec);
+        assert_eq!(parse(""), Error::InvalidSpec);
+    }
+
+    #[cfg(feature = "clap")]
+    #[test]
+    fn test_sweep_spec_clap() {
+        use clap::{value_parser, Arg, Command};
+
+        let cmd = Command::new("anneal").arg(
+            Arg::new("temps")
+                .long("temps")
+                .value_parser(value_parser!(SweepSpec<f64>)),
+        );
+
+        let matches = cmd
+            .clone()
+            .try_get_matches_from(["anneal", "--temps", "1..=100 x3 log"])
+            .unwrap();
+        let temps = *matches.get_one::<SweepSpec<f64>>("temps").unwrap();
+        assert!(temps
+            .into_space()
+            .zip([1.0, 10.0, 100.0])
+            .all(|(a, b)| (a - b).abs() < 1e-10));
+
+        let err = cmd
+            .try_get_matches_from(["anneal", "--temps", "1..100"])
+            .unwrap_err();
+        let source = std::error::Error::source(&err).unwrap();
+        assert_eq!(source.downcast_ref(), Some(&Error::InvalidSpec));
+    }
+}
@@ -0,0 +1,101 @@
+use core::fmt;
+use num_traits::Zero;
+
+use crate::{
+    adapters::{chain_spaces, ChainInterpolation},
+    linspace::{LinearInterpolation, ToLinSpace},
+    space::{Interpolate, Space, SpaceFmt},
+};
+
+/// Starts building a space out of several linear segments
+///
+/// ```
+/// use iter_num_tools::piecewise;
+///
+/// let it = piecewise()
+///     .segment(0.0..=1.0, 5)
+///     .segment(1.0..=4.0, 4)
+///     .build();
+///
+/// // the shared 1.0 endpoint is only included once
+/// assert_eq!(it.bounds(), Some(0.0..=4.0));
+/// assert!(it.eq([0.0, 0.25, 0.5, 0.75, 1.0, 2.0, 3.0, 4.0]));
+/// ```
+pub fn piecewise<T: Zero + Clone>() -> Piecewise<LinearInterpolation<T>> {
+    let empty = LinearInterpolation {
+        start: T::zero(),
+        step: T::zero(),
+    };
+    Piecewise(Space::new(0, empty))
+}
+
+/// Builder returned by [`piecewise`]
+#[derive(Clone)]
+pub struct Piecewise<I>(Space<I>);
+
+impl<I: SpaceFmt> fmt::Debug for Piecewise<I>
+where
+    I::Item: fmt::Debug,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_tuple("Piecewise").field(&self.0).finish()
+    }
+}
+
+impl<I: Interpolate> Piecewise<I>
+where
+    I::Item: PartialEq,
+{
+    /// Appends a linear segment with a fixed number of steps, as in [`lin_space`](crate::lin_space).
+    ///
+    /// If the segment starts on the same value the previous segment ended on,
+    /// that value is not repeated.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the combined length overflows a `usize`
+    pub fn segment<R>(
+        self,
+        range: R,
+        steps: usize,
+    ) -> Piecewise<ChainInterpolation<I, LinearInterpolation<I::Item>>>
+    where
+        R: ToLinSpace<Item = I::Item>,
+        LinearInterpolation<I::Item>: Interpolate<Item = I::Item>,
+    {
+        let mut segment = range.into_lin_space(steps).into_space();
+        if let (Some(prev), Some(next)) = (self.0.bounds(), segment.bounds()) {
+            if prev.end() == next.start() {
+                segment.next();
+            }
+        }
+        Piecewise(chain_spaces(self.0, segment))
+    }
+
+    /// Finishes building the space
+    pub fn build(self) -> Space<I> {
+        self.0
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::check_double_ended_iter;
+
+    #[test]
+    fn test_piecewise() {
+        let it = piecewise()
+            .segment(0.0..1.0, 2)
+            .segment(1.0..=2.0, 3)
+            .segment(4.0..=5.0, 2)
+            .build();
+        check_double_ended_iter(it, [0.0, 0.5, 1.0, 1.5, 2.0, 4.0, 5.0]);
+
+        let it = piecewise().segment(0..=4, 3).segment(4..=4, 1).build();
+        check_double_ended_iter(it, [0, 2, 4]);
+
+        let it = piecewise::<f64>().build();
+        assert_eq!(it.bounds(), None);
+    }
+}
@@ -0,0 +1,174 @@
+use core::fmt;
+use core::ops::Range;
+use num_traits::{FromPrimitive, Num};
+
+use crate::{
+    forward_diff::{abs_diff, epsilon, fold_differences, poly_differences, RESYNC},
+    linspace::{LinearInterpolation, ToLinSpace},
+    space::{Interpolate, IntoSpace, Space, SpaceFmt},
+};
+
+/// Creates a space over the values of a polynomial, sampled at the points of a linear space
+///
+/// The coefficients are in increasing order of degree, so `[c0, c1, c2]` is `c0 + c1 x + c2 x²`.
+/// Each value is evaluated directly with Horner's method, except when folding over the whole space
+/// (such as with [`sum`](Iterator::sum) or [`for_each`](Iterator::for_each)),
+/// where each value is stepped to from the last by adding up its forward differences.
+/// These are recomputed from scratch every 64 values, and the last value is always evaluated directly,
+/// so a fold ends on exactly the same value as [`next_back`](DoubleEndedIterator::next_back).
+/// In between, a folded value at `x` is within `64 * N * ε * Σ |c_k| (|x| + 64 |step|)^k` of the value from Horner's method,
+/// where `ε` is the gap between 1 and the next float, or zero for integers, whose folds are exact.
+/// See [`Space::compensated`] to keep rounding errors from building up in between.
+///
+/// ```
+/// use iter_num_tools::poly_space;
+///
+/// // 1 - 2x + x²
+/// let it = poly_space([1.0, -2.0, 1.0], 0.0..=2.0, 5);
+/// assert!(it.eq([1.0, 0.25, 0.0, 0.25, 1.0]));
+/// ```
+pub fn poly_space<R, const N: usize>(
+    coeffs: [R::Item; N],
+    range: R,
+    steps: usize,
+) -> PolySpace<R::Item, N>
+where
+    R: ToLinSpace,
+{
+    let IntoSpace { interpolate, len } = range.into_lin_space(steps);
+    Space::new(
+        len,
+        PolyInterpolation {
+            x: interpolate,
+            coeffs,
+        },
+    )
+}
+
+#[derive(Clone, Copy, Debug)]
+pub struct PolyInterpolation<T, const N: usize> {
+    pub x: LinearInterpolation<T>,
+    pub coeffs: [T; N],
+}
+
+impl<T: Num + FromPrimitive + PartialOrd + Clone, const N: usize> Interpolate
+    for PolyInterpolation<T, N>
+{
+    type Item = T;
+    fn interpolate(&self, i: usize) -> T {
+        let x = self.x.interpolate(i);
+        self.coeffs
+            .iter()
+            .rev()
+            .fold(T::zero(), |acc, c| acc * x.clone() + c.clone())
+    }
+
+    const EXACT_FOLD: bool = false;
+
+    fn fold_range<B, F>(&self, range: Range<usize>, init: B, f: F) -> B
+    where
+        F: FnMut(B, T) -> B,
+    {
+        self.fold_differences(range, false, init, f)
+    }
+
+    fn fold_agrees(&self, i: usize, folded: &T) -> bool {
+        // each difference is only ever stepped `RESYNC` times from the index,
+        // so is bounded by the polynomial with positive coefficients a block further along
+        let zero = T::zero();
+        let reach = abs_diff(self.x.interpolate(i), zero.clone())
+            + abs_diff(self.x.step.clone(), zero.clone()) * T::from_usize(RESYNC).unwrap();
+        let scale = self.coeffs.iter().rev().fold(zero.clone(), |acc, c| {
+            acc * reach.clone() + abs_diff(c.clone(), zero.clone())
+        });
+        let tolerance = epsilon::<T>() * scale * T::from_usize(RESYNC * N).unwrap();
+        abs_diff(folded.clone(), self.interpolate(i)) <= tolerance
+    }
+}
+
+impl<T: Num + FromPrimitive + PartialOrd + Clone, const N: usize> PolyInterpolation<T, N> {
+    pub(crate) fn fold_differences<B, F>(
+        &self,
+        range: Range<usize>,
+        compensated: bool,
+        init: B,
+        f: F,
+    ) -> B
+    where
+        F: FnMut(B, T) -> B,
+    {
+        if N == 0 {
+            return range.map(|i| self.interpolate(i)).fold(init, f);
+        }
+        let diffs_at = |i| {
+            let start = self.x.interpolate(i);
+            poly_differences(self.coeffs.clone(), start, self.x.step.clone())
+        };
+        let value_at = |i| self.interpolate(i);
+        fold_differences(range, compensated, value_at, diffs_at, init, f)
+    }
+}
+
+impl<T: Num + FromPrimitive + PartialOrd + Clone + fmt::Debug, const N: usize> SpaceFmt
+    for PolyInterpolation<T, N>
+{
+    const NAME: &'static str = "PolySpace";
+    fn fmt_params(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, ", coeffs: {:?}", self.coeffs)
+    }
+}
+
+/// [`Iterator`] returned by [`poly_space`]
+pub type PolySpace<T, const N: usize> = Space<PolyInterpolation<T, N>>;
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::check_double_ended_iter;
+
+    #[test]
+    fn test_poly_space() {
+        // x³ - x
+        let it = poly_space([0, -1, 0, 1], -2..=2, 5);
+        assert_eq!(it.len(), 5);
+        check_double_ended_iter(it, [-6, 0, 0, 0, 6]);
+
+        let it = poly_space([3.0], 0.0..1.0, 2);
+        check_double_ended_iter(it, [3.0, 3.0]);
+
+        let it = poly_space::<_, 0>([], 0.0..1.0, 2);
+        check_double_ended_iter(it, [0.0, 0.0]);
+    }
+
+    #[test]
+    fn test_poly_space_fold() {
+        let it = poly_space([1, -3, 0, 2], -5..=5, 11);
+        let direct: Vec<_> = it.clone().rev().collect();
+        let folded: Vec<_> = it.fold(Vec::new(), |mut v, x| {
+            v.insert(0, x);
+            v
+        });
+        assert_eq!(folded, direct);
+
+        // folding a partially consumed space starts from where it was left
+        let mut it = poly_space([0.5_f64, 0.25, -1.0], 0.0..=10.0, 1001);
+        it.nth(100);
+        let direct: Vec<_> = it.clone().rev().collect();
+        let mut folded = Vec::new();
+        it.for_each(|x| folded.push(x));
+        folded.reverse();
+        assert_eq!(folded.len(), direct.len());
+        for (a, b) in folded.into_iter().zip(direct) {
+            assert!((a - b).abs() < 1e-9, "{a} != {b}");
+        }
+    }
+
+    #[test]
+    fn test_poly_space_fmt() {
+        let it = poly_space([1.0, 1.0], 0.0..=1.0, 3);
+        assert_eq!(
+            format!("{it:?}"),
+            "PolySpace { 1.0..=2.0, len: 3, coeffs: [1.0, 1.0] }"
+        );
+    }
+}
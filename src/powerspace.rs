@@ -0,0 +1,160 @@
+use core::fmt;
+use core::ops::RangeInclusive;
+use num_traits::{real::Real, FromPrimitive};
+
+use crate::{
+    fp,
+    linspace::is_finite,
+    space::{Direction, Interpolate, Monotonic, Space, SpaceFmt},
+};
+
+/// Creates a space over each power of ten in the range, such as `1.0, 10.0, 100.0`
+///
+/// Each value is computed from its integer exponent by multiplication,
+/// rather than with [`powf`](Real::powf) like [`log_space`](crate::log_space),
+/// so every power of ten which can be represented exactly is, and the rest are as close as a division can get them.
+/// The ends of the range are rounded to the nearest power of ten.
+///
+/// ```
+/// use iter_num_tools::decade_space;
+///
+/// let it = decade_space(1e-2..=1e3);
+/// assert!(it.eq([0.01, 0.1, 1.0, 10.0, 100.0, 1000.0]));
+///
+/// let it = decade_space(1e6..=1e0);
+/// assert!(it.eq([1e6, 1e5, 1e4, 1e3, 1e2, 1e1, 1e0]));
+/// ```
+///
+/// # Panics
+///
+/// Panics if the range is not over positive finite values
+pub fn decade_space<T: Real + FromPrimitive>(range: RangeInclusive<T>) -> PowerSpace<T> {
+    power_space(T::from_u8(10).unwrap(), range)
+}
+
+/// Creates a space over each power of two in the range, such as `1.0, 2.0, 4.0`
+///
+/// Like [`decade_space`], each value is computed exactly from its integer exponent.
+///
+/// ```
+/// use iter_num_tools::octave_space;
+///
+/// let it = octave_space(0.25..=8.0);
+/// assert!(it.eq([0.25, 0.5, 1.0, 2.0, 4.0, 8.0]));
+/// ```
+///
+/// # Panics
+///
+/// Panics if the range is not over positive finite values
+pub fn octave_space<T: Real + FromPrimitive>(range: RangeInclusive<T>) -> PowerSpace<T> {
+    power_space(T::from_u8(2).unwrap(), range)
+}
+
+fn power_space<T: Real + FromPrimitive>(base: T, range: RangeInclusive<T>) -> PowerSpace<T> {
+    let exponent = |x: T| {
+        (fp::ln(x) / fp::ln(base))
+            .round()
+            .to_i32()
+            .expect("power space must be over positive finite values")
+    };
+    let (start, end) = range.into_inner();
+    let (start, end) = (exponent(start), exponent(end));
+    let len = start.abs_diff(end) as usize + 1;
+    let step = if start <= end { 1 } else { -1 };
+    Space::new(len, PowerInterpolation { base, start, step })
+}
+
+#[derive(Clone, Copy, Debug)]
+pub struct PowerInterpolation<T> {
+    pub base: T,
+    pub start: i32,
+    pub step: i32,
+}
+
+impl<T: Real> Interpolate for PowerInterpolation<T> {
+    type Item = T;
+    fn interpolate(&self, x: usize) -> T {
+        let exponent = self.start as i64 + self.step as i64 * x as i64;
+        let k = exponent.unsigned_abs() as usize;
+        let power = num_traits::pow(self.base, k);
+        match exponent < 0 {
+            false => power,
+            // the reciprocal of an exact power is correctly rounded,
+            // unless the power overflows, such as for subnormal powers of two
+            true if is_finite(&power) => power.recip(),
+            true => num_traits::pow(self.base.recip(), k),
+        }
+    }
+}
+
+impl<T: Real> Monotonic for PowerInterpolation<T> {
+    fn direction(&self) -> Direction {
+        Direction::of_step(self.step.partial_cmp(&0))
+    }
+}
+
+impl<T: Real + fmt::Debug> SpaceFmt for PowerInterpolation<T> {
+    const NAME: &'static str = "PowerSpace";
+    fn fmt_params(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, ", base: {:?}", self.base)
+    }
+}
+
+/// [`Iterator`] returned by [`decade_space`] and [`octave_space`]
+pub type PowerSpace<T> = Space<PowerInterpolation<T>>;
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::check_double_ended_iter;
+
+    #[test]
+    fn test_decade_space() {
+        check_double_ended_iter(decade_space(1.0..=1000.0), [1.0, 10.0, 100.0, 1000.0]);
+        check_double_ended_iter(decade_space(1e-1..=1e-3), [0.1, 0.01, 0.001]);
+
+        // every exactly representable power of ten comes out exact
+        let it = decade_space(1.0..=1e22);
+        assert_eq!(it.len(), 23);
+        assert!(it
+            .zip(0..)
+            .all(|(x, k)| x == format!("1e{k}").parse::<f64>().unwrap()));
+        let it = decade_space(1e-1..=1e-22);
+        assert!(it
+            .zip(1..)
+            .all(|(x, k)| x == format!("1e-{k}").parse::<f64>().unwrap()));
+
+        // ends are rounded to the nearest power
+        assert!(decade_space(0.9_f32..=120.0).eq([1.0, 10.0, 100.0]));
+        assert_eq!(decade_space(5.0..=5.0).len(), 1);
+        assert_eq!(decade_space(1.0..=1e6).direction(), Direction::Increasing);
+    }
+
+    #[test]
+    fn test_octave_space() {
+        check_double_ended_iter(octave_space(1.0..=0.125), [1.0, 0.5, 0.25, 0.125]);
+        // the exact bits of 2^k, down to the smallest subnormal
+        let pow2 = |k: i32| match k {
+            -1022.. => f64::from_bits(((k + 1023) as u64) << 52),
+            _ => f64::from_bits(1 << (k + 1074)),
+        };
+        let it = octave_space(pow2(-1074)..=pow2(1023));
+        assert_eq!(it.len(), 2098);
+        assert!(it.zip(-1074..).all(|(x, k)| x == pow2(k)));
+    }
+
+    #[test]
+    #[should_panic(expected = "power space must be over positive finite values")]
+    fn test_decade_space_negative() {
+        decade_space(-1.0..=10.0);
+    }
+
+    #[test]
+    fn test_power_space_fmt() {
+        let it = octave_space(1.0..=4.0);
+        assert_eq!(
+            format!("{it:?}"),
+            "PowerSpace { 1.0..=4.0, len: 3, base: 2.0 }"
+        );
+    }
+}
@@ -0,0 +1,164 @@
+use core::fmt;
+use num_traits::{Float, FromPrimitive};
+
+use crate::{
+    linspace::{LinearInterpolation, ToLinSpace},
+    space::{Direction, Interpolate, Monotonic, Space, SpaceFmt},
+};
+
+/// Creates a linear space over range with a fixed number of steps,
+/// where every value is `start + i * step` rounded only once
+///
+/// [`lin_space`](crate::lin_space) rounds the multiplication and then the addition,
+/// so a value can be off from the exact `start + i * step` by more than half an ulp.
+/// This computes the product exactly with a fused multiply-add, then adds the three parts
+/// with [round-to-odd](https://doi.org/10.1109/TC.2008.52), so the sum is rounded just once,
+/// to the nearest value to `start + i * step`.
+///
+/// ```
+/// use iter_num_tools::lin_space_precise;
+///
+/// let (start, end, steps) = (0.1_f32, 7.3_f32, 3_000_001);
+/// let step = (end - start) / (steps - 1) as f32;
+///
+/// // an f64 holds start + i * step exactly, so this is only rounded once
+/// let exact = |i: usize| (start as f64 + i as f64 * step as f64) as f32;
+///
+/// let it = lin_space_precise(start..=end, steps);
+/// assert!(it.enumerate().all(|(i, x)| x == exact(i)));
+/// ```
+///
+/// This is only exact while the index can be represented in the item type,
+/// which is below 2<sup>24</sup> for `f32` and 2<sup>53</sup> for `f64`.
+pub fn lin_space_precise<R>(range: R, steps: usize) -> PreciseLinSpace<R::Item>
+where
+    R: ToLinSpace,
+    R::Item: PreciseFloat,
+{
+    let lin = range.into_lin_space(steps);
+    Space::new(lin.len, PreciseInterpolation(lin.interpolate))
+}
+
+/// Floats which [`lin_space_precise`] can round correctly
+pub trait PreciseFloat: Float + FromPrimitive {
+    /// Rounds `sum + err` to odd, given `sum` is `sum + err` rounded to nearest.
+    ///
+    /// If the sum was inexact and has an even significand, it is moved one ulp towards the error.
+    fn round_to_odd(sum: Self, err: Self) -> Self;
+}
+
+macro_rules! precise_float {
+    ($($t:ty),*) => {$(
+        impl PreciseFloat for $t {
+            fn round_to_odd(sum: Self, err: Self) -> Self {
+                let bits = sum.to_bits();
+                if err == 0.0 || !sum.is_finite() || bits & 1 == 1 {
+                    return sum;
+                }
+                // the bits of a float are ordered by magnitude
+                match (err > 0.0) == (sum > 0.0) {
+                    true => <$t>::from_bits(bits + 1),
+                    false => <$t>::from_bits(bits - 1),
+                }
+            }
+        }
+    )*};
+}
+
+precise_float!(f32, f64);
+
+/// Returns `a + b` rounded, along with the rounding error
+fn two_sum<T: Float>(a: T, b: T) -> (T, T) {
+    let s = a + b;
+    let bb = s - a;
+    let err = (a - (s - bb)) + (b - bb);
+    (s, err)
+}
+
+/// Linear interpolation where each value is correctly rounded, returned by [`lin_space_precise`]
+#[derive(Clone, Copy, Debug, Default)]
+pub struct PreciseInterpolation<T>(pub LinearInterpolation<T>);
+
+impl<T: PreciseFloat> Interpolate for PreciseInterpolation<T> {
+    type Item = T;
+    fn interpolate(&self, x: usize) -> T {
+        let LinearInterpolation { start, step } = self.0;
+        let x = T::from_usize(x).unwrap();
+
+        // x * step is exactly prod + prod_err
+        let prod = x * step;
+        if !prod.is_finite() {
+            return start + prod;
+        }
+        let prod_err = x.mul_add(step, -prod);
+
+        let (sum, sum_err) = two_sum(start, prod);
+        let (tail, tail_err) = two_sum(sum_err, prod_err);
+        sum + T::round_to_odd(tail, tail_err)
+    }
+}
+
+impl<T: PreciseFloat> Monotonic for PreciseInterpolation<T> {
+    fn direction(&self) -> Direction {
+        self.0.direction()
+    }
+}
+
+impl<T: PreciseFloat + fmt::Debug> SpaceFmt for PreciseInterpolation<T> {
+    const NAME: &'static str = "PreciseLinSpace";
+    fn fmt_params(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.0.fmt_params(f)
+    }
+}
+
+/// [`Iterator`] returned by [`lin_space_precise`]
+pub type PreciseLinSpace<T> = Space<PreciseInterpolation<T>>;
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{check_double_ended_iter, lin_space};
+
+    #[test]
+    fn test_lin_space_precise() {
+        check_double_ended_iter(lin_space_precise(0.0..=1.0, 5), [0.0, 0.25, 0.5, 0.75, 1.0]);
+        assert_eq!(lin_space_precise(0.0..1.0, 0).bounds(), None);
+    }
+
+    #[test]
+    fn test_correctly_rounded() {
+        // an f64 holds start + i * step for these f32s exactly, so casting it rounds once
+        let (start, steps) = (0.1_f32, 3_000_001);
+        let precise = lin_space_precise(start..=7.3, steps);
+        let step = precise.interpolate.0.step;
+        let exact = |i: usize| (start as f64 + i as f64 * step as f64) as f32;
+
+        let plain = lin_space(start..=7.3, steps);
+        let mismatches = plain.enumerate().filter(|&(i, x)| x != exact(i)).count();
+        assert_ne!(mismatches, 0);
+
+        for (i, x) in precise.enumerate() {
+            assert_eq!(x, exact(i), "index {i}");
+        }
+    }
+
+    #[test]
+    fn test_round_to_odd() {
+        assert_eq!(f64::round_to_odd(1.0, 0.0), 1.0);
+        assert_eq!(f64::round_to_odd(1.0, 1e-20), 1.0 + f64::EPSILON);
+        assert_eq!(f64::round_to_odd(-1.0, 1e-20), -1.0 + f64::EPSILON / 2.0);
+        assert_eq!(
+            f32::round_to_odd(1.0 + f32::EPSILON, -1e-20),
+            1.0 + f32::EPSILON
+        );
+    }
+
+    #[test]
+    fn test_lin_space_precise_fmt() {
+        let it = lin_space_precise(0.0..=1.0, 3);
+        assert_eq!(
+            format!("{it:?}"),
+            "PreciseLinSpace { 0.0..=1.0, len: 3, step: 0.5 }"
+        );
+    }
+}
@@ -0,0 +1,247 @@
+//! Spaces over dimensioned quantities from the [`uom`] crate.
+//!
+//! Quantities can't implement the number traits that [`lin_space`](crate::lin_space) needs,
+//! since multiplying two of them changes their dimension. These interpolate the value in base units
+//! instead, so every value and the step keep their units.
+
+use core::fmt;
+use core::marker::PhantomData;
+use core::ops::Range;
+
+use ::uom::{
+    num::{FromPrimitive, Num},
+    si::{Dimension, Quantity, Units},
+    Conversion,
+};
+
+use crate::{
+    arange::ToArange,
+    axis::AxisRange,
+    linspace::{LinearInterpolation, ToLinSpace},
+    space::{Interpolate, Space, SpaceFmt},
+};
+
+fn quantity<D, U, V>(value: V) -> Quantity<D, U, V>
+where
+    D: Dimension + ?Sized,
+    U: Units<V> + ?Sized,
+    V: Num + Conversion<V>,
+{
+    Quantity {
+        dimension: PhantomData,
+        units: PhantomData,
+        value,
+    }
+}
+
+/// Interpolation for spaces of quantities, computed on the values in base units
+pub struct QuantityInterpolation<D, U, V>
+where
+    D: Dimension + ?Sized,
+    U: Units<V> + ?Sized,
+    V: Num + Conversion<V>,
+{
+    /// The first value and the step between values
+    pub lin: LinearInterpolation<Quantity<D, U, V>>,
+}
+
+impl<D, U, V> Clone for QuantityInterpolation<D, U, V>
+where
+    D: Dimension + ?Sized,
+    U: Units<V> + ?Sized,
+    V: Num + Conversion<V> + Clone,
+{
+    fn clone(&self) -> Self {
+        QuantityInterpolation {
+            lin: self.lin.clone(),
+        }
+    }
+}
+
+impl<D, U, V> Copy for QuantityInterpolation<D, U, V>
+where
+    D: Dimension + ?Sized,
+    U: Units<V> + ?Sized,
+    V: Num + Conversion<V> + Copy,
+{
+}
+
+impl<D, U, V> fmt::Debug for QuantityInterpolation<D, U, V>
+where
+    D: Dimension + ?Sized,
+    U: Units<V> + ?Sized,
+    V: Num + Conversion<V> + fmt::Debug,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("QuantityInterpolation")
+            .field("lin", &self.lin)
+            .finish()
+    }
+}
+
+impl<D, U, V> Interpolate for QuantityInterpolation<D, U, V>
+where
+    D: Dimension + ?Sized,
+    U: Units<V> + ?Sized,
+    V: Num + Conversion<V> + FromPrimitive + Clone,
+{
+    type Item = Quantity<D, U, V>;
+    fn interpolate(&self, x: usize) -> Self::Item {
+        let LinearInterpolation { start, step } = &self.lin;
+        let lin = LinearInterpolation {
+            start: start.value.clone(),
+            step: step.value.clone(),
+        };
+        quantity(lin.interpolate(x))
+    }
+}
+
+impl<D, U, V> SpaceFmt for QuantityInterpolation<D, U, V>
+where
+    D: Dimension + ?Sized,
+    U: Units<V> + ?Sized,
+    V: Num + Conversion<V> + FromPrimitive + Clone + fmt::Debug,
+{
+    const NAME: &'static str = "QuantitySpace";
+    fn fmt_params(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, ", step: {:?}", self.lin.step)
+    }
+}
+
+/// [`Iterator`] returned by [`lin_space`] and [`arange`]
+pub type QuantitySpace<D, U, V> = Space<QuantityInterpolation<D, U, V>>;
+
+impl<D, U, V> QuantitySpace<D, U, V>
+where
+    D: Dimension + ?Sized,
+    U: Units<V> + ?Sized,
+    V: Num + Conversion<V> + Clone,
+{
+    /// The distance between consecutive values
+    ///
+    /// ```
+    /// use iter_num_tools::quantity::lin_space;
+    /// use uom::si::{f64::Length, length::{centimeter, meter}};
+    ///
+    /// let m = Length::new::<meter>;
+    /// let it = lin_space(m(0.0)..=m(1.0), 5);
+    /// assert_eq!(it.step().get::<centimeter>(), 25.0);
+    /// ```
+    pub fn step(&self) -> Quantity<D, U, V> {
+        self.interpolate.lin.step.clone()
+    }
+}
+
+/// Creates a linear space over a range of quantities with a fixed number of steps
+///
+/// ```
+/// use iter_num_tools::quantity::lin_space;
+/// use uom::si::{f64::Length, length::meter};
+///
+/// let m = Length::new::<meter>;
+/// let it = lin_space(m(0.0)..=m(10.0), 11);
+/// assert!(it.map(|x| x.get::<meter>()).eq([0.0, 1.0, 2.0, 3.0, 4.0, 5.0, 6.0, 7.0, 8.0, 9.0, 10.0]));
+/// ```
+pub fn lin_space<D, U, V>(
+    range: impl Into<AxisRange<Quantity<D, U, V>>>,
+    steps: usize,
+) -> QuantitySpace<D, U, V>
+where
+    D: Dimension + ?Sized,
+    U: Units<V> + ?Sized,
+    V: Num + Conversion<V> + FromPrimitive + Clone,
+{
+    let range = match range.into() {
+        AxisRange::Exclusive(Range { start, end }) => AxisRange::Exclusive(start.value..end.value),
+        AxisRange::Periodic(Range { start, end }) => AxisRange::Periodic(start.value..end.value),
+        AxisRange::Inclusive(range) => {
+            let (start, end) = range.into_inner();
+            AxisRange::Inclusive(start.value..=end.value)
+        }
+    };
+    let it = range.into_lin_space(steps);
+    let LinearInterpolation { start, step } = it.interpolate;
+    let lin = LinearInterpolation {
+        start: quantity(start),
+        step: quantity(step),
+    };
+    Space::new(it.len, QuantityInterpolation { lin })
+}
+
+/// Creates a space over a range of quantities, stepping by `step` each time
+///
+/// ```
+/// use iter_num_tools::quantity::arange;
+/// use uom::si::{f64::Time, time::{millisecond, second}};
+///
+/// let it = arange(Time::new::<second>(0.0)..Time::new::<second>(1.0), Time::new::<millisecond>(250.0));
+/// assert!(it.map(|t| t.get::<second>()).eq([0.0, 0.25, 0.5, 0.75]));
+/// ```
+///
+/// # Panics
+///
+/// Panics if the step is zero or points away from the end, like [`arange`](crate::arange)
+pub fn arange<D, U, V>(
+    range: Range<Quantity<D, U, V>>,
+    step: Quantity<D, U, V>,
+) -> QuantitySpace<D, U, V>
+where
+    D: Dimension + ?Sized,
+    U: Units<V> + ?Sized,
+    V: Num + Conversion<V> + FromPrimitive + Clone,
+    Range<V>: ToArange<V, Item = V>,
+{
+    let it = (range.start.value..range.end.value).into_arange(step.value);
+    let LinearInterpolation { start, step } = it.interpolate;
+    let lin = LinearInterpolation {
+        start: quantity(start),
+        step: quantity(step),
+    };
+    Space::new(it.len, QuantityInterpolation { lin })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::check_double_ended_iter;
+    use ::uom::si::{
+        f32::Velocity,
+        f64::Length,
+        length::{kilometer, meter},
+        velocity::meter_per_second,
+    };
+
+    #[test]
+    fn test_quantity_lin_space() {
+        let km = Length::new::<kilometer>;
+        check_double_ended_iter(
+            lin_space(km(0.0)..km(2.0), 4),
+            [km(0.0), km(0.5), km(1.0), km(1.5)],
+        );
+
+        let it = lin_space(km(1.0)..=Length::new::<meter>(0.0), 3);
+        assert_eq!(it.step(), Length::new::<meter>(-500.0));
+        assert_eq!(it.bounds(), Some(km(1.0)..=km(0.0)));
+
+        let v = Velocity::new::<meter_per_second>;
+        let it = lin_space(AxisRange::Periodic(v(0.0)..v(1.0)), 2);
+        assert!(it.eq([v(0.0), v(0.5)]));
+    }
+
+    #[test]
+    fn test_quantity_arange() {
+        let m = Length::new::<meter>;
+        check_double_ended_iter(arange(m(0.0)..m(1.0), m(0.5)), [m(0.0), m(0.5)]);
+        assert_eq!(
+            format!("{:?}", arange(m(0.0)..m(1.0), m(0.5))),
+            "QuantitySpace { 0.0 m^1..=0.5 m^1, len: 2, step: 0.5 m^1 }"
+        );
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_quantity_arange_wrong_sign() {
+        let m = Length::new::<meter>;
+        arange(m(0.0)..m(1.0), m(-1.0));
+    }
+}
@@ -0,0 +1,147 @@
+use core::fmt;
+use num_traits::{FromPrimitive, Num};
+
+use crate::linspace::{lin_space, LinSpace};
+
+/// Smoothly moves a value towards a target, one block of samples at a time,
+/// such as for smoothing audio parameters
+///
+/// Each call to [`fill_block`](Self::fill_block) carries on the ramp from where the last block stopped,
+/// and holds the target once it is reached.
+///
+/// ```
+/// use iter_num_tools::RampGenerator;
+///
+/// let mut gain = RampGenerator::new(0.0);
+/// gain.set_target(1.5, 6);
+///
+/// let mut block = [0.0; 4];
+/// gain.fill_block(&mut block);
+/// assert_eq!(block, [0.25, 0.5, 0.75, 1.0]);
+/// gain.fill_block(&mut block);
+/// assert_eq!(block, [1.25, 1.5, 1.5, 1.5]);
+/// assert!(!gain.is_ramping());
+/// ```
+#[derive(Clone)]
+pub struct RampGenerator<T> {
+    ramp: LinSpace<T>,
+    current: T,
+    target: T,
+}
+
+impl<T: Num + FromPrimitive + Clone> RampGenerator<T> {
+    /// Creates a generator holding at `value`
+    pub fn new(value: T) -> Self {
+        RampGenerator {
+            ramp: lin_space(value.clone()..value.clone(), 0),
+            current: value.clone(),
+            target: value,
+        }
+    }
+
+    /// Ramps from the current value to `target`, reaching it after `samples` samples.
+    ///
+    /// This can be called in the middle of a ramp, which starts the new ramp from wherever the old one got to.
+    /// With zero samples, it jumps straight to the target.
+    pub fn set_target(&mut self, target: T, samples: usize) {
+        let range = self.current.clone()..=target.clone();
+        self.ramp = lin_space(range, samples.saturating_add(1));
+        self.ramp.next();
+        if samples == 0 {
+            self.current = target.clone();
+        }
+        self.target = target;
+    }
+
+    /// Writes the next samples of the ramp into `out`, holding the target after the ramp is done
+    pub fn fill_block(&mut self, out: &mut [T]) {
+        let n = self.ramp.fill(out);
+        out[n..].fill(self.target.clone());
+        if let Some(last) = out.last() {
+            self.current = last.clone();
+        }
+    }
+
+    /// The last value written, or the starting value if nothing has been written yet
+    pub fn value(&self) -> &T {
+        &self.current
+    }
+
+    /// The value being ramped towards
+    pub fn target(&self) -> &T {
+        &self.target
+    }
+
+    /// Whether there are samples left before the target is reached
+    pub fn is_ramping(&self) -> bool {
+        !self.ramp.is_empty()
+    }
+
+    /// The number of samples left before the target is reached
+    pub fn remaining(&self) -> usize {
+        self.ramp.len()
+    }
+}
+
+impl<T: Num + FromPrimitive + Clone + fmt::Debug> fmt::Debug for RampGenerator<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("RampGenerator")
+            .field("value", &self.current)
+            .field("target", &self.target)
+            .field("remaining", &self.remaining())
+            .finish()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_ramp_generator() {
+        let mut ramp = RampGenerator::new(0);
+        let mut block = [0; 3];
+        ramp.fill_block(&mut block);
+        assert_eq!(block, [0, 0, 0]);
+
+        ramp.set_target(8, 4);
+        assert_eq!(ramp.remaining(), 4);
+        ramp.fill_block(&mut block);
+        assert_eq!(block, [2, 4, 6]);
+        assert_eq!(*ramp.value(), 6);
+
+        // retarget in the middle of the ramp
+        ramp.set_target(0, 2);
+        ramp.fill_block(&mut block);
+        assert_eq!(block, [3, 0, 0]);
+        assert_eq!((*ramp.value(), *ramp.target()), (0, 0));
+
+        ramp.set_target(5, 0);
+        assert_eq!(*ramp.value(), 5);
+        ramp.fill_block(&mut []);
+        ramp.fill_block(&mut block);
+        assert_eq!(block, [5, 5, 5]);
+        assert_eq!(
+            format!("{ramp:?}"),
+            "RampGenerator { value: 5, target: 5, remaining: 0 }"
+        );
+    }
+
+    #[test]
+    fn test_ramp_generator_blocks() {
+        // the ramp is the same however it is split into blocks
+        let mut whole = RampGenerator::new(1.0);
+        whole.set_target(-1.5, 10);
+        let mut expected = [0.0; 12];
+        whole.fill_block(&mut expected);
+
+        let mut split = RampGenerator::new(1.0);
+        split.set_target(-1.5, 10);
+        let mut actual = [0.0; 12];
+        for block in actual.chunks_mut(5) {
+            split.fill_block(block);
+        }
+        assert_eq!(actual, expected);
+        assert_eq!(expected[9..], [-1.5; 3]);
+    }
+}
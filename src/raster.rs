@@ -0,0 +1,225 @@
+//! Grids over the pixels of raster images
+
+use core::fmt;
+use num_traits::{FromPrimitive, Num};
+
+use crate::{
+    gridspace::{grid_from_origin, grid_len, GridSpaceInterpolation},
+    space::{Interpolate, Space, SpaceFmt},
+};
+
+/// Creates a grid over the world coordinates of the pixels of a `width` by `height` raster,
+/// placed by an affine transform in the same layout as a GDAL geotransform.
+///
+/// The transform is `[x0, dx_col, dx_row, y0, dy_col, dy_row]`, so the pixel at column `c` and row `r` is at
+/// `[x0 + c * dx_col + r * dx_row, y0 + c * dy_col + r * dy_row]`.
+/// This is the top left corner of the pixel. Like [`grid_space`](crate::grid_space), the column varies the fastest.
+///
+/// Without rotation, `dx_row` and `dy_col` are zero and this is the same as [`grid_from_origin`](crate::grid_from_origin),
+/// but a rotated or sheared raster can't be split up into a range along each axis.
+///
+/// ```
+/// use iter_num_tools::affine_grid;
+///
+/// // 10 units per pixel, with rows going down
+/// let it = affine_grid([100.0, 10.0, 0.0, 50.0, 0.0, -10.0], 2, 2);
+/// assert!(it.eq([[100.0, 50.0], [110.0, 50.0], [100.0, 40.0], [110.0, 40.0]]));
+///
+/// // rotated by 90 degrees
+/// let it = affine_grid([0.0, 0.0, 1.0, 0.0, 1.0, 0.0], 2, 2);
+/// assert!(it.eq([[0.0, 0.0], [0.0, 1.0], [1.0, 0.0], [1.0, 1.0]]));
+/// ```
+///
+/// # Panics
+///
+/// Panics if the number of pixels overflows a `usize`
+pub fn affine_grid<T>(transform: [T; 6], width: usize, height: usize) -> AffineGrid<T>
+where
+    T: Num + FromPrimitive + Clone,
+{
+    let pixels = grid_from_origin(
+        [T::zero(), T::zero()],
+        [T::one(), T::one()],
+        [width, height],
+    );
+    Space::new(
+        pixels.len(),
+        AffineInterpolation {
+            pixels: pixels.interpolate,
+            transform,
+        },
+    )
+}
+
+#[derive(Clone, Copy, Debug)]
+pub struct AffineInterpolation<T> {
+    pub pixels: GridSpaceInterpolation<T, 2>,
+    pub transform: [T; 6],
+}
+
+impl<T: Num + FromPrimitive + Clone> Interpolate for AffineInterpolation<T> {
+    type Item = [T; 2];
+    fn interpolate(&self, x: usize) -> [T; 2] {
+        let [col, row] = self.pixels.interpolate(x);
+        let [x0, dx_col, dx_row, y0, dy_col, dy_row] = self.transform.clone();
+        [
+            x0 + col.clone() * dx_col + row.clone() * dx_row,
+            y0 + col * dy_col + row * dy_row,
+        ]
+    }
+}
+
+impl<T: Num + FromPrimitive + Clone + fmt::Debug> SpaceFmt for AffineInterpolation<T> {
+    const NAME: &'static str = "AffineGrid";
+    fn fmt_params(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.pixels.fmt_params(f)?;
+        write!(f, ", transform: {:?}", self.transform)
+    }
+}
+
+/// [`Iterator`] returned by [`affine_grid`]
+pub type AffineGrid<T> = Space<AffineInterpolation<T>>;
+
+/// Creates a grid over the pixels of a `width` by `height` image, yielding the `[x, y]` index of each pixel
+///
+/// Like [`grid_step`](crate::grid_step), `x` varies the fastest, so the pixels are in row-major order.
+/// Use [`with_uv`](PixelGrid::with_uv) to also get the normalized coordinates of each pixel.
+///
+/// ```
+/// use iter_num_tools::pixel_grid;
+///
+/// let it = pixel_grid(3, 2);
+/// assert!(it.eq([[0, 0], [1, 0], [2, 0], [0, 1], [1, 1], [2, 1]]));
+/// ```
+///
+/// # Panics
+///
+/// Panics if the number of pixels overflows a `usize`
+pub fn pixel_grid(width: u32, height: u32) -> PixelGrid {
+    let len = grid_len([width as usize, height as usize]);
+    Space::new(len, PixelInterpolation { width, height })
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct PixelInterpolation {
+    pub width: u32,
+    pub height: u32,
+}
+
+impl Interpolate for PixelInterpolation {
+    type Item = [u32; 2];
+    fn interpolate(&self, x: usize) -> [u32; 2] {
+        let width = self.width as usize;
+        [(x % width) as u32, (x / width) as u32]
+    }
+}
+
+impl SpaceFmt for PixelInterpolation {
+    const NAME: &'static str = "PixelGrid";
+    fn fmt_params(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, ", width: {}, height: {}", self.width, self.height)
+    }
+}
+
+/// [`Iterator`] returned by [`pixel_grid`]
+pub type PixelGrid = Space<PixelInterpolation>;
+
+impl PixelGrid {
+    /// Pairs each remaining pixel with its normalized `[u, v]` coordinates,
+    /// which put the center of the pixel `[x, y]` at `[(x + 0.5) / width, (y + 0.5) / height]`,
+    /// so they are always strictly between 0 and 1
+    ///
+    /// ```
+    /// use iter_num_tools::pixel_grid;
+    ///
+    /// let it = pixel_grid(2, 1).with_uv();
+    /// assert!(it.eq([([0, 0], [0.25, 0.5]), ([1, 0], [0.75, 0.5])]));
+    /// ```
+    pub fn with_uv(self) -> PixelGridUv {
+        self.map_interpolate(PixelUvInterpolation)
+    }
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct PixelUvInterpolation(pub PixelInterpolation);
+
+impl Interpolate for PixelUvInterpolation {
+    type Item = ([u32; 2], [f32; 2]);
+    fn interpolate(&self, x: usize) -> Self::Item {
+        let PixelInterpolation { width, height } = self.0;
+        let [px, py] = self.0.interpolate(x);
+        let u = (px as f32 + 0.5) / width as f32;
+        let v = (py as f32 + 0.5) / height as f32;
+        ([px, py], [u, v])
+    }
+}
+
+impl SpaceFmt for PixelUvInterpolation {
+    const NAME: &'static str = "PixelGridUv";
+    fn fmt_params(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.0.fmt_params(f)
+    }
+}
+
+/// [`Iterator`] returned by [`PixelGrid::with_uv`]
+pub type PixelGridUv = Space<PixelUvInterpolation>;
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::check_double_ended_iter;
+
+    #[test]
+    fn test_affine_grid() {
+        // shear each row along by half a pixel
+        check_double_ended_iter(
+            affine_grid([1.0, 1.0, 0.5, 2.0, 0.0, 1.0], 3, 2),
+            [
+                [1.0, 2.0],
+                [2.0, 2.0],
+                [3.0, 2.0],
+                [1.5, 3.0],
+                [2.5, 3.0],
+                [3.5, 3.0],
+            ],
+        );
+        assert_eq!(affine_grid([0, 1, 0, 0, 0, 1], 0, 4).len(), 0);
+
+        // same as grid_from_origin without rotation
+        let it = affine_grid([5, 2, 0, 7, 0, -3], 4, 3);
+        assert!(it.eq(grid_from_origin([5, 7], [2, -3], [4, 3])));
+    }
+
+    #[test]
+    fn test_pixel_grid() {
+        check_double_ended_iter(pixel_grid(2, 2), [[0, 0], [1, 0], [0, 1], [1, 1]]);
+        assert_eq!(pixel_grid(0, 5).len(), 0);
+        assert_eq!(pixel_grid(5, 0).len(), 0);
+
+        let mut it = pixel_grid(4, 2);
+        it.nth(4);
+        check_double_ended_iter(
+            it.with_uv(),
+            [
+                ([1, 1], [0.375, 0.75]),
+                ([2, 1], [0.625, 0.75]),
+                ([3, 1], [0.875, 0.75]),
+            ],
+        );
+
+        let it = pixel_grid(3, 2).with_uv();
+        assert_eq!(
+            format!("{it:?}"),
+            "PixelGridUv { ([0, 0], [0.16666667, 0.25])..=([2, 1], [0.8333333, 0.75]), len: 6, width: 3, height: 2 }"
+        );
+    }
+
+    #[test]
+    fn test_affine_grid_fmt() {
+        let it = affine_grid([0, 1, 0, 0, 0, 1], 2, 3);
+        assert_eq!(
+            format!("{it:?}"),
+            "AffineGrid { [0, 0]..=[1, 2], len: 6, shape: [2, 3], transform: [0, 1, 0, 0, 0, 1] }"
+        );
+    }
+}
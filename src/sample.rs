@@ -0,0 +1,202 @@
+use core::cmp::Ordering;
+use core::fmt;
+use core::iter::Map;
+
+use crate::space::{Interpolate, Space, SpaceFmt};
+
+/// Samples the function `f` at each point of the space
+///
+/// ```
+/// use iter_num_tools::{lin_space, sample};
+///
+/// let sampled = sample(lin_space(-1.0..=2.0, 4), |x: f64| x * x);
+/// assert!(sampled.points().eq([-1.0, 0.0, 1.0, 2.0]));
+/// assert!(sampled.values().eq([1.0, 0.0, 1.0, 4.0]));
+///
+/// assert_eq!(sampled.min(), Some(0.0));
+/// assert_eq!(sampled.argmax(), Some(2.0));
+/// ```
+pub fn sample<I, F, V>(space: Space<I>, f: F) -> Sampled<I, F>
+where
+    I: Interpolate + Clone,
+    F: Fn(I::Item) -> V + Clone,
+{
+    Sampled { space, f }
+}
+
+/// A function sampled over a space, returned by [`sample`]
+///
+/// Values are computed lazily each time they are iterated
+#[derive(Clone)]
+pub struct Sampled<I, F> {
+    space: Space<I>,
+    f: F,
+}
+
+impl<I: SpaceFmt, F> fmt::Debug for Sampled<I, F>
+where
+    I::Item: fmt::Debug,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Sampled")
+            .field("space", &self.space)
+            .finish_non_exhaustive()
+    }
+}
+
+#[derive(Clone, Copy, Debug)]
+pub struct SampleInterpolation<I, F>(pub I, pub F);
+
+impl<I, F, V> Interpolate for SampleInterpolation<I, F>
+where
+    I: Interpolate,
+    I::Item: Clone,
+    F: Fn(I::Item) -> V,
+{
+    type Item = (I::Item, V);
+    fn interpolate(&self, x: usize) -> Self::Item {
+        let point = self.0.interpolate(x);
+        (point.clone(), (self.1)(point))
+    }
+}
+
+impl<I, F, V> SpaceFmt for SampleInterpolation<I, F>
+where
+    I: SpaceFmt,
+    I::Item: Clone,
+    F: Fn(I::Item) -> V,
+{
+    const NAME: &'static str = "Sampled";
+    fn fmt_params(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, ", inner: {}", I::NAME)?;
+        self.0.fmt_params(f)
+    }
+}
+
+impl<I, F, V> Sampled<I, F>
+where
+    I: Interpolate + Clone,
+    I::Item: Clone,
+    F: Fn(I::Item) -> V + Clone,
+{
+    /// The points the function is sampled at
+    pub fn points(&self) -> Space<I> {
+        self.space.clone()
+    }
+
+    /// The value of the function at each point
+    pub fn values(&self) -> Map<Space<I>, F> {
+        self.space.clone().map(self.f.clone())
+    }
+
+    /// Each point paired with the value of the function at that point
+    pub fn iter(&self) -> Space<SampleInterpolation<I, F>> {
+        let f = self.f.clone();
+        self.space
+            .clone()
+            .map_interpolate(|interpolate| SampleInterpolation(interpolate, f))
+    }
+
+    /// The smallest value of the function, ignoring any unordered values such as NaN
+    pub fn min(&self) -> Option<V>
+    where
+        V: PartialOrd,
+    {
+        self.extremum(Ordering::Less).map(|(_, value)| value)
+    }
+
+    /// The largest value of the function, ignoring any unordered values such as NaN
+    pub fn max(&self) -> Option<V>
+    where
+        V: PartialOrd,
+    {
+        self.extremum(Ordering::Greater).map(|(_, value)| value)
+    }
+
+    /// The first point at which the function takes its smallest value
+    pub fn argmin(&self) -> Option<I::Item>
+    where
+        V: PartialOrd,
+    {
+        self.extremum(Ordering::Less).map(|(point, _)| point)
+    }
+
+    /// The first point at which the function takes its largest value
+    pub fn argmax(&self) -> Option<I::Item>
+    where
+        V: PartialOrd,
+    {
+        self.extremum(Ordering::Greater).map(|(point, _)| point)
+    }
+
+    fn extremum(&self, order: Ordering) -> Option<(I::Item, V)>
+    where
+        V: PartialOrd,
+    {
+        self.iter()
+            // skip values that are not comparable with themselves, like NaN
+            .filter(|(_, value)| value.partial_cmp(value).is_some())
+            .reduce(|best, next| {
+                if next.1.partial_cmp(&best.1) == Some(order) {
+                    next
+                } else {
+                    best
+                }
+            })
+    }
+}
+
+impl<I, F, V> IntoIterator for Sampled<I, F>
+where
+    I: Interpolate,
+    I::Item: Clone,
+    F: Fn(I::Item) -> V,
+{
+    type Item = (I::Item, V);
+    type IntoIter = Space<SampleInterpolation<I, F>>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        let f = self.f;
+        self.space
+            .map_interpolate(|interpolate| SampleInterpolation(interpolate, f))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{check_double_ended_iter, grid_space, lin_space};
+
+    #[test]
+    fn test_sample() {
+        let sampled = sample(lin_space(0.0..=2.0, 3), |x: f64| -x);
+        check_double_ended_iter(sampled.iter(), [(0.0, -0.0), (1.0, -1.0), (2.0, -2.0)]);
+        assert_eq!(sampled.max(), Some(0.0));
+        assert_eq!(sampled.min(), Some(-2.0));
+        assert_eq!(sampled.argmin(), Some(2.0));
+        assert_eq!(sampled.argmax(), Some(0.0));
+
+        let sampled = sample(grid_space([0.0, 0.0]..=[1.0, 1.0], 2), |[x, y]| x - y);
+        assert_eq!(sampled.argmax(), Some([1.0, 0.0]));
+        assert_eq!(sampled.into_iter().len(), 4);
+    }
+
+    #[test]
+    fn test_sample_nan() {
+        let sampled = sample(
+            lin_space(0.0..=2.0, 3),
+            |x: f64| {
+                if x == 0.0 {
+                    f64::NAN
+                } else {
+                    x
+                }
+            },
+        );
+        assert_eq!(sampled.min(), Some(1.0));
+        assert_eq!(sampled.argmax(), Some(2.0));
+
+        let sampled = sample(lin_space(0.0..=2.0, 0), |x: f64| x);
+        assert_eq!(sampled.min(), None);
+    }
+}
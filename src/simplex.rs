@@ -0,0 +1,131 @@
+use core::fmt;
+
+use crate::space::{Interpolate, Space, SpaceFmt};
+
+/// Creates an iterator over the lattice points of a simplex,
+/// which are the indices that add up to at most `order`.
+///
+/// These are the points of a triangular grid in 2D, or a tetrahedral grid in 3D,
+/// so dividing them by the order gives evenly spaced barycentric coordinates.
+/// They are also the exponents of every monomial of a polynomial up to degree `order`.
+/// Like [`grid_step`](crate::grid_step), the first index varies the fastest.
+///
+/// ```
+/// use iter_num_tools::simplex_grid;
+///
+/// let it = simplex_grid::<2>(2);
+/// assert_eq!(it.len(), 6);
+/// assert!(it.eq([
+///     [0, 0], [1, 0], [2, 0],
+///     [0, 1], [1, 1],
+///     [0, 2],
+/// ]));
+/// ```
+///
+/// # Panics
+///
+/// Panics if the number of points, `(order + N) choose N`, overflows a `usize`
+pub fn simplex_grid<const N: usize>(order: usize) -> SimplexGrid<N> {
+    let len = binomial(order, N).expect("simplex size overflows usize");
+    Space::new(len, SimplexInterpolation { order })
+}
+
+/// The number of points in a simplex of the given order and dimension,
+/// which is `(order + dim) choose dim`
+fn binomial(order: usize, dim: usize) -> Option<usize> {
+    let mut c: u128 = 1;
+    for i in 1..=dim as u128 {
+        // c is (order + i - 1) choose (i - 1), so this division is exact
+        c = c.checked_mul(order as u128 + i)? / i;
+        usize::try_from(c).ok()?;
+    }
+    usize::try_from(c).ok()
+}
+
+#[derive(Clone, Copy, Debug)]
+pub struct SimplexInterpolation<const N: usize> {
+    pub order: usize,
+}
+
+impl<const N: usize> Interpolate for SimplexInterpolation<N> {
+    type Item = [usize; N];
+    fn interpolate(&self, mut x: usize) -> [usize; N] {
+        let mut index = [0; N];
+        let mut budget = self.order;
+        for j in (0..N).rev() {
+            // Giving axis j the value v leaves a simplex of dimension j and order budget - v.
+            // By the hockey stick identity, the values before v account for
+            // `C(budget + j + 1, j + 1) - C(budget - v + j + 1, j + 1)` points,
+            // so find the largest v where that is at most x.
+            let total = binomial(budget, j + 1).unwrap();
+            let (mut lo, mut hi) = (0, budget);
+            while lo < hi {
+                let mid = hi - (hi - lo) / 2;
+                match total - binomial(budget - mid, j + 1).unwrap() <= x {
+                    true => lo = mid,
+                    false => hi = mid - 1,
+                }
+            }
+            x -= total - binomial(budget - lo, j + 1).unwrap();
+            index[j] = lo;
+            budget -= lo;
+        }
+        index
+    }
+}
+
+impl<const N: usize> SpaceFmt for SimplexInterpolation<N> {
+    const NAME: &'static str = "SimplexGrid";
+    fn fmt_params(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, ", order: {}", self.order)
+    }
+}
+
+/// [`Iterator`] returned by [`simplex_grid`]
+pub type SimplexGrid<const N: usize> = Space<SimplexInterpolation<N>>;
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{check_double_ended_iter, grid_step};
+
+    #[test]
+    fn test_simplex_grid() {
+        check_double_ended_iter(simplex_grid::<1>(2), [[0], [1], [2]]);
+        check_double_ended_iter(simplex_grid::<3>(0), [[0, 0, 0]]);
+        check_double_ended_iter(simplex_grid::<0>(3), [[]]);
+        check_double_ended_iter(
+            simplex_grid::<3>(1),
+            [[0, 0, 0], [1, 0, 0], [0, 1, 0], [0, 0, 1]],
+        );
+    }
+
+    #[test]
+    fn test_simplex_grid_matches_filtered_grid() {
+        let expected: Vec<_> = grid_step([0; 4]..=[5; 4])
+            .filter(|index| index.iter().sum::<usize>() <= 5)
+            .collect();
+        let it = simplex_grid::<4>(5);
+        assert_eq!(it.len(), expected.len());
+        assert!(it.clone().eq(expected.iter().copied()));
+        assert!(it.rev().eq(expected.into_iter().rev()));
+    }
+
+    #[test]
+    fn test_simplex_grid_large() {
+        let mut it = simplex_grid::<2>(1_000_000);
+        assert_eq!(it.len(), 500_001_500_001);
+        assert_eq!(it.next_back(), Some([0, 1_000_000]));
+        assert_eq!(it.nth_back(1), Some([0, 999_999]));
+        assert_eq!(
+            format!("{:?}", simplex_grid::<2>(2)),
+            "SimplexGrid { [0, 0]..=[0, 2], len: 6, order: 2 }"
+        );
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_simplex_grid_overflow() {
+        simplex_grid::<8>(usize::MAX / 2);
+    }
+}
@@ -1,12 +1,79 @@
+use core::cmp::Ordering;
+use core::fmt;
 use core::iter::FusedIterator;
-use core::ops::Range;
+use core::ops::{Range, RangeInclusive};
 
 pub trait Interpolate {
     type Item;
-    fn interpolate(self, x: usize) -> Self::Item;
+    fn interpolate(&self, x: usize) -> Self::Item;
+
+    /// Whether [`fold_range`](Self::fold_range) gives exactly the same values as [`interpolate`](Self::interpolate).
+    /// Folds that pick up rounding errors by stepping from one value to the next set this to `false`,
+    /// and override [`fold_agrees`](Self::fold_agrees) with how far off they can be
+    const EXACT_FOLD: bool = true;
+
+    /// Folds the values at every index in the range, in order.
+    /// Interpolations that can step from one value to the next more cheaply override this
+    fn fold_range<B, F>(&self, range: Range<usize>, init: B, f: F) -> B
+    where
+        F: FnMut(B, Self::Item) -> B,
+    {
+        range.map(|x| self.interpolate(x)).fold(init, f)
+    }
+
+    /// Whether `folded`, the value [`fold_range`](Self::fold_range) gave for index `x`,
+    /// is close enough to the value from [`interpolate`](Self::interpolate).
+    /// Folds that aren't exact accept anything, unless they override this with their error bound
+    fn fold_agrees(&self, x: usize, folded: &Self::Item) -> bool
+    where
+        Self::Item: PartialEq,
+    {
+        !Self::EXACT_FOLD || *folded == self.interpolate(x)
+    }
+}
+
+/// Describes how a space is shown by its [`Debug`](fmt::Debug) and [`Display`](fmt::Display) impls
+pub trait SpaceFmt: Interpolate {
+    /// The name of the space, such as `LinSpace`
+    const NAME: &'static str;
+    /// Writes any extra parameters of the space, each prefixed with `, `
+    fn fmt_params(&self, _f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        Ok(())
+    }
+}
+
+/// Which way the values of a space go as the index increases, returned by [`Space::direction`]
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum Direction {
+    /// Each value is greater than the one before
+    Increasing,
+    /// Each value is less than the one before
+    Decreasing,
+    /// Every value is the same
+    Constant,
+    /// The values are not known to be in order, such as after an arbitrary map
+    NonMonotonic,
 }
 
-#[derive(Clone, Copy, Debug)]
+impl Direction {
+    /// The direction of values stepping by something which compares to zero like this
+    pub(crate) fn of_step(ordering: Option<Ordering>) -> Self {
+        match ordering {
+            Some(Ordering::Greater) => Direction::Increasing,
+            Some(Ordering::Less) => Direction::Decreasing,
+            Some(Ordering::Equal) => Direction::Constant,
+            None => Direction::NonMonotonic,
+        }
+    }
+}
+
+/// Interpolations which know which way their values go
+pub trait Monotonic: Interpolate {
+    /// The direction of the values as the index increases
+    fn direction(&self) -> Direction;
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
 pub struct IntoSpace<I> {
     pub interpolate: I,
     pub len: usize,
@@ -19,9 +86,27 @@ impl<I> IntoSpace<I> {
     pub fn into_space(self) -> Space<I> {
         Space::new(self.len, self.interpolate)
     }
+
+    /// The number of values the space will have, without creating it
+    ///
+    /// ```
+    /// use iter_num_tools::ToLinSpace;
+    ///
+    /// let space = (0.0..=1.0).into_lin_space(5);
+    /// assert_eq!(space.len(), 5);
+    /// assert_eq!(space.into_iter().len(), 5);
+    /// ```
+    pub fn len(&self) -> usize {
+        self.len
+    }
+
+    /// Whether the space will have no values
+    pub fn is_empty(&self) -> bool {
+        self.len == 0
+    }
 }
 
-impl<I: Interpolate + Copy> IntoIterator for IntoSpace<I> {
+impl<I: Interpolate> IntoIterator for IntoSpace<I> {
     type Item = I::Item;
     type IntoIter = Space<I>;
 
@@ -30,22 +115,539 @@ impl<I: Interpolate + Copy> IntoIterator for IntoSpace<I> {
     }
 }
 
-#[derive(Clone, Debug)]
+/// An iterator over evenly indexed values, produced by interpolating each index.
+///
+/// This is the iterator behind every space in this crate, such as [`LinSpace`](crate::LinSpace)
+/// and [`GridSpace`](crate::GridSpace). Since any index can be computed directly,
+/// it knows its exact length, can be iterated from both ends and can skip ahead cheaply.
+///
+/// Spaces can be created with the free functions, such as [`lin_space`](crate::lin_space),
+/// or with the builder starting at [`Space::linear`].
+///
+/// # Adapters
+///
+/// Spaces have their own versions of some [`Iterator`] adapters, such as [`stride`](Space::stride)
+/// for [`Iterator::step_by`] and [`zip_spaces`](crate::zip_spaces) for [`Iterator::zip`].
+/// These work on the indices, so the result is still a space: it keeps its exact length,
+/// iterates from both ends, skips ahead cheaply and has [`bounds`](Space::bounds).
+/// Its type can also be written out, such as [`Stride<LinSpace<f64>>`](crate::Stride).
+#[derive(Clone, PartialEq, Eq, Hash)]
 pub struct Space<I> {
-    interpolate: I,
-    range: Range<usize>,
+    pub(crate) interpolate: I,
+    pub(crate) range: Range<usize>,
+    pub(crate) total: usize,
+}
+
+/// Gets the interpolation out of a space type, so the types returned by adapters
+/// such as [`Map`](crate::Map) can be written in terms of the space being adapted
+pub trait SpaceType {
+    type Interpolation;
+}
+
+impl<I> SpaceType for Space<I> {
+    type Interpolation = I;
 }
 
 impl<I> Space<I> {
+    /// Creates a space of `len` values, interpolating each index from 0
     pub fn new(len: usize, interpolate: I) -> Self {
+        Space::with_range(0..len, interpolate)
+    }
+
+    /// Creates a space with no values, such as for a sweep over a degenerate range
+    ///
+    /// ```
+    /// use iter_num_tools::LinSpace;
+    ///
+    /// let it = LinSpace::<f64>::empty();
+    /// assert!(it.is_empty());
+    /// assert_eq!(it.bounds(), None);
+    /// ```
+    pub fn empty() -> Self
+    where
+        I: Default,
+    {
+        Space::new(0, I::default())
+    }
+
+    /// Whether there are no values remaining, which is the same as `len() == 0`
+    ///
+    /// ```
+    /// use iter_num_tools::lin_space;
+    ///
+    /// let mut it = lin_space(0.0..1.0, 1);
+    /// assert!(!it.is_empty());
+    /// it.next();
+    /// assert!(it.is_empty());
+    /// ```
+    pub fn is_empty(&self) -> bool {
+        self.range.is_empty()
+    }
+
+    pub(crate) fn with_range(range: Range<usize>, interpolate: I) -> Self {
         Space {
             interpolate,
-            range: 0..len,
+            total: range.len(),
+            range,
+        }
+    }
+
+    /// Swaps out the interpolation for one over the same indices,
+    /// keeping track of how much of the space has been consumed
+    pub(crate) fn map_interpolate<J>(self, f: impl FnOnce(I) -> J) -> Space<J> {
+        let Space {
+            interpolate,
+            range,
+            total,
+        } = self;
+        Space {
+            interpolate: f(interpolate),
+            range,
+            total,
+        }
+    }
+
+    /// Narrows the space down to `len` values, after skipping the first `offset` remaining values.
+    ///
+    /// This is the same as `skip(offset).take(len)`, except the result is still a space.
+    /// Like those, it stops early if there are too few values remaining.
+    ///
+    /// ```
+    /// use iter_num_tools::lin_space;
+    ///
+    /// let it = lin_space(0.0..10.0, 10).slice(2, 3);
+    /// assert_eq!(it.bounds(), Some(2.0..=4.0));
+    /// assert!(it.rev().eq([4.0, 3.0, 2.0]));
+    ///
+    /// assert_eq!(lin_space(0.0..10.0, 10).slice(8, 5).len(), 2);
+    /// ```
+    pub fn slice(self, offset: usize, len: usize) -> Self {
+        let Range { start, end } = self.range;
+        let start = start.saturating_add(offset).min(end);
+        let end = start.saturating_add(len).min(end);
+        Space::with_range(start..end, self.interpolate)
+    }
+
+    /// The number of values in the space when it was created
+    ///
+    /// ```
+    /// use iter_num_tools::lin_space;
+    ///
+    /// let mut it = lin_space(0.0..1.0, 4);
+    /// it.next();
+    /// assert_eq!(it.total_len(), 4);
+    /// ```
+    pub fn total_len(&self) -> usize {
+        self.total
+    }
+
+    /// The number of values taken from either end of the space so far
+    ///
+    /// ```
+    /// use iter_num_tools::lin_space;
+    ///
+    /// let mut it = lin_space(0.0..1.0, 4);
+    /// it.next();
+    /// it.next_back();
+    /// assert_eq!(it.consumed(), 2);
+    /// ```
+    pub fn consumed(&self) -> usize {
+        self.total - self.range.len()
+    }
+
+    /// The fraction of the space that has been consumed, from 0 to 1.
+    /// An empty space has nothing left to do, so it is complete.
+    ///
+    /// ```
+    /// use iter_num_tools::lin_space;
+    ///
+    /// let mut it = lin_space(0.0..1.0, 4);
+    /// assert_eq!(it.progress(), 0.0);
+    /// it.next();
+    /// assert_eq!(it.progress(), 0.25);
+    /// ```
+    pub fn progress(&self) -> f64 {
+        match self.total {
+            0 => 1.0,
+            total => self.consumed() as f64 / total as f64,
+        }
+    }
+}
+
+impl<I: Interpolate> Space<I> {
+    /// The first and last values remaining in the space, or `None` if it is empty
+    ///
+    /// ```
+    /// use iter_num_tools::lin_space;
+    ///
+    /// let mut it = lin_space(0.0..5.0, 5);
+    /// assert_eq!(it.bounds(), Some(0.0..=4.0));
+    ///
+    /// it.next_back();
+    /// assert_eq!(it.bounds(), Some(0.0..=3.0));
+    ///
+    /// assert_eq!(lin_space(0.0..5.0, 0).bounds(), None);
+    /// ```
+    pub fn bounds(&self) -> Option<RangeInclusive<I::Item>> {
+        let Range { start, end } = self.range;
+        if start >= end {
+            return None;
+        }
+        let first = self.interpolate.interpolate(start);
+        let last = self.interpolate.interpolate(end - 1);
+        Some(first..=last)
+    }
+
+    /// The next value from the front of the space, without consuming it,
+    /// or `None` if it is empty
+    ///
+    /// ```
+    /// use iter_num_tools::lin_space;
+    ///
+    /// let mut it = lin_space(0.0..5.0, 5);
+    /// it.next();
+    /// assert_eq!(it.first_value(), Some(1.0));
+    /// assert_eq!(it.next(), Some(1.0));
+    /// ```
+    pub fn first_value(&self) -> Option<I::Item> {
+        let Range { start, end } = self.range;
+        (start < end).then(|| self.interpolate.interpolate(start))
+    }
+
+    /// The next value from the back of the space, without consuming it,
+    /// or `None` if it is empty
+    ///
+    /// This is the same as `space.clone().last()`, without needing the clone.
+    ///
+    /// ```
+    /// use iter_num_tools::lin_space;
+    ///
+    /// let mut it = lin_space(0.0..5.0, 5);
+    /// it.next_back();
+    /// assert_eq!(it.last_value(), Some(3.0));
+    /// assert_eq!(it.next_back(), Some(3.0));
+    /// ```
+    pub fn last_value(&self) -> Option<I::Item> {
+        let Range { start, end } = self.range;
+        (start < end).then(|| self.interpolate.interpolate(end - 1))
+    }
+}
+
+impl<I: Interpolate + Clone> Space<I>
+where
+    I::Item: PartialEq + fmt::Debug,
+{
+    /// Checks that the space agrees with itself, panicking with the first inconsistency found.
+    ///
+    /// Every value is computed from its index, so `next`, `next_back` and `nth` should all produce the same values,
+    /// matching the length and the [`bounds`](Self::bounds).
+    /// `fold` should too, except in spaces such as [`poly_space`](crate::poly_space) which step from one value to the next when folding,
+    /// where each value only has to be within the rounding error that space documents.
+    /// This is mostly useful in tests of a new kind of space, where `fold` is specialized to step from one value to the next.
+    ///
+    /// It walks over the whole space, so it does nothing in release builds.
+    ///
+    /// ```
+    /// use iter_num_tools::lin_space;
+    ///
+    /// let mut it = lin_space(0.0..=1.0, 5);
+    /// it.next();
+    /// it.verify_invariants();
+    /// ```
+    pub fn verify_invariants(&self) {
+        if !cfg!(debug_assertions) {
+            return;
+        }
+
+        let Range { start, end } = self.range;
+        assert!(start <= end, "space has a reversed range {start}..{end}");
+        assert!(
+            end - start <= self.total,
+            "space has {} values remaining, out of {} in total",
+            end - start,
+            self.total
+        );
+
+        let mut index = start;
+        self.clone().fold((), |(), x| {
+            assert!(index < end, "fold continued past the end");
+            assert!(
+                self.interpolate.fold_agrees(index, &x),
+                "fold disagrees with next at {index}: {x:?} != {:?}",
+                self.interpolate.interpolate(index)
+            );
+            index += 1;
+        });
+        assert_eq!(index, end, "fold stopped early");
+
+        let mut backward = self.clone();
+        for n in (0..self.len()).rev() {
+            let x = self.clone().nth(n);
+            assert_eq!(
+                backward.next_back(),
+                x,
+                "next_back disagrees with nth at {}",
+                start + n
+            );
+        }
+        assert_eq!(
+            backward.next_back(),
+            None,
+            "next_back continued past the end"
+        );
+
+        assert_eq!(
+            self.first_value(),
+            self.clone().next(),
+            "first_value disagrees with next"
+        );
+        assert_eq!(
+            self.last_value(),
+            self.clone().next_back(),
+            "last_value disagrees with next_back"
+        );
+    }
+}
+
+impl<I: Interpolate> Space<I> {
+    /// Writes the remaining values into the start of `out`, without consuming the space.
+    /// Returns how many were written, which is the smaller of the two lengths.
+    ///
+    /// ```
+    /// use iter_num_tools::lin_space;
+    ///
+    /// let mut buf = [0.0; 8];
+    /// let n = lin_space(0.0..=1.0, 5).fill_slice(&mut buf);
+    /// assert_eq!(&buf[..n], [0.0, 0.25, 0.5, 0.75, 1.0]);
+    /// ```
+    pub fn fill_slice(&self, out: &mut [I::Item]) -> usize {
+        let n = self.len().min(out.len());
+        let start = self.range.start;
+        let mut slots = out.iter_mut();
+        self.interpolate.fold_range(start..start + n, (), |(), x| {
+            *slots.next().unwrap() = x;
+        });
+        n
+    }
+
+    /// Takes as many values as fit into `out`, writing them to the start of it.
+    /// Returns how many were written, like [`fill_slice`](Self::fill_slice),
+    /// but the values are consumed, so the next call carries on where this one stopped.
+    ///
+    /// This doesn't allocate, so it suits filling fixed buffers, such as blocks of audio samples.
+    ///
+    /// ```
+    /// use iter_num_tools::lin_space;
+    ///
+    /// let mut ramp = lin_space(0.0..1.5, 6);
+    /// let mut block = [0.0; 4];
+    ///
+    /// assert_eq!(ramp.fill(&mut block), 4);
+    /// assert_eq!(block, [0.0, 0.25, 0.5, 0.75]);
+    /// assert_eq!(ramp.fill(&mut block), 2);
+    /// assert_eq!(block[..2], [1.0, 1.25]);
+    /// assert_eq!(ramp.fill(&mut block), 0);
+    /// ```
+    pub fn fill(&mut self, out: &mut [I::Item]) -> usize {
+        let n = self.fill_slice(out);
+        self.range.start += n;
+        n
+    }
+
+    /// Calls `f` on each chunk of the remaining values, in order,
+    /// using `scratch` as the buffer to fill each chunk into.
+    ///
+    /// Each chunk is as long as `scratch`, except possibly the last.
+    /// This doesn't allocate, and stays in the cache better than collecting everything and then splitting it up.
+    ///
+    /// ```
+    /// use iter_num_tools::lin_space;
+    ///
+    /// let mut sums = vec![];
+    /// lin_space(0.0..5.0, 5).for_each_chunk(&mut [0.0; 2], |chunk| sums.push(chunk.iter().sum::<f64>()));
+    /// assert_eq!(sums, [1.0, 5.0, 4.0]);
+    /// ```
+    ///
+    /// # Panics
+    ///
+    /// Panics if `scratch` is empty
+    pub fn for_each_chunk<F>(mut self, scratch: &mut [I::Item], mut f: F)
+    where
+        F: FnMut(&[I::Item]),
+    {
+        assert!(!scratch.is_empty(), "scratch buffer must not be empty");
+        loop {
+            let n = self.fill(scratch);
+            if n == 0 {
+                break;
+            }
+            f(&scratch[..n]);
         }
     }
+
+    /// Collects the remaining values into a [`Vec`](alloc::vec::Vec) without consuming the space,
+    /// allocating exactly once
+    ///
+    /// ```
+    /// use iter_num_tools::lin_space;
+    ///
+    /// let it = lin_space(0.0..1.0, 4);
+    /// assert_eq!(it.to_vec(), [0.0, 0.25, 0.5, 0.75]);
+    /// assert_eq!(it.len(), 4);
+    /// ```
+    #[cfg(feature = "alloc")]
+    pub fn to_vec(&self) -> alloc::vec::Vec<I::Item> {
+        let mut values = alloc::vec::Vec::with_capacity(self.len());
+        self.interpolate
+            .fold_range(self.range.clone(), (), |(), x| values.push(x));
+        values
+    }
+
+    /// Collects the remaining values into a boxed slice without consuming the space,
+    /// allocating exactly once
+    #[cfg(feature = "alloc")]
+    pub fn to_boxed_slice(&self) -> alloc::boxed::Box<[I::Item]> {
+        self.to_vec().into_boxed_slice()
+    }
 }
 
-impl<I: Interpolate + Copy> Iterator for Space<I> {
+impl<I: Interpolate> Space<I>
+where
+    I::Item: PartialOrd,
+{
+    /// Searches the remaining values of a monotonic space, like [`slice::binary_search`].
+    ///
+    /// `guess` is where the value is expected to be, counting from the first remaining value.
+    /// Only the neighbours of the guess are checked if it is right, otherwise this falls back to
+    /// a binary search, so rounding in the guess never gives a wrong answer.
+    pub(crate) fn search_with_guess(&self, value: &I::Item, guess: usize) -> Result<usize, usize> {
+        let len = self.len();
+        let at = |i: usize| self.interpolate.interpolate(self.range.start + i);
+        if len == 0 {
+            return Err(0);
+        }
+        let ascending = at(0) <= at(len - 1);
+        let before = |i: usize| match ascending {
+            true => at(i) < *value,
+            false => at(i) > *value,
+        };
+
+        // the values before the partition point are all before the value
+        let guess = guess.min(len);
+        let (lo, hi) = (guess.saturating_sub(1), (guess + 1).min(len));
+        let (mut lo, mut hi) = match (lo == 0 || before(lo - 1)) && (hi == len || !before(hi)) {
+            true => (lo, hi),
+            false => (0, len),
+        };
+        while lo < hi {
+            let mid = lo + (hi - lo) / 2;
+            match before(mid) {
+                true => lo = mid + 1,
+                false => hi = mid,
+            }
+        }
+        match lo < len && at(lo) == *value {
+            true => Ok(lo),
+            false => Err(lo),
+        }
+    }
+}
+
+impl<I: Monotonic> Space<I> {
+    /// Which way the values go, so searching or plotting can rely on them being in order
+    ///
+    /// ```
+    /// use iter_num_tools::{lin_space, log_space, Direction};
+    ///
+    /// assert_eq!(lin_space(0.0..=1.0, 5).direction(), Direction::Increasing);
+    /// assert_eq!(log_space(100.0..=1.0, 3).direction(), Direction::Decreasing);
+    ///
+    /// let it = lin_space(-1.0..=1.0, 5).map_with_bounds(|x| x * x, f64::sqrt);
+    /// assert_eq!(it.direction(), Direction::NonMonotonic);
+    /// assert!(!it.is_monotonic());
+    /// ```
+    pub fn direction(&self) -> Direction {
+        self.interpolate.direction()
+    }
+
+    /// Whether the values never go back the other way, which includes constant spaces
+    pub fn is_monotonic(&self) -> bool {
+        self.direction() != Direction::NonMonotonic
+    }
+}
+
+/// Shows the name, bounds and length of the space, rather than its internals
+///
+/// ```
+/// use iter_num_tools::lin_space;
+///
+/// let it = lin_space(0.0..=5.0, 6);
+/// assert_eq!(format!("{it:?}"), "LinSpace { 0.0..=5.0, len: 6, step: 1.0 }");
+/// ```
+impl<I: SpaceFmt> fmt::Debug for Space<I>
+where
+    I::Item: fmt::Debug,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{} {{ ", I::NAME)?;
+        match self.bounds() {
+            Some(bounds) => write!(f, "{bounds:?}")?,
+            None => f.write_str("empty")?,
+        }
+        write!(f, ", len: {}", self.len())?;
+        self.interpolate.fmt_params(f)?;
+        f.write_str(" }")
+    }
+}
+
+/// Shows the bounds and length of the space
+///
+/// ```
+/// use iter_num_tools::grid_space;
+///
+/// let it = grid_space([0, 0]..=[2, 2], 3);
+/// assert_eq!(it.to_string(), "[0, 0]..=[2, 2] (9 values)");
+/// ```
+impl<I: SpaceFmt> fmt::Display for Space<I>
+where
+    I::Item: fmt::Debug,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self.bounds() {
+            Some(bounds) => write!(f, "{bounds:?} ({} values)", self.len()),
+            None => f.write_str("empty"),
+        }
+    }
+}
+
+/// Maps the remaining index range onto a space with `factor` times as many intervals
+pub(crate) fn subdivide_range(range: Range<usize>, factor: usize) -> Range<usize> {
+    if range.is_empty() {
+        return 0..0;
+    }
+    let end = (range.end - 1)
+        .checked_mul(factor)
+        .expect("subdivided space is too large");
+    range.start * factor..end + 1
+}
+
+/// Maps the remaining index range onto a space with `factor` times fewer intervals,
+/// if both the first and last index line up with the coarser space
+pub(crate) fn coarsen_range(range: Range<usize>, factor: usize) -> Option<Range<usize>> {
+    if factor == 0 {
+        return None;
+    }
+    if range.is_empty() {
+        return Some(0..0);
+    }
+    let last = range.end - 1;
+    if range.start % factor != 0 || last % factor != 0 {
+        return None;
+    }
+    Some(range.start / factor..last / factor + 1)
+}
+
+impl<I: Interpolate> Iterator for Space<I> {
     type Item = I::Item;
 
     fn next(&mut self) -> Option<Self::Item> {
@@ -75,13 +677,20 @@ impl<I: Interpolate + Copy> Iterator for Space<I> {
         self.range.nth(n).map(|x| self.interpolate.interpolate(x))
     }
 
+    fn fold<B, F>(self, init: B, f: F) -> B
+    where
+        F: FnMut(B, Self::Item) -> B,
+    {
+        self.interpolate.fold_range(self.range, init, f)
+    }
+
     fn size_hint(&self) -> (usize, Option<usize>) {
         let len = self.len();
         (len, Some(len))
     }
 }
 
-impl<I: Interpolate + Copy> DoubleEndedIterator for Space<I> {
+impl<I: Interpolate> DoubleEndedIterator for Space<I> {
     #[inline]
     fn next_back(&mut self) -> Option<Self::Item> {
         self.range
@@ -101,16 +710,270 @@ impl<I: Interpolate + Copy> DoubleEndedIterator for Space<I> {
     }
 }
 
-impl<I: Interpolate + Copy> ExactSizeIterator for Space<I> {
+impl<I: Interpolate> ExactSizeIterator for Space<I> {
     #[inline]
     fn len(&self) -> usize {
         self.range.len()
     }
 }
 
-impl<I: Interpolate + Copy> FusedIterator for Space<I> {}
+impl<I: Interpolate> FusedIterator for Space<I> {}
+
+/// Iterates over the remaining values without consuming the space, so it can be iterated again
+///
+/// ```
+/// use iter_num_tools::lin_space;
+///
+/// let it = lin_space(0.0..=1.0, 3);
+/// for _ in 0..2 {
+///     let mut values = vec![];
+///     for x in &it {
+///         values.push(x);
+///     }
+///     assert_eq!(values, [0.0, 0.5, 1.0]);
+/// }
+/// ```
+impl<I: Interpolate + Clone> IntoIterator for &Space<I> {
+    type Item = I::Item;
+    type IntoIter = Space<I>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.clone()
+    }
+}
 
 #[cfg(feature = "trusted_len")]
 use core::iter::TrustedLen;
 #[cfg(feature = "trusted_len")]
-unsafe impl<I: Interpolate + Copy> TrustedLen for Space<I> {}
+unsafe impl<I: Interpolate> TrustedLen for Space<I> {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// An interpolation that owns its values, so it is neither `Copy` nor `Clone`
+    struct Lookup(Vec<String>);
+
+    impl Interpolate for Lookup {
+        type Item = String;
+        fn interpolate(&self, x: usize) -> String {
+            self.0[x].clone()
+        }
+    }
+
+    #[test]
+    fn test_borrowed_interpolation() {
+        let names = ["a", "b", "c"].map(String::from);
+        let it = Space::new(3, Lookup(names.to_vec()));
+        assert_eq!(it.bounds(), Some("a".to_owned()..="c".to_owned()));
+        assert!(it.rev().eq(names.into_iter().rev()));
+    }
+
+    #[test]
+    fn test_into_space_non_copy() {
+        // the interpolation is moved into the space, so it doesn't need to be `Copy`
+        let names = ["a", "b", "c"].map(String::from);
+        let mut seen = vec![];
+        for name in IntoSpace::new(3, Lookup(names.to_vec())) {
+            seen.push(name);
+        }
+        assert_eq!(seen, names);
+    }
+
+    #[test]
+    fn test_empty() {
+        let it = crate::LogSpace::<f64>::empty();
+        assert_eq!((it.len(), it.total_len(), it.progress()), (0, 0, 1.0));
+        assert!(it.is_empty());
+        assert_eq!(crate::PreciseLinSpace::<f32>::empty().next(), None);
+
+        assert!(crate::lin_space(0.0..1.0, 0).is_empty());
+        assert!(crate::lin_space(0.0..1.0, 4).slice(4, 1).is_empty());
+    }
+
+    #[test]
+    fn test_first_last_value() {
+        let mut it = Space::new(3, Lookup(["a", "b", "c"].map(String::from).to_vec()));
+        assert_eq!(it.first_value().as_deref(), Some("a"));
+        assert_eq!(it.last_value().as_deref(), Some("c"));
+        it.nth(1);
+        assert_eq!(it.first_value(), it.last_value());
+        it.next();
+        assert_eq!((it.first_value(), it.last_value()), (None, None));
+    }
+
+    #[test]
+    fn test_iter_ref() {
+        let mut it = crate::grid_space([0, 0]..[2, 2], 2);
+        it.next();
+        assert!((&it).into_iter().eq([[1, 0], [0, 1], [1, 1]]));
+        assert_eq!(it.len(), 3);
+
+        let total: i32 = (&it).into_iter().flatten().sum();
+        assert_eq!(total, 4);
+        assert_eq!(it.next(), Some([1, 0]));
+    }
+
+    #[test]
+    fn test_into_space_len() {
+        use crate::ToGridSpace;
+        let space = ([0.0; 3]..[1.0; 3]).into_grid_space([2, 3, 4]);
+        assert_eq!((space.len(), space.is_empty()), (24, false));
+        assert!(IntoSpace::new(0, Lookup(vec![])).is_empty());
+    }
+
+    #[test]
+    fn test_for_each_chunk() {
+        let mut it = crate::lin_space(0..10, 10);
+        it.next();
+        let mut chunks = vec![];
+        it.for_each_chunk(&mut [0; 4], |chunk| chunks.push(chunk.to_vec()));
+        assert_eq!(chunks, [vec![1, 2, 3, 4], vec![5, 6, 7, 8], vec![9]]);
+
+        let mut chunks = 0;
+        crate::lin_space(0..10, 0).for_each_chunk(&mut [0; 4], |_| chunks += 1);
+        assert_eq!(chunks, 0);
+    }
+
+    #[test]
+    #[should_panic(expected = "scratch buffer must not be empty")]
+    fn test_for_each_chunk_empty_scratch() {
+        crate::lin_space(0..10, 10).for_each_chunk(&mut [], |_| {});
+    }
+
+    #[test]
+    fn test_progress() {
+        let mut it = crate::lin_space(0.0..=1.0, 5).with_index();
+        it.nth(1);
+        assert_eq!((it.consumed(), it.total_len()), (2, 5));
+        it.nth_back(2);
+        assert_eq!(it.progress(), 1.0);
+        assert_eq!(it.next(), None);
+
+        let it = Space::new(0, Lookup(vec![]));
+        assert_eq!((it.consumed(), it.progress()), (0, 1.0));
+    }
+
+    #[test]
+    fn test_fill_slice() {
+        let mut it = crate::lin_space(0..10, 10);
+        it.nth(2);
+        let mut buf = [0; 4];
+        assert_eq!(it.fill_slice(&mut buf), 4);
+        assert_eq!(buf, [3, 4, 5, 6]);
+        assert_eq!(it.fill_slice(&mut []), 0);
+
+        let mut buf = [0; 4];
+        assert_eq!(it.slice(5, 3).fill_slice(&mut buf), 2);
+        assert_eq!(buf, [8, 9, 0, 0]);
+    }
+
+    #[test]
+    fn test_fill() {
+        let mut it = crate::lin_space(0..10, 10);
+        it.next_back();
+        let mut buf = [0; 4];
+        assert_eq!(it.fill(&mut buf), 4);
+        assert_eq!((buf, it.consumed()), ([0, 1, 2, 3], 5));
+        assert_eq!(it.fill(&mut buf), 4);
+        assert_eq!(buf, [4, 5, 6, 7]);
+        assert_eq!(it.fill(&mut buf), 1);
+        assert_eq!(it.next(), None);
+    }
+
+    #[test]
+    #[cfg(feature = "alloc")]
+    fn test_to_vec() {
+        let mut it = crate::lin_space(0..10, 10);
+        it.nth(2);
+        it.next_back();
+        assert_eq!(it.to_vec(), [3, 4, 5, 6, 7, 8]);
+        assert_eq!(&*it.to_boxed_slice(), [3, 4, 5, 6, 7, 8]);
+
+        let names = Space::new(2, Lookup(vec!["a".into(), "b".into()]));
+        assert_eq!(names.to_vec(), ["a", "b"]);
+    }
+
+    #[test]
+    fn test_direction() {
+        use crate::{lin_space, log_space, logit_space};
+
+        assert_eq!(lin_space(1..=1, 4).direction(), Direction::Constant);
+        assert_eq!(lin_space(3.0..0.0, 3).direction(), Direction::Decreasing);
+        assert_eq!(log_space(-1.0..=-8.0, 4).direction(), Direction::Decreasing);
+        assert_eq!(log_space(-8.0..=-1.0, 4).direction(), Direction::Increasing);
+        assert_eq!(logit_space(0.9..=0.1, 3).direction(), Direction::Decreasing);
+        assert_eq!(
+            lin_space(1.0..0.0, 5).stride(2).direction(),
+            Direction::Decreasing
+        );
+        assert_eq!(
+            lin_space(0.0..f64::NAN, 3).direction(),
+            Direction::NonMonotonic
+        );
+        assert!(lin_space(0.0..1.0, 0).is_monotonic());
+    }
+
+    #[test]
+    fn test_slice() {
+        let mut it = crate::lin_space(0..10, 10);
+        it.next();
+        let it = it.slice(2, 3);
+        assert_eq!((it.len(), it.total_len()), (3, 3));
+        assert_eq!(it.bounds(), Some(3..=5));
+
+        assert_eq!(it.clone().slice(5, 1).bounds(), None);
+        assert_eq!(it.clone().slice(1, usize::MAX).bounds(), Some(4..=5));
+        assert_eq!(it.slice(usize::MAX, usize::MAX).len(), 0);
+    }
+
+    #[test]
+    fn test_verify_invariants() {
+        let mut it = crate::lin_space(0.0..=1.0, 9);
+        it.next();
+        it.next_back();
+        it.verify_invariants();
+        crate::log_space(1.0..1e6, 100).verify_invariants();
+        crate::poly_space([1, -2, 1], -10..10, 20).verify_invariants();
+        crate::grid_space([0, 0]..=[4, 9], [5, 10]).verify_invariants();
+        crate::lin_space(0.0..1.0, 0).verify_invariants();
+        crate::lin_space(0.0..=328608.8304575479, 8).verify_invariants();
+        crate::lin_space(0.0_f32..=1.0, 100_001).verify_invariants();
+
+        // these step from one value to the next when folding, so only agree within their rounding error
+        crate::poly_space([0.1, -3.0, 0.7, 0.01], -5.0..=7.3, 1000).verify_invariants();
+        crate::poly_space([0.1, -3.0, 0.7, 0.01], -5.0..=7.3, 1000)
+            .compensated()
+            .verify_invariants();
+        crate::sin_space(0.0..=100.0, 1000).verify_invariants();
+        crate::cos_space(0.0..=100.0, 1000).verify_invariants();
+        crate::sin_space(-1e4_f32..=1e4, 10_000).verify_invariants();
+        crate::poly_space([1.0, 2.0], 0.0..=1.0, 5)
+            .map_fn(|x: f64| x * 2.0)
+            .verify_invariants();
+    }
+
+    /// Steps along one too far when folding
+    #[derive(Clone)]
+    struct OffByOne;
+
+    impl Interpolate for OffByOne {
+        type Item = usize;
+        fn interpolate(&self, x: usize) -> usize {
+            x
+        }
+
+        fn fold_range<B, F>(&self, range: Range<usize>, init: B, f: F) -> B
+        where
+            F: FnMut(B, usize) -> B,
+        {
+            (range.start + 1..range.end + 1).fold(init, f)
+        }
+    }
+
+    #[test]
+    #[should_panic(expected = "fold disagrees with next at 0")]
+    fn test_verify_invariants_fold() {
+        Space::new(3, OffByOne).verify_invariants();
+    }
+}
@@ -0,0 +1,166 @@
+use core::fmt;
+use num_traits::{real::Real, FloatConst, FromPrimitive};
+
+use crate::{
+    fp,
+    space::{Interpolate, Space, SpaceFmt},
+};
+
+/// Creates an iterator over `n` points spread approximately evenly over the surface of a sphere,
+/// centered on the origin.
+///
+/// The points lie on a Fibonacci lattice: evenly spaced in height,
+/// and rotated by the golden angle around the vertical axis each time.
+///
+/// ```
+/// use iter_num_tools::sphere_space;
+///
+/// let it = sphere_space(2.0_f64, 100);
+/// assert_eq!(it.len(), 100);
+/// assert!(it.clone().all(|[x, y, z]| ((x * x + y * y + z * z).sqrt() - 2.0_f64).abs() < 1e-12));
+///
+/// // as many points above the equator as below
+/// assert_eq!(it.filter(|[_, _, z]| *z > 0.0).count(), 50);
+/// ```
+pub fn sphere_space<T: Real + FloatConst + FromPrimitive>(radius: T, n: usize) -> SphereSpace<T> {
+    Space::new(n, SphereInterpolation { radius, n })
+}
+
+/// Creates an iterator over `n` points spread approximately evenly over a disk,
+/// centered on the origin.
+///
+/// The points lie on a sunflower spiral (Vogel's model): each point covers an equal area,
+/// and is rotated by the golden angle from the last.
+///
+/// ```
+/// use iter_num_tools::disk_space;
+///
+/// let it = disk_space(1.0_f64, 100);
+/// assert_eq!(it.len(), 100);
+/// assert!(it.clone().all(|[x, y]| x * x + y * y < 1.0_f64));
+///
+/// // a quarter of the points fall in the inner half of the radius
+/// assert_eq!(it.filter(|[x, y]| x * x + y * y < 0.25).count(), 25);
+/// ```
+pub fn disk_space<T: Real + FloatConst + FromPrimitive>(radius: T, n: usize) -> DiskSpace<T> {
+    Space::new(n, DiskInterpolation { radius, n })
+}
+
+/// The angle of the `i`th point around the spiral, which is `i` golden angles.
+///
+/// Only the fractional number of turns matters,
+/// so that is found first to keep the angle accurate for large `i`.
+fn golden_angle<T: Real + FloatConst + FromPrimitive>(i: usize) -> T {
+    // the golden angle is (2 - φ) turns
+    let turns = T::from_usize(i).unwrap() * T::from_f64(0.381_966_011_250_105_15).unwrap();
+    (turns - turns.floor()) * T::TAU()
+}
+
+/// The center of the `i`th of `n` equal parts of the range `0..1`
+fn center<T: Real + FromPrimitive>(i: usize, n: usize) -> T {
+    let half = T::from_f64(0.5).unwrap();
+    (T::from_usize(i).unwrap() + half) / T::from_usize(n).unwrap()
+}
+
+#[derive(Clone, Copy, Debug)]
+pub struct SphereInterpolation<T> {
+    pub radius: T,
+    pub n: usize,
+}
+
+impl<T: Real + FloatConst + FromPrimitive> Interpolate for SphereInterpolation<T> {
+    type Item = [T; 3];
+    fn interpolate(&self, i: usize) -> [T; 3] {
+        let two = T::one() + T::one();
+        let z = T::one() - two * center::<T>(i, self.n);
+        let r = (T::one() - z * z).sqrt();
+        let (sin, cos) = fp::sin_cos(golden_angle::<T>(i));
+        [r * cos, r * sin, z].map(|x| x * self.radius)
+    }
+}
+
+impl<T: Real + FloatConst + FromPrimitive + fmt::Debug> SpaceFmt for SphereInterpolation<T> {
+    const NAME: &'static str = "SphereSpace";
+    fn fmt_params(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, ", radius: {:?}", self.radius)
+    }
+}
+
+#[derive(Clone, Copy, Debug)]
+pub struct DiskInterpolation<T> {
+    pub radius: T,
+    pub n: usize,
+}
+
+impl<T: Real + FloatConst + FromPrimitive> Interpolate for DiskInterpolation<T> {
+    type Item = [T; 2];
+    fn interpolate(&self, i: usize) -> [T; 2] {
+        let r = center::<T>(i, self.n).sqrt() * self.radius;
+        let (sin, cos) = fp::sin_cos(golden_angle::<T>(i));
+        [r * cos, r * sin]
+    }
+}
+
+impl<T: Real + FloatConst + FromPrimitive + fmt::Debug> SpaceFmt for DiskInterpolation<T> {
+    const NAME: &'static str = "DiskSpace";
+    fn fmt_params(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, ", radius: {:?}", self.radius)
+    }
+}
+
+/// [`Iterator`] returned by [`sphere_space`]
+pub type SphereSpace<T> = Space<SphereInterpolation<T>>;
+
+/// [`Iterator`] returned by [`disk_space`]
+pub type DiskSpace<T> = Space<DiskInterpolation<T>>;
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_sphere_space() {
+        let points: Vec<[f64; 3]> = sphere_space(1.0, 1000).collect();
+
+        // the center of mass is at the origin
+        let mut sum = [0.0; 3];
+        for p in &points {
+            for (s, x) in sum.iter_mut().zip(p) {
+                *s += x;
+            }
+        }
+        assert!(sum.iter().all(|s| s.abs() / 1000.0 < 1e-3), "{sum:?}");
+
+        // no two points are much closer than the average spacing
+        let min_dist = points
+            .iter()
+            .enumerate()
+            .flat_map(|(i, a)| points[..i].iter().map(move |b| (a, b)))
+            .map(|(a, b)| (0..3).map(|k| (a[k] - b[k]).powi(2)).sum::<f64>().sqrt())
+            .fold(f64::INFINITY, f64::min);
+        assert!(min_dist > 0.05, "{min_dist}");
+
+        assert_eq!(sphere_space(1.0_f32, 1).next(), Some([1.0, 0.0, 0.0]));
+        assert_eq!(sphere_space(1.0, 0).next(), None::<[f64; 3]>);
+    }
+
+    #[test]
+    fn test_disk_space() {
+        let it = disk_space(3.0, 200);
+        let points: Vec<[f64; 2]> = it.clone().collect();
+        assert!(points.iter().all(|[x, y]| x.hypot(*y) < 3.0));
+
+        // equal area per point, so the ring from 1 to 2 holds (4 - 1) / 9 of them
+        let ring = points
+            .iter()
+            .filter(|[x, y]| (1.0..2.0).contains(&x.hypot(*y)))
+            .count();
+        assert!(ring.abs_diff(200 / 3) <= 1, "{ring}");
+
+        assert!(it.rev().eq(points.into_iter().rev()));
+        assert_eq!(
+            format!("{:?}", disk_space(1.0, 0)),
+            "DiskSpace { empty, len: 0, radius: 1.0 }"
+        );
+    }
+}
@@ -0,0 +1,371 @@
+use core::fmt;
+use core::ops::Range;
+
+use crate::{
+    gridspace::grid_len,
+    linspace::{LinearInterpolation, ToLinSpace},
+    map::{Function, MapInterpolation},
+    space::{Interpolate, IntoSpace, Space, SpaceFmt},
+};
+
+/// Creates a space over every combination of the values of a tuple of spaces,
+/// such as a sweep over hyperparameters.
+///
+/// Like [`grid_space`](crate::grid_space), the first space varies the fastest.
+/// The spaces can be of different types, and any values already consumed from them are left out.
+///
+/// ```
+/// use iter_num_tools::{lin_space, log_space, sweep};
+///
+/// let it = sweep((log_space(1e-3..=1e-1, 3), lin_space(16..=32, 2)));
+/// assert_eq!(it.len(), 6);
+///
+/// // look up the configuration for a given job
+/// assert_eq!(it.get(4).map(|(lr, batch)| batch), Some(32));
+/// assert_eq!(it.get(6), None);
+/// ```
+///
+/// An array of spaces of the same type yields an array of values instead,
+/// like [`grid_space`](crate::grid_space) but with any kind of space along each axis.
+///
+/// ```
+/// use iter_num_tools::{octave_space, sweep};
+///
+/// let it = sweep([octave_space(1.0..=4.0), octave_space(1.0..=2.0)]);
+/// assert!(it.eq([[1.0, 1.0], [2.0, 1.0], [4.0, 1.0], [1.0, 2.0], [2.0, 2.0], [4.0, 2.0]]));
+/// ```
+///
+/// The [`sweep!`](macro@crate::sweep) macro gives each parameter a name.
+///
+/// # Panics
+///
+/// Panics if the total number of combinations overflows a `usize`
+pub fn sweep<S: ToSweep>(spaces: S) -> Sweep<S::Axes> {
+    spaces.into_sweep().into_space()
+}
+
+/// Creates a [`sweep`](fn@crate::sweep) that yields a struct with a field for each named space
+///
+/// The result is still a space, [`map_fn`](crate::Space::map_fn)ped into the struct,
+/// so the configuration for a given index can be looked up with `get`.
+///
+/// ```
+/// use iter_num_tools::{lin_space, log_space, sweep};
+///
+/// let mut it = sweep!(
+///     lr: log_space(1e-3..=1e-1, 3),
+///     batch: lin_space(16..=32, 2),
+/// );
+/// assert_eq!(it.len(), 6);
+/// assert_eq!(it.get(4).map(|config| config.batch), Some(32));
+///
+/// let config = it.next().unwrap();
+/// assert_eq!(config.batch, 16);
+/// assert!((config.lr - 1e-3_f64).abs() < 1e-12);
+/// ```
+#[macro_export]
+macro_rules! sweep {
+    ($($name:ident: $space:expr),+ $(,)?) => {{
+        #[allow(non_camel_case_types)]
+        #[derive(Clone, Copy, Debug, PartialEq)]
+        struct Config<$($name),+> {
+            $($name: $name),+
+        }
+        #[derive(Clone, Copy, Debug)]
+        struct ToConfig;
+        #[allow(non_camel_case_types)]
+        impl<$($name),+> $crate::Function<($($name,)+)> for ToConfig {
+            type Output = Config<$($name),+>;
+            fn call(&self, ($($name,)+): ($($name,)+)) -> Self::Output {
+                Config { $($name),+ }
+            }
+        }
+        $crate::sweep(($($space,)+)).map_fn(ToConfig)
+    }};
+}
+
+/// Creates a linear grid space over a tuple of ranges, which can each be of a different type
+///
+/// This is a [`sweep`](fn@crate::sweep) over a [`lin_space`](crate::lin_space) for each range,
+/// so like [`grid_space`](crate::grid_space) the first range varies the fastest.
+/// The steps can be given for each range, or as one count for all of them.
+///
+/// ```
+/// use iter_num_tools::grid_space_tuple;
+///
+/// let it = grid_space_tuple((0.0..=1.0, 0_u8..=20), [3, 2]);
+/// assert!(it.eq([
+///     (0.0, 0), (0.5, 0), (1.0, 0),
+///     (0.0, 20), (0.5, 20), (1.0, 20),
+/// ]));
+///
+/// let it = grid_space_tuple((0.0_f32..1.0, 0.0_f64..1.0, 0..10), 2);
+/// assert_eq!(it.len(), 8);
+/// ```
+///
+/// # Panics
+///
+/// Panics if the total number of points overflows a `usize`
+pub fn grid_space_tuple<R, S>(ranges: R, steps: S) -> Sweep<R::Axes>
+where
+    R: ToGridTuple<S>,
+{
+    ranges.into_grid_tuple(steps).into_space()
+}
+
+/// Helper trait for [`grid_space_tuple`], implemented for tuples of up to 12 ranges
+pub trait ToGridTuple<S> {
+    /// The linear interpolation and index range along each axis
+    type Axes;
+    /// Create the grid space
+    fn into_grid_tuple(self, steps: S) -> IntoSweep<Self::Axes>;
+}
+
+/// Helper trait for [`sweep`](fn@crate::sweep), implemented for tuples of up to 12 spaces,
+/// and for arrays of spaces of the same type
+pub trait ToSweep {
+    /// The interpolation and remaining index range of each space
+    type Axes;
+    /// Create the sweep
+    fn into_sweep(self) -> IntoSweep<Self::Axes>;
+}
+
+pub trait SweepAxes {
+    type Item;
+    const AXES: usize;
+    fn len(&self) -> usize;
+    fn get(&self, x: usize) -> Self::Item;
+}
+
+#[derive(Clone, Copy, Debug)]
+pub struct SweepInterpolation<A>(pub A);
+
+impl<A: SweepAxes> Interpolate for SweepInterpolation<A> {
+    type Item = A::Item;
+    fn interpolate(&self, x: usize) -> Self::Item {
+        self.0.get(x)
+    }
+}
+
+impl<A: SweepAxes> SpaceFmt for SweepInterpolation<A> {
+    const NAME: &'static str = "Sweep";
+    fn fmt_params(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, ", axes: {}", A::AXES)
+    }
+}
+
+macro_rules! impl_sweep {
+    ($($t:ident $idx:tt),+) => {
+        impl<$($t: Interpolate),+> SweepAxes for ($(($t, Range<usize>),)+) {
+            type Item = ($($t::Item,)+);
+            const AXES: usize = [$($idx),+].len();
+
+            fn len(&self) -> usize {
+                grid_len([$(self.$idx.1.len()),+])
+            }
+
+            #[allow(unused_assignments)]
+            fn get(&self, mut x: usize) -> Self::Item {
+                ($({
+                    let (interpolate, range) = &self.$idx;
+                    let z = x % range.len();
+                    x /= range.len();
+                    interpolate.interpolate(range.start + z)
+                },)+)
+            }
+        }
+
+        impl<$($t: Interpolate),+> ToSweep for ($(Space<$t>,)+) {
+            type Axes = ($(($t, Range<usize>),)+);
+
+            fn into_sweep(self) -> IntoSweep<Self::Axes> {
+                let axes = ($({
+                    let Space { interpolate, range, .. } = self.$idx;
+                    (interpolate, range)
+                },)+);
+                IntoSweep::new(axes.len(), SweepInterpolation(axes))
+            }
+        }
+
+        impl<$($t: ToLinSpace),+> ToGridTuple<usize> for ($($t,)+) {
+            type Axes = ($((LinearInterpolation<$t::Item>, Range<usize>),)+);
+
+            fn into_grid_tuple(self, steps: usize) -> IntoSweep<Self::Axes> {
+                let steps = [steps; [$($idx),+].len()];
+                self.into_grid_tuple(steps)
+            }
+        }
+
+        impl<$($t: ToLinSpace),+> ToGridTuple<[usize; [$($idx),+].len()]> for ($($t,)+) {
+            type Axes = ($((LinearInterpolation<$t::Item>, Range<usize>),)+);
+
+            fn into_grid_tuple(self, steps: [usize; [$($idx),+].len()]) -> IntoSweep<Self::Axes> {
+                let axes = ($({
+                    let IntoSpace { interpolate, len } = self.$idx.into_lin_space(steps[$idx]);
+                    (interpolate, 0..len)
+                },)+);
+                let len = grid_len([$(axes.$idx.1.len()),+]);
+                IntoSweep::new(len, SweepInterpolation(axes))
+            }
+        }
+    };
+}
+
+impl_sweep!(A 0);
+impl_sweep!(A 0, B 1);
+impl_sweep!(A 0, B 1, C 2);
+impl_sweep!(A 0, B 1, C 2, D 3);
+impl_sweep!(A 0, B 1, C 2, D 3, E 4);
+impl_sweep!(A 0, B 1, C 2, D 3, E 4, F 5);
+impl_sweep!(A 0, B 1, C 2, D 3, E 4, F 5, G 6);
+impl_sweep!(A 0, B 1, C 2, D 3, E 4, F 5, G 6, H 7);
+impl_sweep!(A 0, B 1, C 2, D 3, E 4, F 5, G 6, H 7, I 8);
+impl_sweep!(A 0, B 1, C 2, D 3, E 4, F 5, G 6, H 7, I 8, J 9);
+impl_sweep!(A 0, B 1, C 2, D 3, E 4, F 5, G 6, H 7, I 8, J 9, K 10);
+impl_sweep!(A 0, B 1, C 2, D 3, E 4, F 5, G 6, H 7, I 8, J 9, K 10, L 11);
+
+impl<I: Interpolate, const N: usize> SweepAxes for [(I, Range<usize>); N] {
+    type Item = [I::Item; N];
+    const AXES: usize = N;
+
+    fn len(&self) -> usize {
+        grid_len(self.each_ref().map(|(_, range)| range.len()))
+    }
+
+    fn get(&self, mut x: usize) -> Self::Item {
+        self.each_ref().map(|(interpolate, range)| {
+            let z = x % range.len();
+            x /= range.len();
+            interpolate.interpolate(range.start + z)
+        })
+    }
+}
+
+impl<I: Interpolate, const N: usize> ToSweep for [Space<I>; N] {
+    type Axes = [(I, Range<usize>); N];
+
+    fn into_sweep(self) -> IntoSweep<Self::Axes> {
+        let axes = self.map(|space| (space.interpolate, space.range));
+        IntoSweep::new(axes.len(), SweepInterpolation(axes))
+    }
+}
+
+/// [`Iterator`] returned by [`sweep`](fn@crate::sweep)
+pub type Sweep<A> = Space<SweepInterpolation<A>>;
+
+/// [`IntoIterator`] returned by [`ToSweep::into_sweep`]
+pub type IntoSweep<A> = IntoSpace<SweepInterpolation<A>>;
+
+impl<A: SweepAxes> Sweep<A> {
+    /// The configuration at `index` in the full sweep, regardless of how much has been consumed,
+    /// or `None` if the index is out of bounds
+    pub fn get(&self, index: usize) -> Option<A::Item> {
+        (index < self.interpolate.0.len()).then(|| self.interpolate.interpolate(index))
+    }
+}
+
+impl<A: SweepAxes, F: Function<A::Item>> Space<MapInterpolation<SweepInterpolation<A>, F>> {
+    /// The mapped configuration at `index` in the full sweep, such as from [`sweep!`](macro@crate::sweep),
+    /// or `None` if the index is out of bounds
+    pub fn get(&self, index: usize) -> Option<F::Output> {
+        (index < self.interpolate.inner.0.len()).then(|| self.interpolate.interpolate(index))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{arange, check_double_ended_iter, grid_step, lin_space};
+
+    #[test]
+    fn test_sweep() {
+        let it = sweep((
+            lin_space(0..=1, 2),
+            grid_step([0]..=[2]),
+            arange(0.0..1.0, 0.5),
+        ));
+        assert_eq!(it.len(), 12);
+        assert_eq!(it.get(11), Some((1, [2], 0.5)));
+        assert_eq!(it.get(12), None);
+        assert_eq!(it.clone().nth(3), Some((1, [1], 0.0)));
+        assert_eq!(it.last(), Some((1, [2], 0.5)));
+    }
+
+    #[test]
+    fn test_sweep_consumed() {
+        let mut a = lin_space(0..4, 4);
+        a.next();
+        let it = sweep((a, lin_space(0..=1, 2)));
+        check_double_ended_iter(it, [(1, 0), (2, 0), (3, 0), (1, 1), (2, 1), (3, 1)]);
+
+        let it = sweep((lin_space(0..4, 0), lin_space(0..=1, 2)));
+        assert_eq!(it.len(), 0);
+        assert_eq!(format!("{it:?}"), "Sweep { empty, len: 0, axes: 2 }");
+    }
+
+    #[test]
+    fn test_sweep_array() {
+        let mut a = lin_space(0..4, 4);
+        a.next();
+        let it = sweep([a, lin_space(5..7, 2)]);
+        check_double_ended_iter(it, [[1, 5], [2, 5], [3, 5], [1, 6], [2, 6], [3, 6]]);
+
+        let it = sweep(core::array::from_fn::<_, 3, _>(|_| arange(0.0..1.0, 0.5)));
+        assert_eq!(it.len(), 8);
+        assert!(it.clone().eq(crate::grid_space([0.0; 3]..[1.0; 3], 2)));
+        assert_eq!(
+            format!("{it}"),
+            "[0.0, 0.0, 0.0]..=[0.5, 0.5, 0.5] (8 values)"
+        );
+        assert_eq!(sweep([lin_space(0..1, 1), lin_space(0..0, 0)]).len(), 0);
+    }
+
+    #[test]
+    fn test_grid_space_tuple() {
+        let it = grid_space_tuple((0..2, 0.0..=1.0), [2, 3]);
+        check_double_ended_iter(
+            it,
+            [(0, 0.0), (1, 0.0), (0, 0.5), (1, 0.5), (0, 1.0), (1, 1.0)],
+        );
+
+        let it = grid_space_tuple(
+            (
+                0..2,
+                0..2,
+                0..2,
+                0..2,
+                0..2,
+                0..2,
+                0..2,
+                0..2,
+                0..2,
+                0..2,
+                0..2,
+                0.0..=1.0,
+            ),
+            2,
+        );
+        assert_eq!(it.len(), 4096);
+        assert_eq!(
+            it.clone().nth(2047),
+            Some((1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 0.0))
+        );
+        assert_eq!(
+            format!("{it:?}").split(", len").nth(1),
+            Some(": 4096, axes: 12 }")
+        );
+    }
+
+    #[test]
+    fn test_sweep_macro() {
+        let mut it = sweep!(a: lin_space(0..=1, 2), b: lin_space(5..=6, 2));
+        assert_eq!(it.get(2).map(|c| (c.a, c.b)), Some((0, 6)));
+        assert!(it.get(4).is_none());
+
+        // still a space, so it can be consumed from both ends without losing the lookup
+        assert_eq!(it.next_back().map(|c| (c.a, c.b)), Some((1, 6)));
+        assert_eq!(it.get(3).map(|c| (c.a, c.b)), Some((1, 6)));
+        let configs: Vec<_> = it.map(|c| (c.a, c.b)).collect();
+        assert_eq!(configs, [(0, 5), (1, 5), (0, 6)]);
+    }
+}
@@ -0,0 +1,115 @@
+//! Helpers for testing code that produces floating point spaces.
+//!
+//! Values computed from a space are rarely exactly equal to hand written ones,
+//! so these compare iterators within a tolerance instead.
+//!
+//! ```
+//! use iter_num_tools::{assert_iter_approx_eq, log_space};
+//!
+//! assert_iter_approx_eq!(log_space(1.0..=1000.0, 4), [1.0, 10.0, 100.0, 1000.0]);
+//! assert_iter_approx_eq!(log_space(1.0..=1000.0, 4), [1.0, 10.0, 100.0, 1000.0], 1e-12);
+//! ```
+
+use core::fmt::Debug;
+
+use num_traits::Float;
+
+/// The tolerance used by [`assert_iter_approx_eq!`](crate::assert_iter_approx_eq) when none is given
+pub const DEFAULT_TOLERANCE: f64 = 1e-10;
+
+/// Asserts that two iterators have the same number of values,
+/// and that each pair of values differs by at most `tolerance`
+///
+/// ```
+/// use iter_num_tools::{lin_space, testing::assert_iter_approx_eq};
+///
+/// assert_iter_approx_eq(lin_space(0.0..=0.3, 4), [0.0, 0.1, 0.2, 0.3], 1e-15);
+/// ```
+///
+/// # Panics
+///
+/// Panics at the first pair of values that differ by more than `tolerance`, or are NaN,
+/// showing their index, both values and the difference between them.
+/// Also panics if one iterator runs out before the other.
+#[track_caller]
+pub fn assert_iter_approx_eq<T, L, R>(left: L, right: R, tolerance: T)
+where
+    T: Float + Debug,
+    L: IntoIterator<Item = T>,
+    R: IntoIterator<Item = T>,
+{
+    let (mut left, mut right) = (left.into_iter(), right.into_iter());
+    let mut index = 0;
+    loop {
+        match (left.next(), right.next()) {
+            (None, None) => return,
+            (Some(l), Some(r)) => {
+                let difference = (l - r).abs();
+                if difference.is_nan() || difference > tolerance {
+                    panic!(
+                        "iterators differ at index {index}\n  left: {l:?}\n right: {r:?}\n  difference {difference:?} is over the tolerance {tolerance:?}"
+                    );
+                }
+            }
+            (Some(l), None) => {
+                panic!("right ran out after {index} values, but left continues with {l:?}")
+            }
+            (None, Some(r)) => {
+                panic!("left ran out after {index} values, but right continues with {r:?}")
+            }
+        }
+        index += 1;
+    }
+}
+
+/// Asserts that two iterators of floats are equal to within a tolerance,
+/// which defaults to [`DEFAULT_TOLERANCE`](crate::testing::DEFAULT_TOLERANCE).
+///
+/// See [`testing::assert_iter_approx_eq`](crate::testing::assert_iter_approx_eq)
+///
+/// ```should_panic
+/// use iter_num_tools::{assert_iter_approx_eq, lin_space};
+///
+/// // iterators differ at index 1
+/// //   left: 0.5
+/// //  right: 0.6
+/// assert_iter_approx_eq!(lin_space(0.0..=1.0, 3), [0.0, 0.6, 1.0], 0.01);
+/// ```
+#[macro_export]
+macro_rules! assert_iter_approx_eq {
+    ($left:expr, $right:expr $(,)?) => {
+        $crate::testing::assert_iter_approx_eq(
+            $left,
+            $right,
+            $crate::testing::DEFAULT_TOLERANCE as _,
+        )
+    };
+    ($left:expr, $right:expr, $tolerance:expr $(,)?) => {
+        $crate::testing::assert_iter_approx_eq($left, $right, $tolerance)
+    };
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::lin_space;
+
+    #[test]
+    fn test_assert_iter_approx_eq() {
+        assert_iter_approx_eq(lin_space(0.0_f32..=1.0, 3), [0.0, 0.5, 1.0], 0.0);
+        crate::assert_iter_approx_eq!(lin_space(0.0..=1.0, 3), [0.0, 0.5, 1.0 + 1e-11]);
+        crate::assert_iter_approx_eq!([] as [f64; 0], []);
+    }
+
+    #[test]
+    #[should_panic(expected = "iterators differ at index 2")]
+    fn test_assert_iter_approx_eq_nan() {
+        assert_iter_approx_eq([0.0, 1.0, f64::NAN], [0.0, 1.0, f64::NAN], 1.0);
+    }
+
+    #[test]
+    #[should_panic(expected = "left ran out after 2 values, but right continues with 3.0")]
+    fn test_assert_iter_approx_eq_len() {
+        assert_iter_approx_eq([1.0, 2.0], [1.0, 2.0, 3.0], 0.1);
+    }
+}
@@ -0,0 +1,105 @@
+use array_bin_ops::Array;
+use core::ops::{Range, RangeInclusive};
+
+/// Splits a range over arrays or tuples into a range along each component.
+///
+/// This is how [`grid_space`](crate::grid_space) treats a range between two corners,
+/// as a separate range along each axis.
+///
+/// ```
+/// use iter_num_tools::Transpose;
+///
+/// let ranges = ([0.0, 1.0]..[2.0, 3.0]).transpose();
+/// assert_eq!(ranges, [0.0..2.0, 1.0..3.0]);
+///
+/// let ranges = ((0, 'a')..=(10, 'z')).transpose();
+/// assert_eq!(ranges, (0..=10, 'a'..='z'));
+/// ```
+///
+/// Implemented for ranges over arrays, and over tuples of up to 12 values.
+pub trait Transpose {
+    /// The range along each component
+    type Output;
+    /// Splits up the range
+    fn transpose(self) -> Self::Output;
+}
+
+impl<T, const N: usize> Transpose for Range<[T; N]> {
+    type Output = [Range<T>; N];
+
+    fn transpose(self) -> Self::Output {
+        let Range { start, end } = self;
+        Array(start).zip_map(end, |start, end| start..end)
+    }
+}
+
+impl<T, const N: usize> Transpose for RangeInclusive<[T; N]> {
+    type Output = [RangeInclusive<T>; N];
+
+    fn transpose(self) -> Self::Output {
+        let (start, end) = self.into_inner();
+        Array(start).zip_map(end, RangeInclusive::new)
+    }
+}
+
+macro_rules! impl_transpose {
+    ($($t:ident $idx:tt),+) => {
+        impl<$($t),+> Transpose for Range<($($t,)+)> {
+            type Output = ($(Range<$t>,)+);
+
+            fn transpose(self) -> Self::Output {
+                let Range { start, end } = self;
+                ($(start.$idx..end.$idx,)+)
+            }
+        }
+
+        impl<$($t),+> Transpose for RangeInclusive<($($t,)+)> {
+            type Output = ($(RangeInclusive<$t>,)+);
+
+            fn transpose(self) -> Self::Output {
+                let (start, end) = self.into_inner();
+                ($(start.$idx..=end.$idx,)+)
+            }
+        }
+    };
+}
+
+impl_transpose!(A 0);
+impl_transpose!(A 0, B 1);
+impl_transpose!(A 0, B 1, C 2);
+impl_transpose!(A 0, B 1, C 2, D 3);
+impl_transpose!(A 0, B 1, C 2, D 3, E 4);
+impl_transpose!(A 0, B 1, C 2, D 3, E 4, F 5);
+impl_transpose!(A 0, B 1, C 2, D 3, E 4, F 5, G 6);
+impl_transpose!(A 0, B 1, C 2, D 3, E 4, F 5, G 6, H 7);
+impl_transpose!(A 0, B 1, C 2, D 3, E 4, F 5, G 6, H 7, I 8);
+impl_transpose!(A 0, B 1, C 2, D 3, E 4, F 5, G 6, H 7, I 8, J 9);
+impl_transpose!(A 0, B 1, C 2, D 3, E 4, F 5, G 6, H 7, I 8, J 9, K 10);
+impl_transpose!(A 0, B 1, C 2, D 3, E 4, F 5, G 6, H 7, I 8, J 9, K 10, L 11);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{grid_space_tuple, lin_space};
+
+    #[test]
+    fn test_transpose_array() {
+        assert_eq!(([0; 3]..[1, 2, 3]).transpose(), [0..1, 0..2, 0..3]);
+        assert_eq!(([1, 2]..=[3, 4]).transpose(), [1..=3, 2..=4]);
+        assert_eq!((Range::<[u8; 0]> { start: [], end: [] }).transpose(), []);
+    }
+
+    #[test]
+    fn test_transpose_tuple() {
+        let ranges = ((0, 0.0)..(4, 1.0)).transpose();
+        assert!(grid_space_tuple(ranges, [4, 2]).eq(lin_space(0..4, 4)
+            .map(|x| (x, 0.0))
+            .chain(lin_space(0..4, 4).map(|x| (x, 0.5)))));
+
+        let start = (0, 1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11);
+        let end = (1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12);
+        let ranges = (start..=end).transpose();
+        assert_eq!(ranges.0, 0..=1);
+        assert_eq!(ranges.11, 11..=12);
+    }
+}
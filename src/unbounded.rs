@@ -0,0 +1,211 @@
+use core::fmt;
+use core::iter::FusedIterator;
+use core::ops::RangeFrom;
+
+use num_traits::{FromPrimitive, Num};
+
+use crate::{
+    linspace::LinearInterpolation,
+    space::{Interpolate, Space, SpaceFmt},
+};
+
+/// An iterator that interpolates every index counting up from 0, without an end.
+///
+/// Like [`Space`], any index can be computed directly, so skipping ahead with
+/// [`nth`](Iterator::nth) is cheap. It only runs out once the index reaches `usize::MAX`.
+#[derive(Clone)]
+pub struct Unbounded<I> {
+    pub(crate) interpolate: I,
+    pub(crate) index: usize,
+}
+
+impl<I> Unbounded<I> {
+    /// Creates an iterator interpolating each index from 0
+    pub fn new(interpolate: I) -> Self {
+        Unbounded {
+            interpolate,
+            index: 0,
+        }
+    }
+
+    /// The number of values already taken from the iterator
+    pub fn consumed(&self) -> usize {
+        self.index
+    }
+}
+
+impl<I: Interpolate> Iterator for Unbounded<I> {
+    type Item = I::Item;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.nth(0)
+    }
+
+    fn nth(&mut self, n: usize) -> Option<Self::Item> {
+        match self.index.checked_add(n) {
+            Some(x) if x < usize::MAX => {
+                self.index = x + 1;
+                Some(self.interpolate.interpolate(x))
+            }
+            _ => {
+                self.index = usize::MAX;
+                None
+            }
+        }
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (usize::MAX - self.index, None)
+    }
+}
+
+impl<I: Interpolate> FusedIterator for Unbounded<I> {}
+
+/// Shows the name and first remaining value, rather than its internals
+impl<I: SpaceFmt> fmt::Debug for Unbounded<I>
+where
+    I::Item: fmt::Debug,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{} {{ ", I::NAME)?;
+        match self.index {
+            usize::MAX => f.write_str("empty")?,
+            x => write!(f, "{:?}..", self.interpolate.interpolate(x))?,
+        }
+        self.interpolate.fmt_params(f)?;
+        f.write_str(" }")
+    }
+}
+
+/// Creates a grid that steps along one axis forever, visiting every point of `rows` at each step
+///
+/// This suits streaming grids, such as sweeping a set of parameters at every tick of a clock.
+/// Each value pairs the unbounded axis with a point of the row, and the row varies the fastest.
+///
+/// ```
+/// use iter_num_tools::{arange_grid_from, lin_space};
+///
+/// let mut it = arange_grid_from(0.0.., 0.5, lin_space(0.0..=1.0, 3));
+/// assert!(it.by_ref().take(4).eq([(0.0, 0.0), (0.0, 0.5), (0.0, 1.0), (0.5, 0.0)]));
+///
+/// // skips straight to the 1000th row
+/// assert_eq!(it.nth(3 * 1000 - 4), Some((500.0, 0.0)));
+/// ```
+///
+/// If `rows` is empty, so is the grid
+pub fn arange_grid_from<T, I>(range: RangeFrom<T>, step: T, rows: Space<I>) -> ArangeGridFrom<T, I>
+where
+    T: Num + FromPrimitive + Clone,
+    I: Interpolate,
+{
+    let Space {
+        interpolate,
+        range: inner,
+        ..
+    } = rows;
+    let interpolate = GridFromInterpolation {
+        outer: LinearInterpolation {
+            start: range.start,
+            step,
+        },
+        inner: interpolate,
+        offset: inner.start,
+        row_len: inner.len(),
+    };
+    Unbounded {
+        index: match interpolate.row_len {
+            0 => usize::MAX,
+            _ => 0,
+        },
+        interpolate,
+    }
+}
+
+#[derive(Clone, Copy, Debug)]
+pub struct GridFromInterpolation<T, I> {
+    outer: LinearInterpolation<T>,
+    inner: I,
+    offset: usize,
+    row_len: usize,
+}
+
+impl<T: Num + FromPrimitive + Clone, I: Interpolate> Interpolate for GridFromInterpolation<T, I> {
+    type Item = (T, I::Item);
+    fn interpolate(&self, x: usize) -> Self::Item {
+        let (row, z) = (x / self.row_len, x % self.row_len);
+        (
+            self.outer.interpolate(row),
+            self.inner.interpolate(self.offset + z),
+        )
+    }
+}
+
+impl<T, I> SpaceFmt for GridFromInterpolation<T, I>
+where
+    T: Num + FromPrimitive + Clone + fmt::Debug,
+    I: SpaceFmt,
+{
+    const NAME: &'static str = "ArangeGridFrom";
+    fn fmt_params(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            ", step: {:?}, row_len: {}",
+            self.outer.step, self.row_len
+        )?;
+        write!(f, ", inner: {}", I::NAME)?;
+        self.inner.fmt_params(f)
+    }
+}
+
+/// [`Iterator`] returned by [`arange_grid_from`]
+pub type ArangeGridFrom<T, I> = Unbounded<GridFromInterpolation<T, I>>;
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{grid_space, lin_space};
+
+    #[test]
+    fn test_arange_grid_from() {
+        let mut it = arange_grid_from(1.0.., -1.0, grid_space([0, 0]..[2, 2], 2));
+        assert_eq!(it.size_hint(), (usize::MAX, None));
+        assert!(it.by_ref().take(5).eq([
+            (1.0, [0, 0]),
+            (1.0, [1, 0]),
+            (1.0, [0, 1]),
+            (1.0, [1, 1]),
+            (0.0, [0, 0]),
+        ]));
+        assert_eq!(it.consumed(), 5);
+        assert_eq!(it.nth(3), Some((-1.0, [0, 0])));
+
+        assert_eq!(it.nth(usize::MAX), None);
+        assert_eq!(it.next(), None);
+        assert_eq!(it.size_hint(), (0, None));
+    }
+
+    #[test]
+    fn test_arange_grid_from_partial_rows() {
+        let mut rows = lin_space(0..4, 4);
+        rows.next();
+        rows.next_back();
+        let it = arange_grid_from(0.0.., 0.5, rows);
+        assert!(it.take(4).eq([(0.0, 1), (0.0, 2), (0.5, 1), (0.5, 2)]));
+
+        let mut it = arange_grid_from(0.0.., 0.5, lin_space(0..4, 0));
+        assert_eq!(it.next(), None);
+        assert_eq!(
+            format!("{it:?}"),
+            "ArangeGridFrom { empty, step: 0.5, row_len: 0, inner: LinSpace, step: 0 }"
+        );
+    }
+
+    #[test]
+    fn test_arange_grid_from_fmt() {
+        let it = arange_grid_from(0.0.., 0.5, lin_space(0.0..1.0, 2));
+        assert_eq!(
+            format!("{it:?}"),
+            "ArangeGridFrom { (0.0, 0.0).., step: 0.5, row_len: 2, inner: LinSpace, step: 0.5 }"
+        );
+    }
+}